@@ -0,0 +1,133 @@
+use quote::ToTokens;
+use syn::{Data, DeriveInput, Expr, Fields, GenericArgument, Ident, LitStr, PathArguments, Type};
+
+/// Struct deriving `DynoCollection`, one field per kind of row of a partition.
+pub struct CollectionInfo {
+    pub collection_name: String,
+    pub table_name_provider: Option<String>,
+    pub fields: Vec<CollectionFieldInfo>,
+}
+
+pub struct CollectionFieldInfo {
+    pub ident: Ident,
+    /// The row type, without the `Option` or `Vec` around it.
+    pub row_type: Type,
+    pub kind: CollectionFieldKind,
+    /// From `#[dynorow(sk_prefix = "...")]`, routes the items by sort key prefix instead of the row templates.
+    pub sk_prefix: Option<String>,
+}
+
+pub enum CollectionFieldKind {
+    /// Exactly one item is expected, a missing item is `ValueNotFound` and a second one `DuplicateItem`.
+    One,
+    /// At most one item, a second one is `DuplicateItem`.
+    Optional,
+    Many,
+}
+
+impl CollectionInfo {
+    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+        let mut table_name_provider: Option<String> = None;
+        for attribute in &input.attrs {
+            if !attribute.path().is_ident("dynorow") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if !meta.path.is_ident("table") {
+                    return Err(meta.error(format!(
+                        "Unknown struct attribute `{}`. Expected table.",
+                        meta.path.to_token_stream()
+                    )));
+                }
+                let value = meta.value()?.parse::<Expr>()?;
+                table_name_provider = Some(value.into_token_stream().to_string());
+                Ok(())
+            })?;
+        }
+
+        let span = input.ident.span();
+        if !input.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "DynoCollection does not support generics.",
+            ));
+        }
+
+        let Data::Struct(data) = input.data else {
+            return Err(syn::Error::new(
+                span,
+                "DynoCollection only supports structs.",
+            ));
+        };
+        let Fields::Named(fields) = data.fields else {
+            return Err(syn::Error::new(span, "Only named fields are supported."));
+        };
+
+        let mut collection_fields = vec![];
+        for field in fields.named {
+            let mut sk_prefix: Option<String> = None;
+            for attribute in &field.attrs {
+                if !attribute.path().is_ident("dynorow") {
+                    continue;
+                }
+
+                attribute.parse_nested_meta(|meta| {
+                    if !meta.path.is_ident("sk_prefix") {
+                        return Err(meta.error(format!(
+                            "Unknown field attribute `{}`. Expected sk_prefix.",
+                            meta.path.to_token_stream()
+                        )));
+                    }
+                    sk_prefix = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                })?;
+            }
+
+            let (kind, row_type) = match get_inner_type(&field.ty, "Option") {
+                Some(x) => (CollectionFieldKind::Optional, x),
+                None => match get_inner_type(&field.ty, "Vec") {
+                    Some(x) => (CollectionFieldKind::Many, x),
+                    None => (CollectionFieldKind::One, field.ty.clone()),
+                },
+            };
+            collection_fields.push(CollectionFieldInfo {
+                ident: field.ident.unwrap(),
+                row_type,
+                kind,
+                sk_prefix,
+            });
+        }
+
+        if collection_fields.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "DynoCollection requires at least one field.",
+            ));
+        }
+
+        Ok(Self {
+            collection_name: input.ident.to_string(),
+            table_name_provider,
+            fields: collection_fields,
+        })
+    }
+}
+
+/// `T` of `Option<T>` or `Vec<T>`, when `ty` is the given wrapper.
+fn get_inner_type(ty: &Type, wrapper: &str) -> Option<Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first() {
+        Some(GenericArgument::Type(x)) if arguments.args.len() == 1 => Some(x.clone()),
+        _ => None,
+    }
+}
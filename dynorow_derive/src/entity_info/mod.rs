@@ -0,0 +1,83 @@
+use quote::ToTokens;
+use syn::{Data, DeriveInput, Expr, Fields, Ident, Type};
+
+/// Enum deriving `DynoEntity`, one newtype variant per row type sharing the table. <br>
+/// Items are read into the first variant whose row type matches them.
+pub struct EntityInfo {
+    pub entity_name: String,
+    pub table_name_provider: Option<String>,
+    pub variants: Vec<EntityVariantInfo>,
+}
+
+pub struct EntityVariantInfo {
+    pub ident: Ident,
+    pub row_type: Type,
+}
+
+impl EntityInfo {
+    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+        let mut table_name_provider: Option<String> = None;
+        for attribute in &input.attrs {
+            if !attribute.path().is_ident("dynorow") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if !meta.path.is_ident("table") {
+                    return Err(meta.error(format!(
+                        "Unknown enum attribute `{}`. Expected table.",
+                        meta.path.to_token_stream()
+                    )));
+                }
+                let value = meta.value()?.parse::<Expr>()?;
+                table_name_provider = Some(value.into_token_stream().to_string());
+                Ok(())
+            })?;
+        }
+
+        let span = input.ident.span();
+        if !input.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "DynoEntity does not support generics.",
+            ));
+        }
+
+        let Data::Enum(data) = input.data else {
+            return Err(syn::Error::new(span, "DynoEntity only supports enums."));
+        };
+
+        let mut variants = vec![];
+        for variant in data.variants {
+            let Fields::Unnamed(fields) = variant.fields else {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    "DynoEntity variants hold a single DynoRow, such as `Profile(Profile)`.",
+                ));
+            };
+            if fields.unnamed.len() != 1 {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    "DynoEntity variants hold a single DynoRow, such as `Profile(Profile)`.",
+                ));
+            }
+            variants.push(EntityVariantInfo {
+                ident: variant.ident,
+                row_type: fields.unnamed.into_iter().next().unwrap().ty,
+            });
+        }
+
+        if variants.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "DynoEntity requires at least one variant.",
+            ));
+        }
+
+        Ok(Self {
+            entity_name: input.ident.to_string(),
+            table_name_provider,
+            variants,
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use quote::ToTokens;
+use syn::{Data, DeriveInput, Fields, LitStr, Variant, meta::ParseNestedMeta};
+
+use crate::utils::rename_rule::RenameRule;
+
+pub mod tagged_enum_info;
+
+pub struct EnumInfo {
+    pub enum_name: String,
+    pub variants: Vec<VariantInfo>,
+}
+
+pub struct VariantInfo {
+    pub name: String,
+    pub value: String,
+}
+
+impl EnumInfo {
+    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+        let mut rename_rule: Option<RenameRule> = None;
+        for attribute in &input.attrs {
+            if !attribute.path().is_ident("dynorow") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if !meta.path.is_ident("rename_all") {
+                    return Err(meta.error(format!(
+                        "Unknown enum attribute `{}`. Expected rename_all.",
+                        meta.path.to_token_stream()
+                    )));
+                }
+                rename_rule = Some(parse_rename_all(&meta)?);
+                Ok(())
+            })?;
+        }
+
+        let Data::Enum(data) = input.data else {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "DynoEnum only supports enums.",
+            ));
+        };
+
+        let mut variants: Vec<VariantInfo> = vec![];
+        for variant in data.variants.iter() {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    "DynoEnum only supports unit variants.",
+                ));
+            }
+
+            let name = variant.ident.to_string();
+            let value = parse_variant_value(variant, rename_rule)?;
+
+            if variants.iter().any(|x| x.value == value) {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    format!("Duplicate variant value : {}", value),
+                ));
+            }
+            variants.push(VariantInfo { name, value });
+        }
+
+        Ok(Self {
+            enum_name: input.ident.to_string(),
+            variants,
+        })
+    }
+}
+
+pub fn parse_rename_all(meta: &ParseNestedMeta) -> syn::Result<RenameRule> {
+    let value = meta.value()?.parse::<LitStr>()?;
+    match RenameRule::from_str(&value.value()) {
+        Some(rule) => Ok(rule),
+        None => Err(syn::Error::new_spanned(
+            &value,
+            format!("Unknown rename_all rule : {}", value.value()),
+        )),
+    }
+}
+
+/// Stored value of the variant, from `#[dynorow(rename = "...")]` or the `rename_all` rule of the enum.
+pub fn parse_variant_value(
+    variant: &Variant,
+    rename_rule: Option<RenameRule>,
+) -> syn::Result<String> {
+    let name = variant.ident.to_string();
+    let mut value = match rename_rule {
+        Some(rule) => rule.apply_to_variant(&name),
+        None => name,
+    };
+
+    for attribute in &variant.attrs {
+        if !attribute.path().is_ident("dynorow") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("rename") {
+                return Err(meta.error(format!(
+                    "Unknown variant attribute `{}`. Expected rename.",
+                    meta.path.to_token_stream()
+                )));
+            }
+            value = meta.value()?.parse::<LitStr>()?.value();
+            Ok(())
+        })?;
+    }
+    Ok(value)
+}
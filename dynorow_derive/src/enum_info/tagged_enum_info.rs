@@ -0,0 +1,166 @@
+use quote::ToTokens;
+use syn::{Data, DeriveInput, Fields, LitStr, Type};
+
+use crate::{
+    enum_info::{parse_rename_all, parse_variant_value},
+    struct_info::field_info::FieldInfo,
+    utils::{as_expr::AsExpr, generics::GenericsInfo, rename_rule::RenameRule},
+};
+
+/// Data carrying enum deriving `DynoMap`. <br>
+/// Stored as a map with the variant name under `tag`, the variant fields are stored
+/// next to the tag, or in a nested map under `content` when it is set.
+pub struct TaggedEnumInfo {
+    pub enum_name: String,
+    pub tag: String,
+    pub content: Option<String>,
+    pub variants: Vec<TaggedVariantInfo>,
+    pub generics: GenericsInfo,
+}
+
+pub struct TaggedVariantInfo {
+    pub name: String,
+    pub value: String,
+    pub kind: TaggedVariantKind,
+}
+
+pub enum TaggedVariantKind {
+    Unit,
+    Named(Vec<FieldInfo>),
+    /// Only supported with adjacent tagging, the inner value is stored under `content`.
+    Newtype(Type),
+}
+
+impl TaggedVariantInfo {
+    pub fn get_handled_fields(&self) -> Vec<&FieldInfo> {
+        match &self.kind {
+            TaggedVariantKind::Named(fields) => fields.iter().filter(|x| !x.ignore).collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl TaggedEnumInfo {
+    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+        let mut tag: Option<String> = None;
+        let mut content: Option<String> = None;
+        let mut rename_rule: Option<RenameRule> = None;
+        for attribute in &input.attrs {
+            if !attribute.path().is_ident("dynorow") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                let name = meta.path.to_token_stream().to_string();
+                match name.as_str() {
+                    "tag" => {
+                        tag = Some(meta.value()?.parse::<LitStr>()?.value());
+                    }
+                    "content" => {
+                        content = Some(meta.value()?.parse::<LitStr>()?.value());
+                    }
+                    "rename_all" => {
+                        rename_rule = Some(parse_rename_all(&meta)?);
+                    }
+                    _ => {
+                        return Err(meta.error(format!(
+                            "Unknown enum attribute `{}`. Expected tag, content or rename_all.",
+                            name
+                        )));
+                    }
+                }
+                Ok(())
+            })?;
+        }
+
+        let Some(tag) = tag else {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "DynoMap enums need a #[dynorow(tag = \"...\")] attribute.",
+            ));
+        };
+
+        let Data::Enum(data) = input.data else {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "Only structs and enums are supported.",
+            ));
+        };
+
+        let mut variants: Vec<TaggedVariantInfo> = vec![];
+        for variant in data.variants.iter() {
+            let name = variant.ident.to_string();
+            let value = parse_variant_value(variant, rename_rule)?;
+
+            let kind = match &variant.fields {
+                Fields::Unit => TaggedVariantKind::Unit,
+                Fields::Named(fields) => {
+                    let fields = fields
+                        .named
+                        .iter()
+                        .map(FieldInfo::try_from)
+                        .collect::<syn::Result<Vec<FieldInfo>>>()?;
+                    if let Some(field) = fields.iter().find(|x| x.get_key_str() == tag)
+                        && content.is_none()
+                    {
+                        return Err(syn::Error::new(
+                            field.span,
+                            format!("Field of variant {} uses the tag key : {}", name, tag),
+                        ));
+                    }
+                    TaggedVariantKind::Named(fields)
+                }
+                Fields::Unnamed(fields) => {
+                    if fields.unnamed.len() != 1 || content.is_none() {
+                        return Err(syn::Error::new(
+                            variant.ident.span(),
+                            "Tuple variants need a single field and #[dynorow(content = \"...\")].",
+                        ));
+                    }
+                    TaggedVariantKind::Newtype(fields.unnamed.first().unwrap().ty.clone())
+                }
+            };
+
+            if variants.iter().any(|x| x.value == value) {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    format!("Duplicate variant value : {}", value),
+                ));
+            }
+            variants.push(TaggedVariantInfo { name, value, kind });
+        }
+
+        Ok(Self {
+            enum_name: input.ident.to_string(),
+            tag,
+            content,
+            variants,
+            generics: GenericsInfo::new(input.generics),
+        })
+    }
+
+    /// `Name<T>`, the enum name followed by its type parameters.
+    pub fn enum_type(&self) -> proc_macro2::TokenStream {
+        let enum_name_expr = self.enum_name.as_expr();
+        let ty_generics = self.generics.ty_generics();
+        quote::quote! { #enum_name_expr #ty_generics }
+    }
+
+    /// Named variant fields and newtype variant types, used to bound the type parameters.
+    pub fn get_handled_fields(&self) -> Vec<&FieldInfo> {
+        self.variants
+            .iter()
+            .flat_map(|x| x.get_handled_fields())
+            .collect()
+    }
+
+    pub fn get_newtype_types(&self) -> Vec<&Type> {
+        self.variants
+            .iter()
+            .filter_map(|x| match &x.kind {
+                TaggedVariantKind::Newtype(ty) => Some(ty),
+                _ => None,
+            })
+            .collect()
+    }
+}
@@ -1,9 +1,26 @@
 use quote::{ToTokens, quote};
 
-use crate::{struct_info::StructInfo, utils::as_expr::AsExpr};
+use crate::{
+    struct_info::{StructInfo, field_info::FieldInfo},
+    utils::{as_expr::AsExpr, generics::GenericsInfo},
+};
 
 pub fn generate_as_attribute_values(struct_info: &StructInfo) -> proc_macro2::TokenStream {
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let mut predicates = generate_into_attribute_value_predicates(
+        struct_info.get_handled_fields(),
+        &struct_info.generics,
+    );
+    for field in struct_info.get_flattened_fields() {
+        let field_syn_type = &field.field_syn_type;
+        if struct_info.generics.uses_type_params(field_syn_type) {
+            predicates.push(quote! {
+                #field_syn_type: dynorow::traits::as_attribute_key_values::AsAttributeKeyValues
+            });
+        }
+    }
+    let where_clause = struct_info.generics.where_clause(&predicates);
     let mut fields_token = quote::quote! {};
 
     if struct_info.struct_has_pk() {
@@ -22,37 +39,117 @@ pub fn generate_as_attribute_values(struct_info: &StructInfo) -> proc_macro2::To
         }
     }
 
+    if struct_info.struct_has_sk_value() {
+        let sk_key = struct_info.get_sk_key().unwrap();
+        quote! {
+            result.insert(#sk_key.into(), self.as_sk_value().into_attribute_value());
+        }
+        .to_tokens(&mut fields_token);
+    }
+
+    if let (Some(entity_type), Some(entity_type_attr)) =
+        (&struct_info.entity_type, struct_info.get_entity_type_attr())
+    {
+        quote! {
+            result.insert(#entity_type_attr.into(), #entity_type.into_attribute_value());
+        }
+        .to_tokens(&mut fields_token);
+    }
+
+    for field in struct_info.get_flattened_fields() {
+        let field_name_expr = field.name.as_expr();
+        quote! {
+            result.extend(dynorow::traits::as_attribute_key_values::AsAttributeKeyValues::try_as_attribute_key_values(&self.#field_name_expr)?);
+        }
+        .to_tokens(&mut fields_token);
+    }
+
     for field in struct_info.get_handled_fields() {
         let field_name_expr = field.name.as_expr();
         let key_str = field.get_key_str();
-        let field_type_expr = field.get_type_token();
-
-        match field.is_serde {
-            true => quote! {
-                result.insert(#key_str.into(),
-                    aws_sdk_dynamodb::types::AttributeValue::S(
-                        dynorow::serde_json::to_string(&self.#field_name_expr)
-                            .expect("Should be able to generate json from value.")
-                    ));
-            },
-            false => {
-                quote! {
-                    result.insert(#key_str.into(),
-                       <#field_type_expr as dynorow::traits::into_attribute_value::IntoAttributeValue>::into_attribute_value(&self.#field_name_expr));
-                }
-            }
-        }.to_tokens(&mut fields_token);
+        let attribute_value_token =
+            generate_field_attribute_value_token(field, quote! { &self.#field_name_expr });
+        quote! {
+            result.insert(#key_str.into(), #attribute_value_token);
+        }
+        .to_tokens(&mut fields_token);
     }
     quote! {
-        impl dynorow::traits::as_attribute_key_values::AsAttributeKeyValues for #struct_name_expr {
-            fn as_attribute_key_values(&self) -> std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue> {
+        impl #impl_generics dynorow::traits::as_attribute_key_values::AsAttributeKeyValues for #struct_type #where_clause {
+            fn try_as_attribute_key_values(&self) -> Result<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>, dynorow::error::Error> {
                 use dynorow::traits::into_attribute_value::*;
                 let mut result = std::collections::HashMap::<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>::new();
 
                 #fields_token
 
-                return result;
+                return Ok(result);
             }
         }
     }.into()
 }
+
+/// Bounds for the fields using type parameters, fields with their own conversion such as serde
+/// have to be bounded on the type itself.
+pub fn generate_into_attribute_value_predicates(
+    fields: Vec<&FieldInfo>,
+    generics: &GenericsInfo,
+) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .into_iter()
+        .filter(|x| x.has_default_conversion() && generics.uses_type_params(&x.field_syn_type))
+        .map(|x| {
+            let field_type_token = x.get_type_token();
+            quote! { #field_type_token: dynorow::traits::into_attribute_value::IntoAttributeValue }
+        })
+        .collect()
+}
+
+/// `value_token` has to evaluate to a reference to the field value. <br>
+/// The generated expression uses `?`, so it has to be placed in a function returning `Result<_, dynorow::error::Error>`.
+pub fn generate_field_attribute_value_token(
+    field: &FieldInfo,
+    value_token: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_type_expr = field.get_type_token();
+    if let Some(format) = field.get_timestamp_format_token() {
+        let field_syn_type = &field.field_syn_type;
+        return match field.is_option {
+            true => quote! {
+                match #value_token {
+                    Some(x) => <#field_syn_type as dynorow::Timestamp>::to_timestamp_attribute_value(x, #format),
+                    None => dynorow::aws_sdk_dynamodb::types::AttributeValue::Null(true),
+                }
+            },
+            false => quote! {
+                <#field_syn_type as dynorow::Timestamp>::to_timestamp_attribute_value(#value_token, #format)
+            },
+        };
+    }
+
+    if field.is_serde {
+        return quote! {
+            dynorow::aws_sdk_dynamodb::types::AttributeValue::S(
+                dynorow::serde_json::to_string(#value_token)
+                    .map_err(|e| dynorow::error::Error::IntoAttributeError(format!("{:?}", e)))?
+            )
+        };
+    }
+
+    if let Some(module) = &field.with {
+        let function_expr = format!("{}::to_attribute_value", module).as_expr();
+        return quote! { #function_expr(#value_token)? };
+    }
+
+    if field.is_serde_dynamo {
+        return quote! {
+            dynorow::traits::serde_dynamo_attribute_value_into::SerdeDynamoAttributeValueInto::into_aws_attribute_value(
+                dynorow::serde_dynamo::to_attribute_value::<_, dynorow::serde_dynamo::AttributeValue>(#value_token)
+                    .map_err(|e| dynorow::error::Error::IntoAttributeError(format!("{:?}", e)))?
+            )
+        };
+    }
+
+    quote! {
+        <#field_type_expr as dynorow::traits::into_attribute_value::IntoAttributeValue>::try_into_attribute_value(#value_token)?
+    }
+}
@@ -1,10 +1,16 @@
 use quote::{ToTokens, quote};
-use syn::Expr;
 
-use crate::{struct_info::StructInfo, utils::as_expr::AsExpr};
+use crate::{
+    struct_info::{StructInfo, field_info::FieldInfo},
+    utils::as_expr::AsExpr,
+};
 
 pub fn generate_as_key_value_token(struct_info: &StructInfo) -> proc_macro2::TokenStream {
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info
+        .generics
+        .where_clause(&generate_key_predicates(struct_info));
     let pk_key = struct_info.get_pk_key();
     let sk_key = struct_info.get_sk_key();
     let pk_value = struct_info.pk_value.clone();
@@ -13,41 +19,42 @@ pub fn generate_as_key_value_token(struct_info: &StructInfo) -> proc_macro2::Tok
 
     let mut key_generator_caller_token = quote! {};
     let static_key_generator_token = if !pk_value_available && !sk_key_available {
-        let pk_field_name_expr = struct_info.get_pk_field().unwrap().name.as_expr();
+        let pk_value_token = generate_field_value_token(struct_info.get_pk_field().unwrap());
         quote! {
             use dynorow::traits::as_key_value::AsPartitionKeyValue;
-            #struct_name_expr::as_partition_key_value(self.#pk_field_name_expr.clone())
+            <#struct_type>::as_partition_key_value(#pk_value_token)
         }
         .to_tokens(&mut key_generator_caller_token);
 
-        generate_as_partition_key_value(&struct_name_expr, &pk_key)
+        generate_as_partition_key_value(struct_info, &pk_key)
     } else if !pk_value_available && sk_key_available {
-        let pk_field_name_expr = struct_info.get_pk_field().unwrap().name.as_expr();
-        let sk_field_name_expr = struct_info.get_sk_field().unwrap().name.as_expr();
+        let pk_value_token = generate_field_value_token(struct_info.get_pk_field().unwrap());
+        let sk_value_token = generate_sk_value_token(struct_info);
         quote! {
             use dynorow::traits::as_key_value::AsCompositeKeyValue;
-            #struct_name_expr::as_composite_key_value(self.#pk_field_name_expr.clone(), self.#sk_field_name_expr.clone())
-        }.to_tokens(&mut key_generator_caller_token);
+            <#struct_type>::as_composite_key_value(#pk_value_token, #sk_value_token)
+        }
+        .to_tokens(&mut key_generator_caller_token);
 
-        generate_as_composite_key_value(&struct_name_expr, &pk_key, &sk_key.unwrap())
-    } else if pk_value_available && !sk_key_available {
+        generate_as_composite_key_value(struct_info, &pk_key, &sk_key.unwrap())
+    } else if struct_info.is_static_pk_value() && !sk_key_available {
         quote! {
             use dynorow::traits::as_key_value::AsValueAvailablePkValue;
-            #struct_name_expr::as_value_available_pk()
+            <#struct_type>::as_value_available_pk_value()
         }
         .to_tokens(&mut key_generator_caller_token);
 
-        generate_as_value_available_pk(&struct_name_expr, &pk_key, &pk_value.unwrap())
+        generate_as_value_available_pk(struct_info, &pk_key, &pk_value.unwrap())
     } else if struct_info.is_static_pk_value() {
-        let sk_field_name_expr = struct_info.get_sk_field().unwrap().name.as_expr();
+        let sk_value_token = generate_sk_value_token(struct_info);
         quote! {
             use dynorow::traits::as_key_value::AsPkAvailableCompositeKeyValue;
-            #struct_name_expr::as_pk_available_composite_key_value(self.#sk_field_name_expr.clone())
+            <#struct_type>::as_pk_available_composite_key_value(#sk_value_token)
         }
         .to_tokens(&mut key_generator_caller_token);
 
         generate_as_pk_available_composite_key_value(
-            &struct_name_expr,
+            struct_info,
             &pk_key,
             &sk_key.unwrap(),
             &pk_value.unwrap(),
@@ -58,10 +65,10 @@ pub fn generate_as_key_value_token(struct_info: &StructInfo) -> proc_macro2::Tok
         }
         .to_tokens(&mut key_generator_caller_token);
 
-        if let Some(sk_field) = struct_info.get_sk_field() {
-            let sk_field_name = sk_field.name.as_expr();
+        if sk_key_available {
+            let sk_value_token = generate_sk_value_token(struct_info);
             key_generator_caller_token = quote! {
-                self.as_pk_value().with_composite_key_value::<#struct_name_expr>(self.#sk_field_name.clone())
+                self.as_pk_value().with_composite_key_value::<#struct_type>(#sk_value_token)
             };
         }
 
@@ -72,23 +79,67 @@ pub fn generate_as_key_value_token(struct_info: &StructInfo) -> proc_macro2::Tok
 
     quote! {
         #static_key_generator_token
-        impl dynorow::traits::as_key_value::AsKeyValue for #struct_name_expr {
-            fn as_key_value(&self) -> dynorow::key::KeyValue {
+        impl #impl_generics dynorow::traits::as_key_value::AsKeyValue for #struct_type #where_clause {
+            fn as_untyped_key_value(&self) -> dynorow::key::KeyValue {
                 #key_generator_caller_token
             }
         }
     }
 }
 
+/// Key fields using type parameters have to be convertible into attribute values.
+fn generate_key_predicates(struct_info: &StructInfo) -> Vec<proc_macro2::TokenStream> {
+    [struct_info.get_pk_field(), struct_info.get_sk_field()]
+        .into_iter()
+        .flatten()
+        .filter(|x| struct_info.generics.uses_type_params(&x.field_syn_type))
+        .map(|x| {
+            let key_type = x.get_expression_type_token();
+            quote! { #key_type: dynorow::traits::into_attribute_value::IntoAttributeValue }
+        })
+        .collect()
+}
+
+/// Key field value of `self` as its expression type, so that epoch timestamps are converted the same way as the stored attribute.
+fn generate_field_value_token(field: &FieldInfo) -> proc_macro2::TokenStream {
+    let field_name_expr = field.name.as_expr();
+    let key_type = field.get_expression_type_token();
+    quote! { <#key_type>::from(self.#field_name_expr.clone()) }
+}
+
+/// Sort key value of `self`, generated from `sk_value` or read from the sk field.
+fn generate_sk_value_token(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    match struct_info.get_sk_field() {
+        Some(sk_field) => generate_field_value_token(sk_field),
+        None => quote! { self.as_sk_value() },
+    }
+}
+
+/// Value type of the sort key in the key constructors.
+fn generate_sk_type_token(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    match struct_info.get_sk_field() {
+        Some(sk_field) => sk_field.get_expression_type_token(),
+        None => quote! { String },
+    }
+}
+
 fn generate_as_pk_available_composite_key_value(
-    struct_name_expr: &Expr,
+    struct_info: &StructInfo,
     pk_key: &String,
     sk_key: &String,
     pk_value: &String,
 ) -> proc_macro2::TokenStream {
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info
+        .generics
+        .where_clause(&generate_key_predicates(struct_info));
+    let sk_type = generate_sk_type_token(struct_info);
     quote::quote! {
-        impl dynorow::traits::as_key_value::AsPkAvailableCompositeKeyValue for #struct_name_expr {
-            fn as_pk_available_composite_key_value(sort_key_value: String) -> dynorow::key::KeyValue {
+        impl #impl_generics dynorow::traits::as_key_value::AsPkAvailableCompositeKeyValue for #struct_type #where_clause {
+            type SortKey = #sk_type;
+
+            fn as_pk_available_composite_key_value(sort_key_value: #sk_type) -> dynorow::key::KeyValue {
                 dynorow::key::KeyValue::new_composite_key(#pk_key.into(), #pk_value, #sk_key.into(), sort_key_value)
             }
         }
@@ -96,13 +147,26 @@ fn generate_as_pk_available_composite_key_value(
 }
 
 fn generate_as_composite_key_value(
-    struct_name_expr: &Expr,
+    struct_info: &StructInfo,
     pk_key: &String,
     sk_key: &String,
 ) -> proc_macro2::TokenStream {
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info
+        .generics
+        .where_clause(&generate_key_predicates(struct_info));
+    let pk_type = struct_info
+        .get_pk_field()
+        .unwrap()
+        .get_expression_type_token();
+    let sk_type = generate_sk_type_token(struct_info);
     quote::quote! {
-        impl dynorow::traits::as_key_value::AsCompositeKeyValue for #struct_name_expr {
-            fn as_composite_key_value(partition_key_value: String, sort_key_value: String) -> dynorow::key::KeyValue {
+        impl #impl_generics dynorow::traits::as_key_value::AsCompositeKeyValue for #struct_type #where_clause {
+            type PartitionKey = #pk_type;
+            type SortKey = #sk_type;
+
+            fn as_composite_key_value(partition_key_value: #pk_type, sort_key_value: #sk_type) -> dynorow::key::KeyValue {
                 dynorow::key::KeyValue::new_composite_key(#pk_key.into(), partition_key_value, #sk_key.into(), sort_key_value)
             }
         }
@@ -110,27 +174,41 @@ fn generate_as_composite_key_value(
 }
 
 fn generate_as_partition_key_value(
-    struct_name_expr: &Expr,
+    struct_info: &StructInfo,
     pk_key: &String,
 ) -> proc_macro2::TokenStream {
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info
+        .generics
+        .where_clause(&generate_key_predicates(struct_info));
+    let pk_type = struct_info
+        .get_pk_field()
+        .unwrap()
+        .get_expression_type_token();
     quote::quote! {
-        impl dynorow::traits::as_key_value::AsPartitionKeyValue for #struct_name_expr {
-            fn as_partition_key_value(partition_key_value: String) -> dynorow::key::KeyValue {
-                dynorow::key::KeyValue::new_partition_key_value(#pk_key.into(), partition_key_value)
+        impl #impl_generics dynorow::traits::as_key_value::AsPartitionKeyValue for #struct_type #where_clause {
+            type PartitionKey = #pk_type;
+
+            fn as_partition_key_value(partition_key_value: #pk_type) -> dynorow::key::KeyValue {
+                dynorow::key::KeyValue::new_partition_key(#pk_key.into(), partition_key_value)
             }
         }
     }
 }
 
 fn generate_as_value_available_pk(
-    struct_name_expr: &Expr,
+    struct_info: &StructInfo,
     pk_key: &String,
     pk_value: &String,
 ) -> proc_macro2::TokenStream {
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
     quote! {
-        impl dynorow::traits::as_key_value::AsValueAvailablePkValue<#struct_name_expr> for #struct_name_expr {
-            fn as_value_available_pk() -> dynorow::key::KeyValue {
-                dynorow::key::KeyValue::new_partition_key_value(#pk_key.into(), #pk_value.into())
+        impl #impl_generics dynorow::traits::as_key_value::AsValueAvailablePkValue for #struct_type #where_clause {
+            fn as_value_available_pk_value() -> dynorow::key::KeyValue {
+                dynorow::key::KeyValue::new_partition_key(#pk_key.into(), #pk_value)
             }
         }
     }
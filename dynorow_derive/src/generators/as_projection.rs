@@ -1,22 +1,49 @@
-use quote::{quote, ToTokens};
+use quote::{ToTokens, quote};
 
-use crate::{struct_info::StructInfo, utils::as_expr::AsExpr};
+use crate::struct_info::StructInfo;
 
 pub fn generate_as_projection(struct_info: &StructInfo) -> proc_macro2::TokenStream {
-    let struct_name = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
     let projection = struct_info.generate_projection_expression();
 
     let mut as_projection_names_expr = quote! {};
+    let mut flattened_projection_expr = quote! {};
+    let mut predicates = vec![];
+    for field in struct_info.get_flattened_fields() {
+        let field_syn_type = &field.field_syn_type;
+        if struct_info.generics.uses_type_params(field_syn_type) {
+            predicates.push(quote! {#field_syn_type: dynorow::traits::as_projection::AsProjection});
+        }
+        quote! {result.extend(<#field_syn_type as dynorow::traits::as_projection::AsProjection>::as_projection_names());}
+            .to_tokens(&mut as_projection_names_expr);
+        quote! {<#field_syn_type as dynorow::traits::as_projection::AsProjection>::as_projection(),}
+            .to_tokens(&mut flattened_projection_expr);
+    }
+
     for field in struct_info.get_handled_fields() {
         let field_name = field.get_key_str();
         let field_variable_name = field.as_projection_variable();
-        quote! {result.insert(#field_variable_name.into(), #field_name.into());}.to_tokens(&mut as_projection_names_expr);
+        quote! {result.insert(#field_variable_name.into(), #field_name.into());}
+            .to_tokens(&mut as_projection_names_expr);
     }
 
+    if let Some(entity_type_attr) = struct_info.get_entity_type_attr() {
+        let entity_type_variable = format!("#v_{}", entity_type_attr);
+        quote! {result.insert(#entity_type_variable.into(), #entity_type_attr.into());}
+            .to_tokens(&mut as_projection_names_expr);
+    }
+
+    let where_clause = struct_info.generics.where_clause(&predicates);
+
     quote::quote! {
-        impl dynorow::traits::as_projection::AsProjection for #struct_name {
+        impl #impl_generics dynorow::traits::as_projection::AsProjection for #struct_type #where_clause {
             fn as_projection() -> String {
-                String::from(#projection)
+                [String::from(#projection), #flattened_projection_expr]
+                    .into_iter()
+                    .filter(|x| !x.is_empty())
+                    .collect::<Vec<String>>()
+                    .join(", ")
             }
 
             fn as_projection_names() -> std::collections::HashMap<String, String> {
@@ -26,4 +53,4 @@ pub fn generate_as_projection(struct_info: &StructInfo) -> proc_macro2::TokenStr
             }
         }
     }
-}
\ No newline at end of file
+}
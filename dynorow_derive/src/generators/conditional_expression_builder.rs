@@ -1,18 +1,42 @@
 use quote::{ToTokens, quote};
 
-use crate::{struct_info::StructInfo, utils::as_expr::AsExpr};
+use crate::{
+    generators::flattened_builder::{
+        ForwardedFunction, generate_flattened_builder_tokens, generate_forwarding_trait_token,
+    },
+    struct_info::{StructInfo, field_info::FieldInfo},
+    utils::as_expr::AsExpr,
+};
 
 pub fn generate_conditional_expression_builder_token(
     struct_info: &StructInfo,
 ) -> proc_macro2::TokenStream {
-    let struct_name_expr = struct_info.struct_name.as_expr();
-    let expression_builder_struct_name_expr =
-        format!("{}ConditionalExpressionBuilder", struct_info.struct_name).as_expr();
+    let expression_builder_struct_name =
+        format!("{}ConditionalExpressionBuilder", struct_info.struct_name);
+    let expression_builder_struct_name_expr = expression_builder_struct_name.as_expr();
+    let expression_builder_type = struct_info.generated_type(&expression_builder_struct_name);
 
     let mut field_tokens = quote! {};
     for field in &struct_info.get_handled_fields() {
-        generate_field_function_token(&field.name, &field.get_key_str())
+        if field.with.is_some() {
+            let key = field.get_key_str();
+            let function_name = field.name.as_expr();
+            let output = generate_with_field_output_token(field);
+            let builder = generate_with_field_builder_token(field, quote! { #key });
+            quote! {
+                pub fn #function_name(self) -> #output {
+                    #builder
+                }
+            }
             .to_tokens(&mut field_tokens);
+            continue;
+        }
+        generate_field_function_token(
+            &field.name,
+            &field.get_key_str(),
+            field.get_expression_type_token(),
+        )
+        .to_tokens(&mut field_tokens);
     }
 
     let pk_key = struct_info.get_pk_key();
@@ -20,36 +44,183 @@ pub fn generate_conditional_expression_builder_token(
         .find_in_handled_fields("partition_key")
         .is_none()
     {
-        generate_field_function_token("partition_key", &pk_key).to_tokens(&mut field_tokens);
+        generate_field_function_token("partition_key", &pk_key, quote! { String })
+            .to_tokens(&mut field_tokens);
     }
 
     if let Some(sk_key) = struct_info.get_sk_key() {
         if struct_info.find_in_handled_fields("sort_key").is_none() {
-            generate_field_function_token("sort_key", &sk_key).to_tokens(&mut field_tokens);
+            generate_field_function_token("sort_key", &sk_key, quote! { String })
+                .to_tokens(&mut field_tokens);
+        }
+    }
+
+    let flattened = generate_flattened_builder_tokens(
+        struct_info,
+        &expression_builder_struct_name,
+        quote! { dynorow::traits::dyno_map_trait::DynoMapConditionalFields },
+        quote! { dynomap_conditional_expression_builder },
+        quote! { dynorow::traits::dyno_map_trait::FlattenedConditionalFields },
+        quote! { "" },
+    );
+    let flattened_functions = flattened.functions;
+    let flattened_impls = flattened.flattened_impls;
+
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&flattened.predicates);
+    let marker_field = struct_info.generics.marker_field();
+    let marker_init = struct_info.generics.marker_init();
+
+    quote! {
+        impl #impl_generics #struct_type #where_clause {
+            pub fn conditional_expression_builder() -> #expression_builder_type {
+                #expression_builder_struct_name_expr {
+                    #marker_init
+                }
+            }
+        }
+
+        pub struct #expression_builder_struct_name_expr #impl_generics #where_clause {
+            #marker_field
+        }
+
+        impl #impl_generics #expression_builder_type #where_clause {
+            #field_tokens
+
+            #flattened_functions
+        }
+
+        #flattened_impls
+    }
+}
+
+/// Conditions on the fields of a `DynoMap`, used through `#[dynorow(flatten)]` fields of the parent.
+pub fn generate_dynomap_conditional_expression_builder_token(
+    struct_info: &StructInfo,
+) -> proc_macro2::TokenStream {
+    let expression_builder_struct_name = format!(
+        "{}DynoMapConditionalExpressionBuilder",
+        struct_info.struct_name
+    );
+    let expression_builder_struct_name_expr = expression_builder_struct_name.as_expr();
+    let expression_builder_type = struct_info.generated_type(&expression_builder_struct_name);
+
+    let mut field_tokens = quote! {};
+    let mut forwarded_functions = vec![];
+    for field in &struct_info.get_handled_fields() {
+        let key = field.get_key_str();
+        let function_name = field.name.as_expr();
+        let path_token = quote! {
+            &dynorow::traits::dyno_map_trait::nested_attribute_path(&self.parent_name, #key)
+        };
+        let (output, builder) = match field.with.is_some() {
+            true => (
+                generate_with_field_output_token(field),
+                generate_with_field_builder_token(field, path_token),
+            ),
+            false => {
+                let field_type_token = field.get_expression_type_token();
+                (
+                    quote! { dynorow::ConditionalExpressionBuilder<#field_type_token> },
+                    quote! { dynorow::ConditionalExpressionBuilder::<#field_type_token>::typed(#path_token) },
+                )
+            }
+        };
+        forwarded_functions.push(ForwardedFunction {
+            name: function_name.clone(),
+            output: output.clone(),
+        });
+        quote! {
+            pub fn #function_name(&self) -> #output {
+                #builder
+            }
         }
+        .to_tokens(&mut field_tokens);
     }
 
+    let flattened = generate_flattened_builder_tokens(
+        struct_info,
+        &expression_builder_struct_name,
+        quote! { dynorow::traits::dyno_map_trait::DynoMapConditionalFields },
+        quote! { dynomap_conditional_expression_builder },
+        quote! { dynorow::traits::dyno_map_trait::FlattenedConditionalFields },
+        quote! { &self.parent_name },
+    );
+    let flattened_functions = flattened.functions;
+    let flattened_impls = flattened.flattened_impls;
+    forwarded_functions.extend(flattened.forwarded_functions);
+    let forwarding_trait = generate_forwarding_trait_token(
+        struct_info,
+        &format!("{}FlattenedConditionalFields", struct_info.struct_name),
+        quote! { dynorow::traits::dyno_map_trait::FlattenedConditionalFields },
+        expression_builder_type.clone(),
+        &forwarded_functions,
+        &flattened.predicates,
+    );
+
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&flattened.predicates);
+    let marker_field = struct_info.generics.marker_field();
+    let marker_init = struct_info.generics.marker_init();
+
     quote! {
-        impl #struct_name_expr {
-            pub fn conditional_expression_builder() -> #expression_builder_struct_name_expr {
-                #expression_builder_struct_name_expr {}
+        impl #impl_generics dynorow::traits::dyno_map_trait::DynoMapConditionalFields for #struct_type #where_clause {
+            type Builder = #expression_builder_type;
+
+            fn dynomap_conditional_expression_builder(parent_name: &str) -> #expression_builder_type {
+                #expression_builder_struct_name_expr {
+                    parent_name: parent_name.into(),
+                    #marker_init
+                }
             }
         }
 
-        pub struct #expression_builder_struct_name_expr {
+        pub struct #expression_builder_struct_name_expr #impl_generics #where_clause {
+            pub parent_name: String,
+            #marker_field
         }
 
-        impl #expression_builder_struct_name_expr {
+        impl #impl_generics #expression_builder_type #where_clause {
             #field_tokens
+
+            #flattened_functions
         }
+
+        #flattened_impls
+
+        #forwarding_trait
     }
 }
 
-pub fn generate_field_function_token(field_name: &str, key: &str) -> proc_macro2::TokenStream {
+pub fn generate_field_function_token(
+    field_name: &str,
+    key: &str,
+    field_type_token: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
     let function_name = field_name.to_string().as_expr();
     quote! {
-        pub fn #function_name(self) -> dynorow::ConditionalExpressionBuilder {
-            dynorow::ConditionalExpressionBuilder::new(#key.into())
+        pub fn #function_name(self) -> dynorow::ConditionalExpressionBuilder<#field_type_token> {
+            dynorow::ConditionalExpressionBuilder::<#field_type_token>::typed(#key)
         }
     }
 }
+
+fn generate_with_field_output_token(field: &FieldInfo) -> proc_macro2::TokenStream {
+    let field_type_token = field.get_type_token();
+    quote! { dynorow::ConvertedConditionalExpressionBuilder<#field_type_token> }
+}
+
+/// `key_token` has to evaluate to the attribute path as a `&str`.
+fn generate_with_field_builder_token(
+    field: &FieldInfo,
+    key_token: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_type_token = field.get_type_token();
+    let to_attribute_value_expr =
+        format!("{}::to_attribute_value", field.with.as_ref().unwrap()).as_expr();
+    quote! {
+        dynorow::ConvertedConditionalExpressionBuilder::<#field_type_token>::new(#key_token, #to_attribute_value_expr)
+    }
+}
@@ -0,0 +1,109 @@
+use quote::{ToTokens, quote};
+
+use crate::{
+    collection_info::{CollectionFieldKind, CollectionInfo},
+    utils::as_expr::AsExpr,
+};
+
+/// Every item goes to the first field whose row type matches it, by `sk_prefix` when set or by `MatchesItem`. <br>
+/// A second item for a plain or `Option` field is an error rather than being dropped. <br>
+/// The key is the one of the row type of the first field.
+pub fn generate_dyno_collection(collection_info: &CollectionInfo) -> proc_macro2::TokenStream {
+    let collection_expr = collection_info.collection_name.as_expr();
+    let first_row_type = &collection_info.fields[0].row_type;
+
+    let mut declarations = quote! {};
+    let mut dispatch = quote! {};
+    let mut initializers = quote! {};
+    for field in &collection_info.fields {
+        let field_ident = &field.ident;
+        let field_name = field_ident.to_string();
+        let row_type = &field.row_type;
+        let convert_token = quote! {
+            <#row_type as TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>>>::try_from(item)?
+        };
+
+        let (declaration, add, initializer) = match field.kind {
+            CollectionFieldKind::Many => (
+                quote! { let mut #field_ident: Vec<#row_type> = vec![]; },
+                quote! { #field_ident.push(#convert_token); },
+                quote! { #field_ident, },
+            ),
+            CollectionFieldKind::Optional | CollectionFieldKind::One => (
+                quote! { let mut #field_ident: Option<#row_type> = None; },
+                quote! {
+                    if #field_ident.is_some() {
+                        return Err(dynorow::error::Error::duplicate_item(#field_name));
+                    }
+                    #field_ident = Some(#convert_token);
+                },
+                match field.kind {
+                    CollectionFieldKind::One => quote! {
+                        #field_ident: #field_ident.ok_or_else(|| dynorow::error::Error::value_not_found(#field_name))?,
+                    },
+                    _ => quote! { #field_ident, },
+                },
+            ),
+        };
+
+        let matches_token = match &field.sk_prefix {
+            Some(sk_prefix) => quote! {
+                <#row_type as dynorow::traits::has_key::HasKey>::get_key()
+                    .get_sort_key()
+                    .and_then(|x| item.get(&x))
+                    .and_then(|x| x.as_s().ok())
+                    .is_some_and(|x| x.starts_with(#sk_prefix))
+            },
+            None => quote! {
+                <#row_type as dynorow::traits::matches_item::MatchesItem>::matches_item(&item)
+            },
+        };
+
+        declaration.to_tokens(&mut declarations);
+        quote! {
+            if #matches_token {
+                #add
+                continue;
+            }
+        }
+        .to_tokens(&mut dispatch);
+        initializer.to_tokens(&mut initializers);
+    }
+
+    let has_table_name_token = match &collection_info.table_name_provider {
+        Some(table_name_provider) => {
+            let table_name_provider_expr = table_name_provider.as_expr();
+            quote! {
+                impl dynorow::traits::has_table_name::HasTableName for #collection_expr {
+                    fn get_table_name() -> String {
+                        #table_name_provider_expr.clone()
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    quote! {
+        impl dynorow::traits::collection::Collection for #collection_expr {
+            fn from_items(items: Vec<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>>) -> Result<Self, dynorow::error::Error> {
+                #declarations
+                for item in items {
+                    #dispatch
+                }
+
+                Ok(Self {
+                    #initializers
+                })
+            }
+        }
+
+        impl dynorow::traits::has_key::HasKey for #collection_expr {
+            fn get_key() -> dynorow::key::Key {
+                <#first_row_type as dynorow::traits::has_key::HasKey>::get_key()
+            }
+        }
+
+        #has_table_name_token
+    }
+}
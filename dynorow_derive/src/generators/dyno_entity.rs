@@ -0,0 +1,177 @@
+use quote::{ToTokens, quote, quote_spanned};
+
+use crate::{entity_info::EntityInfo, utils::as_expr::AsExpr};
+
+/// Variants are tried in declaration order, so a row matched only by the presence of its key fields has to be the last one. <br>
+/// The key and table are the ones of the first variant, all variants have to share them.
+/// A `table` attribute on the enum overrides the table, it is checked against the table of the variants.
+pub fn generate_dyno_entity(entity_info: &EntityInfo) -> proc_macro2::TokenStream {
+    let entity_name = &entity_info.entity_name;
+    let entity_expr = entity_name.as_expr();
+    let first_row_type = &entity_info.variants[0].row_type;
+    let last_row_type = &entity_info.variants[entity_info.variants.len() - 1].row_type;
+    let table_name_provider_token = match &entity_info.table_name_provider {
+        Some(table_name_provider) => quote! { Some(#table_name_provider) },
+        None => {
+            quote! { <#first_row_type as dynorow::traits::matches_item::MatchesItem>::TABLE_NAME_PROVIDER }
+        }
+    };
+
+    let mut try_from_token = quote! {};
+    let mut matches_tokens = vec![];
+    let mut projection_names_token = quote! {};
+    let mut checks_token = quote! {};
+    let last_index = entity_info.variants.len() - 1;
+    for (index, variant) in entity_info.variants.iter().enumerate() {
+        let variant_ident = &variant.ident;
+        let row_type = &variant.row_type;
+        quote! {
+            if <#row_type as dynorow::traits::matches_item::MatchesItem>::matches_item(&items) {
+                return <#row_type as TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>>>::try_from(items)
+                    .map(Self::#variant_ident);
+            }
+        }
+        .to_tokens(&mut try_from_token);
+
+        matches_tokens.push(quote! {
+            <#row_type as dynorow::traits::matches_item::MatchesItem>::matches_item(item)
+        });
+
+        // Key attributes are not always fields, they are needed to match the items.
+        quote! {
+            result.extend(<#row_type as dynorow::traits::as_projection::AsProjection>::as_projection_names());
+            let key = <#row_type as dynorow::traits::has_key::HasKey>::get_key();
+            for key_name in std::iter::once(key.get_partition_key()).chain(key.get_sort_key()) {
+                result.insert(format!("#v_{}", key_name), key_name);
+            }
+        }
+        .to_tokens(&mut projection_names_token);
+
+        if index < last_index {
+            let message = format!(
+                "The variant `{}` matches any item holding its key attributes, it has to be the last variant of `{}`.",
+                variant_ident, entity_name
+            );
+            quote_spanned! {variant.ident.span()=>
+                const _: () = assert!(
+                    !<#row_type as dynorow::traits::matches_item::MatchesItem>::MATCHES_BY_PRESENCE,
+                    #message
+                );
+            }
+            .to_tokens(&mut checks_token);
+        }
+        if index > 0 {
+            let message = format!(
+                "The variant `{}` does not share the table and key attributes of `{}`.",
+                variant_ident, entity_info.variants[0].ident
+            );
+            quote_spanned! {variant.ident.span()=>
+                const _: () = assert!(
+                    dynorow::traits::matches_item::shares_table_key::<#first_row_type, #row_type>(),
+                    #message
+                );
+            }
+            .to_tokens(&mut checks_token);
+        }
+    }
+
+    let has_table_name_token = match &entity_info.table_name_provider {
+        Some(table_name_provider) => {
+            let table_name_provider_expr = table_name_provider.as_expr();
+            let message = format!(
+                "The table of `{}` is not the one of its variants.",
+                entity_name
+            );
+            quote! {
+                const _: () = assert!(
+                    dynorow::traits::matches_item::uses_table_name_provider::<#first_row_type>(#table_name_provider),
+                    #message
+                );
+
+                impl dynorow::traits::has_table_name::HasTableName for #entity_expr {
+                    fn get_table_name() -> String {
+                        #table_name_provider_expr.clone()
+                    }
+                }
+            }
+        }
+        None => {
+            let first_variant_span = entity_info.variants[0].ident.span();
+            quote_spanned! {first_variant_span=>
+                impl dynorow::traits::has_table_name::HasTableName for #entity_expr {
+                    fn get_table_name() -> String {
+                        <#first_row_type as dynorow::traits::has_table_name::HasTableName>::get_table_name()
+                    }
+                }
+            }
+        }
+    };
+
+    quote! {
+        #checks_token
+
+        impl TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>> for #entity_expr {
+            type Error = dynorow::error::Error;
+
+            fn try_from(items: std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                #try_from_token
+                Err(dynorow::error::Error::entity_mismatch(#entity_name))
+            }
+        }
+
+        impl TryFrom<dynorow::aws_sdk_dynamodb::operation::get_item::GetItemOutput> for #entity_expr {
+            type Error = dynorow::error::Error;
+
+            fn try_from(value: dynorow::aws_sdk_dynamodb::operation::get_item::GetItemOutput) -> Result<Self, Self::Error> {
+                let Some(items) = value.item else {
+                    return Err(dynorow::error::Error::value_not_found("GetItemOuput.item"));
+                };
+
+                Self::try_from(items)
+            }
+        }
+
+        impl dynorow::traits::matches_item::MatchesItem for #entity_expr {
+            const MATCHES_BY_PRESENCE: bool = <#last_row_type as dynorow::traits::matches_item::MatchesItem>::MATCHES_BY_PRESENCE;
+            const PARTITION_KEY: &'static str = <#first_row_type as dynorow::traits::matches_item::MatchesItem>::PARTITION_KEY;
+            const SORT_KEY: Option<&'static str> = <#first_row_type as dynorow::traits::matches_item::MatchesItem>::SORT_KEY;
+            const TABLE_NAME_PROVIDER: Option<&'static str> = #table_name_provider_token;
+
+            fn matches_item(item: &std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>) -> bool {
+                #(#matches_tokens)||*
+            }
+        }
+
+        /// Union of the projections of the variants.
+        impl dynorow::traits::as_projection::AsProjection for #entity_expr {
+            fn as_projection() -> String {
+                let mut names: Vec<String> = Self::as_projection_names().into_keys().collect();
+                names.sort();
+                names.join(", ")
+            }
+
+            fn as_projection_names() -> std::collections::HashMap<String, String> {
+                let mut result: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+                #projection_names_token
+                result
+            }
+        }
+
+        impl dynorow::traits::has_key::HasKey for #entity_expr {
+            fn get_key() -> dynorow::key::Key {
+                <#first_row_type as dynorow::traits::has_key::HasKey>::get_key()
+            }
+        }
+
+        #has_table_name_token
+
+        impl #entity_expr {
+            /// Condition on the partition key of an item collection, the variants can have different sort keys.
+            pub fn key_condition(
+                partition_key_value: impl dynorow::traits::into_attribute_value::IntoAttributeValue,
+            ) -> dynorow::KeyConditionBuilder<Self> {
+                dynorow::KeyConditionBuilder::new(partition_key_value)
+            }
+        }
+    }
+}
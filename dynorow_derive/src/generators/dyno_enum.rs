@@ -0,0 +1,74 @@
+use quote::{ToTokens, quote};
+
+use crate::{enum_info::EnumInfo, utils::as_expr::AsExpr};
+
+pub fn generate_dyno_enum(enum_info: &EnumInfo) -> proc_macro2::TokenStream {
+    let enum_name_expr = enum_info.enum_name.as_expr();
+    let enum_name_str = enum_info.enum_name.clone();
+
+    let mut as_variant_str_token = quote! {};
+    let mut from_variant_str_token = quote! {};
+    for variant in &enum_info.variants {
+        let variant_name_expr = variant.name.as_expr();
+        let value = &variant.value;
+        quote! {
+            #enum_name_expr::#variant_name_expr => #value,
+        }
+        .to_tokens(&mut as_variant_str_token);
+
+        quote! {
+            #value => Some(#enum_name_expr::#variant_name_expr),
+        }
+        .to_tokens(&mut from_variant_str_token);
+    }
+
+    quote! {
+        impl dynorow::traits::dyno_enum_trait::DynoEnumTrait for #enum_name_expr {
+            fn as_variant_str(&self) -> &'static str {
+                match self {
+                    #as_variant_str_token
+                }
+            }
+
+            fn from_variant_str(value: &str) -> Option<Self> {
+                match value {
+                    #from_variant_str_token
+                    _ => None,
+                }
+            }
+        }
+
+        impl dynorow::traits::scalar_attribute::ScalarAttribute for #enum_name_expr {
+            const SCALAR_ATTRIBUTE_TYPE: dynorow::aws_sdk_dynamodb::types::ScalarAttributeType =
+                dynorow::aws_sdk_dynamodb::types::ScalarAttributeType::S;
+        }
+
+        impl dynorow::traits::scalar_attribute::SortKeyPrefix for #enum_name_expr {
+            type Prefix = String;
+        }
+
+        impl dynorow::traits::into_attribute_value::IntoAttributeValue for #enum_name_expr {
+            fn into_attribute_value(&self) -> dynorow::aws_sdk_dynamodb::types::AttributeValue {
+                use dynorow::traits::dyno_enum_trait::DynoEnumTrait;
+                dynorow::aws_sdk_dynamodb::types::AttributeValue::S(self.as_variant_str().into())
+            }
+        }
+
+        impl dynorow::traits::from_attribute_value::FromAttributeValue<#enum_name_expr> for #enum_name_expr {
+            fn from_attribute_value(value: dynorow::aws_sdk_dynamodb::types::AttributeValue) -> Result<#enum_name_expr, dynorow::error::Error> {
+                use dynorow::traits::dyno_enum_trait::DynoEnumTrait;
+                let Ok(variant) = value.as_s() else {
+                    return Err(dynorow::error::Error::parse_error(value, #enum_name_str, String::new()));
+                };
+                match #enum_name_expr::from_variant_str(variant) {
+                    Some(x) => Ok(x),
+                    None => Err(dynorow::error::Error::parse_error(
+                        value.clone(),
+                        #enum_name_str,
+                        format!("Unknown variant `{}` for enum `{}`.", variant, #enum_name_str),
+                    )),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{Ident, spanned::Spanned};
+
+use crate::{
+    projection_info::{
+        ProjectionInfo, projection_entity_type_check_name, projection_entity_type_key_name,
+        projection_key_name, projection_reader_name,
+    },
+    utils::as_expr::AsExpr,
+};
+
+/// Reads and projects the fields through the functions `DynoRow` generates for the parent fields,
+/// table name, key and entity type come from the blanket impls of `ProjectionOf`. <br>
+/// Items of another entity type than the parent are rejected, so the entity type attribute is part of the projection.
+pub fn generate_dyno_projection(projection_info: &ProjectionInfo) -> proc_macro2::TokenStream {
+    let impl_generics = projection_info.generics.impl_generics();
+    let projection_type = projection_info.projection_type();
+    let where_clause = projection_info.generics.where_clause(&[]);
+    let parent = &projection_info.parent;
+
+    let mut fields_token = quote! {};
+    let mut field_names = vec![];
+    let mut key_names = vec![];
+    for (field_ident, field_type) in &projection_info.fields {
+        let field_name = field_ident.to_string();
+        // Missing parent fields and type mismatches are reported at the projection field.
+        let reader_name = Ident::new(&projection_reader_name(&field_name), field_ident.span());
+        let key_name = Ident::new(&projection_key_name(&field_name), field_ident.span());
+        quote_spanned! {field_type.span()=>
+            let #field_ident: Result<#field_type, dynorow::error::Error> = <#parent>::#reader_name(&mut items);
+        }
+        .to_tokens(&mut fields_token);
+        field_names.push(field_ident);
+        key_names.push(quote! { <#parent>::#key_name() });
+    }
+
+    let check_name_expr = projection_entity_type_check_name().as_expr();
+    let entity_type_key_name_expr = projection_entity_type_key_name().as_expr();
+
+    quote! {
+        impl #impl_generics dynorow::traits::projection_of::ProjectionOf for #projection_type #where_clause {
+            type Parent = #parent;
+        }
+
+        impl #impl_generics TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>> for #projection_type #where_clause {
+            type Error = dynorow::error::Error;
+
+            fn try_from(mut items: std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                <#parent>::#check_name_expr(&items)?;
+                #fields_token
+                Ok(Self {
+                    #(#field_names: #field_names?),*
+                })
+            }
+        }
+
+        impl #impl_generics TryFrom<dynorow::aws_sdk_dynamodb::operation::get_item::GetItemOutput> for #projection_type #where_clause {
+            type Error = dynorow::error::Error;
+
+            fn try_from(value: dynorow::aws_sdk_dynamodb::operation::get_item::GetItemOutput) -> Result<Self, Self::Error> {
+                let Some(items) = value.item else {
+                    return Err(dynorow::error::Error::value_not_found("GetItemOuput.item"));
+                };
+
+                Self::try_from(items)
+            }
+        }
+
+        impl #impl_generics dynorow::traits::as_projection::AsProjection for #projection_type #where_clause {
+            fn as_projection() -> String {
+                [#(#key_names),*]
+                    .into_iter()
+                    .chain(<#parent>::#entity_type_key_name_expr())
+                    .map(|x| format!("#v_{}", x))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            }
+
+            fn as_projection_names() -> std::collections::HashMap<String, String> {
+                [#(#key_names),*]
+                    .into_iter()
+                    .chain(<#parent>::#entity_type_key_name_expr())
+                    .map(|x| (format!("#v_{}", x), x.to_string()))
+                    .collect()
+            }
+        }
+    }
+}
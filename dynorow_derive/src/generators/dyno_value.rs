@@ -0,0 +1,170 @@
+use quote::{ToTokens, format_ident, quote};
+
+use crate::{struct_info::field_type::FieldType, utils::as_expr::AsExpr, value_info::ValueInfo};
+
+pub fn generate_dyno_value(value_info: &ValueInfo) -> proc_macro2::TokenStream {
+    let value_name_expr = value_info.value_name.as_expr();
+    let inner_type = &value_info.inner_type;
+    let (accessor, constructor) = match value_info.field_accessor.as_str() {
+        "0" => {
+            let index = syn::Index::from(0);
+            (quote! {#index}, quote! {#value_name_expr(inner)})
+        }
+        x => {
+            let ident = format_ident!("{}", x);
+            (quote! {#ident}, quote! {#value_name_expr { #ident: inner }})
+        }
+    };
+
+    let impl_generics = value_info.generics.impl_generics();
+    let value_type = value_info.value_type();
+    let uses_type_params = value_info.generics.uses_type_params(inner_type);
+    let bounded_where_clause = |bound: proc_macro2::TokenStream| match uses_type_params {
+        true => value_info
+            .generics
+            .where_clause(&[quote! { #inner_type: #bound }]),
+        false => value_info.generics.where_clause(&[]),
+    };
+    let into_where_clause =
+        bounded_where_clause(quote! { dynorow::traits::into_attribute_value::IntoAttributeValue });
+    let from_where_clause = bounded_where_clause(
+        quote! { dynorow::traits::from_attribute_value::FromAttributeValue<#inner_type> },
+    );
+    let where_clause = value_info.generics.where_clause(&[]);
+    let display_where_clause = bounded_where_clause(quote! { std::fmt::Display });
+    let from_str_where_clause = bounded_where_clause(quote! { std::str::FromStr });
+
+    let mut tokens = quote! {
+        impl #impl_generics dynorow::traits::into_attribute_value::IntoAttributeValue for #value_type #into_where_clause {
+            fn into_attribute_value(&self) -> dynorow::aws_sdk_dynamodb::types::AttributeValue {
+                <#inner_type as dynorow::traits::into_attribute_value::IntoAttributeValue>::into_attribute_value(&self.#accessor)
+            }
+
+            fn try_into_attribute_value(&self) -> Result<dynorow::aws_sdk_dynamodb::types::AttributeValue, dynorow::error::Error> {
+                <#inner_type as dynorow::traits::into_attribute_value::IntoAttributeValue>::try_into_attribute_value(&self.#accessor)
+            }
+        }
+
+        impl #impl_generics dynorow::traits::dyno_value_trait::DynoValueTrait for #value_type #where_clause {
+            type Inner = #inner_type;
+
+            fn as_inner(&self) -> &#inner_type {
+                &self.#accessor
+            }
+
+            fn from_inner(inner: #inner_type) -> Self {
+                #constructor
+            }
+        }
+
+        impl #impl_generics dynorow::traits::from_attribute_value::FromAttributeValue<#value_type> for #value_type #from_where_clause {
+            fn from_attribute_value(value: dynorow::aws_sdk_dynamodb::types::AttributeValue) -> Result<#value_type, dynorow::error::Error> {
+                let inner = <#inner_type as dynorow::traits::from_attribute_value::FromAttributeValue<#inner_type>>::from_attribute_value(value)?;
+                Ok(#constructor)
+            }
+        }
+    };
+
+    if let Some(scalar_attribute_token) = generate_scalar_attribute_token(value_info) {
+        scalar_attribute_token.to_tokens(&mut tokens);
+    }
+
+    if let Some(sort_key_prefix_token) = generate_sort_key_prefix_token(value_info) {
+        sort_key_prefix_token.to_tokens(&mut tokens);
+    }
+
+    if value_info.display {
+        quote! {
+            impl #impl_generics std::fmt::Display for #value_type #display_where_clause {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    std::fmt::Display::fmt(&self.#accessor, f)
+                }
+            }
+        }
+        .to_tokens(&mut tokens);
+    }
+
+    if value_info.from_str {
+        quote! {
+            impl #impl_generics std::str::FromStr for #value_type #from_str_where_clause {
+                type Err = <#inner_type as std::str::FromStr>::Err;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    let inner = <#inner_type as std::str::FromStr>::from_str(value)?;
+                    Ok(#constructor)
+                }
+            }
+        }
+        .to_tokens(&mut tokens);
+    }
+
+    tokens
+}
+
+/// Key type of the inner value, types unknown to the derive provide it through `ScalarAttribute`. <br>
+/// The bound on a concrete inner type is higher ranked, so that newtypes over values that cannot be keys
+/// still compile and only fail when they are used as a key.
+fn generate_scalar_attribute_token(value_info: &ValueInfo) -> Option<proc_macro2::TokenStream> {
+    let inner_type = &value_info.inner_type;
+    let scalar_attribute_type = match &value_info.inner_field_type {
+        FieldType::String => quote! { S },
+        FieldType::Binary(_) => quote! { B },
+        FieldType::bool | FieldType::Vec(_) | FieldType::HashSet(_) => return None,
+        FieldType::Map(_) => {
+            let bound = quote! { #inner_type: dynorow::traits::scalar_attribute::ScalarAttribute };
+            let predicate = match value_info.generics.uses_type_params(inner_type) {
+                true => bound,
+                false => quote! { for<'a> #bound },
+            };
+            let impl_generics = value_info.generics.impl_generics();
+            let value_type = value_info.value_type();
+            let where_clause = value_info.generics.where_clause(&[predicate]);
+            return Some(quote! {
+                impl #impl_generics dynorow::traits::scalar_attribute::ScalarAttribute for #value_type #where_clause {
+                    const SCALAR_ATTRIBUTE_TYPE: dynorow::aws_sdk_dynamodb::types::ScalarAttributeType =
+                        <#inner_type as dynorow::traits::scalar_attribute::ScalarAttribute>::SCALAR_ATTRIBUTE_TYPE;
+                }
+            });
+        }
+        _ => quote! { N },
+    };
+
+    let impl_generics = value_info.generics.impl_generics();
+    let value_type = value_info.value_type();
+    let where_clause = value_info.generics.where_clause(&[]);
+    Some(quote! {
+        impl #impl_generics dynorow::traits::scalar_attribute::ScalarAttribute for #value_type #where_clause {
+            const SCALAR_ATTRIBUTE_TYPE: dynorow::aws_sdk_dynamodb::types::ScalarAttributeType =
+                dynorow::aws_sdk_dynamodb::types::ScalarAttributeType::#scalar_attribute_type;
+        }
+    })
+}
+
+/// `begins_with` prefix of the inner value, only newtypes over strings and binary get one.
+fn generate_sort_key_prefix_token(value_info: &ValueInfo) -> Option<proc_macro2::TokenStream> {
+    let inner_type = &value_info.inner_type;
+    let impl_generics = value_info.generics.impl_generics();
+    let value_type = value_info.value_type();
+    let (prefix_type, predicates) = match &value_info.inner_field_type {
+        FieldType::String => (quote! { String }, vec![]),
+        FieldType::Binary(_) => (quote! { Vec<u8> }, vec![]),
+        FieldType::Map(_) => {
+            let bound = quote! { #inner_type: dynorow::traits::scalar_attribute::SortKeyPrefix };
+            let predicate = match value_info.generics.uses_type_params(inner_type) {
+                true => bound,
+                false => quote! { for<'a> #bound },
+            };
+            (
+                quote! { <#inner_type as dynorow::traits::scalar_attribute::SortKeyPrefix>::Prefix },
+                vec![predicate],
+            )
+        }
+        _ => return None,
+    };
+    let where_clause = value_info.generics.where_clause(&predicates);
+    Some(quote! {
+        impl #impl_generics dynorow::traits::scalar_attribute::SortKeyPrefix for #value_type #where_clause {
+            type Prefix = #prefix_type;
+        }
+    })
+}
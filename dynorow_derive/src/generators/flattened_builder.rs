@@ -0,0 +1,208 @@
+use quote::{ToTokens, quote, quote_spanned};
+
+use crate::{struct_info::StructInfo, utils::as_expr::AsExpr};
+
+/// Function of a `DynoMap` builder, forwarded to the builders of the structs flattening the map.
+pub struct ForwardedFunction {
+    pub name: syn::Expr,
+    pub output: proc_macro2::TokenStream,
+}
+
+/// Expression builder tokens of the `#[dynorow(flatten)]` fields of a struct.
+pub struct FlattenedBuilderTokens {
+    /// One `<field>_fields()` function per flattened field.
+    pub functions: proc_macro2::TokenStream,
+    /// The `<field>_fields()` functions, forwarded in turn when the struct is itself flattened.
+    pub forwarded_functions: Vec<ForwardedFunction>,
+    /// One `flattened_trait` implementation per flattened field, the field functions of the flattened struct are forwarded through them.
+    pub flattened_impls: proc_macro2::TokenStream,
+    /// Bounds of the flattened fields using type parameters, for the builder declaration and impls.
+    pub predicates: Vec<proc_macro2::TokenStream>,
+}
+
+/// `builder_trait` is the `DynoMap` trait providing the builder of the flattened structs, `builder_function` its function
+/// and `flattened_trait` the matching `Flattened*Fields` trait. <br>
+/// Flattened attributes live at the same level as the fields of the struct, so the flattened builders get the parent name of the builder itself,
+/// `parent_name` in its functions.
+pub fn generate_flattened_builder_tokens(
+    struct_info: &StructInfo,
+    builder_struct_name: &str,
+    builder_trait: proc_macro2::TokenStream,
+    builder_function: proc_macro2::TokenStream,
+    flattened_trait: proc_macro2::TokenStream,
+    parent_name: proc_macro2::TokenStream,
+) -> FlattenedBuilderTokens {
+    let builder_type = struct_info.generated_type(builder_struct_name);
+    let flattened_fields = struct_info.get_flattened_fields();
+
+    let mut predicates = vec![];
+    for field in &flattened_fields {
+        let field_syn_type = &field.field_syn_type;
+        if struct_info.generics.uses_type_params(field_syn_type) {
+            predicates.push(quote! { #field_syn_type: #builder_trait });
+        }
+    }
+
+    let impl_generics = struct_info.generics.impl_generics();
+    let where_clause = struct_info.generics.where_clause(&predicates);
+    let mut functions = quote! {};
+    let mut forwarded_functions = vec![];
+    let mut flattened_impls = quote! {};
+    for (index, field) in flattened_fields.iter().enumerate() {
+        let field_syn_type = &field.field_syn_type;
+        let function_name = format!("{}_fields", field.name).as_expr();
+        let output = quote! { <#field_syn_type as #builder_trait>::Builder };
+        quote! {
+            pub fn #function_name(&self) -> #output {
+                <#field_syn_type as #builder_trait>::#builder_function(#parent_name)
+            }
+        }
+        .to_tokens(&mut functions);
+
+        quote! {
+            impl #impl_generics #flattened_trait<#field_syn_type, dynorow::traits::dyno_map_trait::FlattenedIndex<#index>> for #builder_type #where_clause {
+                type Builder = #output;
+
+                fn flattened_fields(&self) -> #output {
+                    self.#function_name()
+                }
+            }
+        }
+        .to_tokens(&mut flattened_impls);
+
+        forwarded_functions.push(ForwardedFunction {
+            name: function_name,
+            output,
+        });
+    }
+
+    FlattenedBuilderTokens {
+        functions,
+        forwarded_functions,
+        flattened_impls,
+        predicates,
+    }
+}
+
+/// `{Name}Flattened*Fields` trait of a `DynoMap` builder, implemented for every builder reaching the struct through `flattened_trait`,
+/// so that the functions of the builder are available on the builders of the structs flattening it.
+pub fn generate_forwarding_trait_token(
+    struct_info: &StructInfo,
+    trait_name: &str,
+    flattened_trait: proc_macro2::TokenStream,
+    builder_type: proc_macro2::TokenStream,
+    functions: &[ForwardedFunction],
+    predicates: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let trait_name_expr = trait_name.to_string().as_expr();
+    let struct_type = struct_info.struct_type();
+    let trait_generics = struct_info.generics.with_type_params(&["__I"]);
+    let trait_impl_generics = trait_generics.impl_generics();
+    let trait_ty_generics = trait_generics.ty_generics();
+    let trait_where_clause = trait_generics.where_clause(predicates);
+    let impl_generics = struct_info.generics.with_type_params(&["__B", "__I"]);
+    let mut impl_predicates = predicates.to_vec();
+    impl_predicates
+        .push(quote! { __B: #flattened_trait<#struct_type, __I, Builder = #builder_type> });
+    let impl_where_clause = impl_generics.where_clause(&impl_predicates);
+    let impl_generics = impl_generics.impl_generics();
+
+    let names: Vec<&syn::Expr> = functions.iter().map(|x| &x.name).collect();
+    let outputs: Vec<&proc_macro2::TokenStream> = functions.iter().map(|x| &x.output).collect();
+    let doc = format!(
+        "Functions of `{}` on the builders of the structs flattening `{}`.",
+        builder_type.to_string().replace(' ', ""),
+        struct_info.struct_name
+    );
+
+    quote! {
+        #[doc = #doc]
+        pub trait #trait_name_expr #trait_impl_generics #trait_where_clause {
+            #(fn #names(&self) -> #outputs;)*
+        }
+
+        impl #impl_generics #trait_name_expr #trait_ty_generics for __B #impl_where_clause {
+            #(
+                fn #names(&self) -> #outputs {
+                    <__B as #flattened_trait<#struct_type, __I>>::flattened_fields(self).#names()
+                }
+            )*
+        }
+    }
+}
+
+/// `DynoMapAttributeNames` of a struct deriving `DynoMap`, the attribute names of its own fields.
+pub fn generate_dynomap_attribute_names(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
+    let keys: Vec<String> = struct_info
+        .get_handled_fields()
+        .iter()
+        .map(|x| x.get_key_str())
+        .collect();
+    quote! {
+        impl #impl_generics dynorow::traits::dyno_map_trait::DynoMapAttributeNames for #struct_type #where_clause {
+            const ATTRIBUTE_NAMES: &'static [&'static str] = &[#(#keys),*];
+        }
+    }
+}
+
+/// Compile time checks that the attributes of the flattened fields do not collide with the attributes of the struct,
+/// its keys and entity type included, nor with each other. <br>
+/// Only the own fields of the flattened structs are compared, and fields of a type parameter are not checked.
+pub fn generate_flattened_attribute_check(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    let mut names: Vec<String> = struct_info
+        .get_handled_fields()
+        .iter()
+        .map(|x| x.get_key_str())
+        .collect();
+    if struct_info.dynorow {
+        names.push(struct_info.get_pk_key());
+        names.extend(struct_info.get_sk_key());
+        names.extend(struct_info.get_entity_type_attr());
+    }
+
+    let flattened_fields: Vec<_> = struct_info
+        .get_flattened_fields()
+        .into_iter()
+        .filter(|x| !struct_info.generics.uses_type_params(&x.field_syn_type))
+        .collect();
+    let mut tokens = quote! {};
+    for (index, field) in flattened_fields.iter().enumerate() {
+        let field_syn_type = &field.field_syn_type;
+        let message = format!(
+            "The flattened field `{}` has an attribute name of `{}`.",
+            field.name, struct_info.struct_name
+        );
+        quote_spanned! {field.span=>
+            const _: () = assert!(
+                !dynorow::traits::dyno_map_trait::shares_attribute_name(
+                    <#field_syn_type as dynorow::traits::dyno_map_trait::DynoMapAttributeNames>::ATTRIBUTE_NAMES,
+                    &[#(#names),*],
+                ),
+                #message
+            );
+        }
+        .to_tokens(&mut tokens);
+
+        for other in &flattened_fields[..index] {
+            let other_syn_type = &other.field_syn_type;
+            let message = format!(
+                "The flattened fields `{}` and `{}` have attributes with the same name.",
+                other.name, field.name
+            );
+            quote_spanned! {field.span=>
+                const _: () = assert!(
+                    !dynorow::traits::dyno_map_trait::shares_attribute_name(
+                        <#field_syn_type as dynorow::traits::dyno_map_trait::DynoMapAttributeNames>::ATTRIBUTE_NAMES,
+                        <#other_syn_type as dynorow::traits::dyno_map_trait::DynoMapAttributeNames>::ATTRIBUTE_NAMES,
+                    ),
+                    #message
+                );
+            }
+            .to_tokens(&mut tokens);
+        }
+    }
+    tokens
+}
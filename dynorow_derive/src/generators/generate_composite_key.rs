@@ -1,6 +1,9 @@
 use quote::{ToTokens, quote};
 
-use crate::{AsExpr, StructInfo};
+use crate::{
+    AsExpr, StructInfo,
+    generators::generate_pk_value::{TemplatePartsTokens, generate_template_parts_tokens},
+};
 
 pub fn generate_generate_composite_key(struct_info: &StructInfo) -> proc_macro2::TokenStream {
     let Some(sk_key) = struct_info.get_sk_key() else {
@@ -12,51 +15,74 @@ pub fn generate_generate_composite_key(struct_info: &StructInfo) -> proc_macro2:
     }
 
     let pk_key = struct_info.get_pk_key();
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
 
     let mut function_parameters = quote! {};
+    let mut parameter_names: Vec<&String> = vec![];
     let mut pk_value_token = quote! {};
 
     if struct_info.is_generated_pk_value() {
-        for part in &struct_info.pk_value_parts {
-            let part_expr = part.as_expr();
-            quote! {
-                #part_expr: impl std::fmt::Display,
-            }
-            .to_tokens(&mut function_parameters);
-
-            quote! {
-                #part_expr.to_string(),
-            }
-            .to_tokens(&mut pk_value_token);
+        let TemplatePartsTokens {
+            function_parameters: parameters,
+            generate_format_parameters,
+            ..
+        } = generate_template_parts_tokens(struct_info, &struct_info.pk_value_parts, false);
+        for (part, parameter) in struct_info.pk_value_parts.iter().zip(parameters) {
+            parameter_names.push(part);
+            quote! { #parameter, }.to_tokens(&mut function_parameters);
         }
 
         pk_value_token = quote! {
-          #struct_name_expr::generate_pk_value(#pk_value_token).get_partition_key_value()
+            <#struct_type>::pk_value_template().format(&[#(#generate_format_parameters),*])
         };
     } else if struct_info.is_static_pk_value() {
         quote! {
-            <#struct_name_expr as dynorow::traits::has_pk_value::HasStaticPkValue>::get_static_pk_value()
+            <#struct_type as dynorow::traits::has_pk_value::HasStaticPkValue>::get_static_pk_value()
         }
         .to_tokens(&mut pk_value_token);
     }
 
-    let sk_field_name = struct_info.get_sk_field().unwrap().name.as_expr();
-    quote! {
-        #sk_field_name: impl std::fmt::Display,
-    }
-    .to_tokens(&mut function_parameters);
+    // Placeholders used by both templates are taken once.
+    let sk_value_token = match struct_info.get_sk_field() {
+        Some(sk_field) => {
+            let sk_field_name = sk_field.name.as_expr();
+            let sk_type = sk_field.get_expression_type_token();
+            quote! {
+                #sk_field_name: impl Into<#sk_type>,
+            }
+            .to_tokens(&mut function_parameters);
 
-    quote! {
-        impl #struct_name_expr {
-            pub fn generate_composite_key(#function_parameters) -> dynorow::key::KeyValue {
+            quote! { #sk_field_name.into() }
+        }
+        None => {
+            let TemplatePartsTokens {
+                function_parameters: parameters,
+                generate_format_parameters,
+                ..
+            } = generate_template_parts_tokens(struct_info, &struct_info.sk_value_parts, true);
+            for (part, parameter) in struct_info.sk_value_parts.iter().zip(parameters) {
+                if !parameter_names.contains(&part) {
+                    quote! { #parameter, }.to_tokens(&mut function_parameters);
+                }
+            }
+
+            quote! {
+                <#struct_type>::sk_value_template().format(&[#(#generate_format_parameters),*])
+            }
+        }
+    };
 
-                dynorow::key::KeyValue::new_composite_key(
+    quote! {
+        impl #impl_generics #struct_type #where_clause {
+            pub fn generate_composite_key(#function_parameters) -> dynorow::key::TypedKey<Self> {
+                dynorow::key::TypedKey::from_key_value(dynorow::key::KeyValue::new_composite_key(
                     #pk_key.into(),
                     #pk_value_token,
                     #sk_key.into(),
-                    #sk_field_name.to_string(),
-                )
+                    #sk_value_token,
+                ))
             }
         }
     }
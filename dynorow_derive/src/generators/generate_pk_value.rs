@@ -1,22 +1,39 @@
-use quote::{ToTokens, quote};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::spanned::Spanned;
 
 use crate::{AsExpr, StructInfo};
 
+/// `generate_pk_value` is the key of a row when there is no sort key, and returns `TypedKey<Self>`. <br>
+/// With a sort key it only holds the partition key value, for queries and conditions, and stays a `KeyValue`.
+fn generate_pk_value_type_tokens(
+    struct_info: &StructInfo,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    match struct_info.get_sk_key() {
+        Some(_) => (quote! { dynorow::key::KeyValue }, quote! {}),
+        None => (
+            quote! { dynorow::key::TypedKey<Self> },
+            quote! { dynorow::key::TypedKey::from_key_value },
+        ),
+    }
+}
+
 pub fn generate_generate_pk_value(struct_info: &StructInfo) -> proc_macro2::TokenStream {
-    let struct_name = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
+    let (pk_value_type, into_pk_value_type) = generate_pk_value_type_tokens(struct_info);
 
     if !struct_info.is_generated_pk_value() {
         if struct_info.is_static_pk_value() {
             let pk_key = struct_info.pk.clone().unwrap();
 
             return quote! {
-                impl #struct_name {
-                    pub fn generate_pk_value() -> dynorow::key::KeyValue {
-                        dynorow::key::KeyValue::new_partition_key(
+                impl #impl_generics #struct_type #where_clause {
+                    pub fn generate_pk_value() -> #pk_value_type {
+                        #into_pk_value_type(dynorow::key::KeyValue::new_partition_key(
                             #pk_key.into(),
-                            <#struct_name as dynorow::traits::has_pk_value::HasStaticPkValue>::get_static_pk_value()
-                        )
-
+                            <#struct_type as dynorow::traits::has_pk_value::HasStaticPkValue>::get_static_pk_value()
+                        ))
                     }
                 }
             };
@@ -24,44 +41,208 @@ pub fn generate_generate_pk_value(struct_info: &StructInfo) -> proc_macro2::Toke
         return quote! {};
     }
 
-    let mut generate_function_parameters = quote! {};
-    let mut generate_format_parameters = quote! {};
-    let mut as_format_parameters = quote! {};
-    for part in &struct_info.pk_value_parts {
-        let part_expr = part.as_expr();
-        quote! {
-            #part_expr: impl std::fmt::Display,
-        }
-        .to_tokens(&mut generate_function_parameters);
-
-        quote! {
-            self.#part_expr,
-        }
-        .to_tokens(&mut as_format_parameters);
-
-        quote! {
-            #part_expr,
-        }
-        .to_tokens(&mut generate_format_parameters);
-    }
+    let TemplatePartsTokens {
+        function_parameters,
+        generate_format_parameters,
+        as_format_parameters,
+        display_assertions,
+        parts_fields,
+        parse_parts,
+    } = generate_template_parts_tokens(struct_info, &struct_info.pk_value_parts, false);
     let pk_key = struct_info.pk.clone().unwrap();
 
     let pk_value = struct_info.pk_value.clone().unwrap();
+    let escape = generate_template_escape_token(struct_info);
+    let parts_name_expr = struct_info.pk_parts_struct_name().as_expr();
+
+    let (parse_token, matches_template_token) = match struct_info
+        .has_parsable_parts(&struct_info.pk_value_parts)
+    {
+        true => (
+            quote! {
+                /// Placeholder values of the pk value, returned by `parse_pk_value`.
+                #[derive(Debug, Clone)]
+                pub struct #parts_name_expr {
+                    #parts_fields
+                }
+
+                impl #impl_generics #struct_type #where_clause {
+                    pub fn parse_pk_value(value: &str) -> Result<#parts_name_expr, dynorow::error::Error> {
+                        let parts = Self::pk_value_template().parse(value)?;
+                        Ok(#parts_name_expr {
+                            #parse_parts
+                        })
+                    }
+                }
+
+                impl #impl_generics dynorow::traits::parse_pk_value::ParsePkValue for #struct_type #where_clause {
+                    type Parts = #parts_name_expr;
+
+                    fn parse_pk_value(value: &str) -> Result<#parts_name_expr, dynorow::error::Error> {
+                        <#struct_type>::parse_pk_value(value)
+                    }
+                }
+            },
+            quote! { <#struct_type>::parse_pk_value(value).is_ok() },
+        ),
+        false => (
+            quote! {},
+            quote! { <#struct_type>::pk_value_template().matches(value) },
+        ),
+    };
+
     quote! {
-        impl #struct_name {
-            pub fn generate_pk_value(#generate_function_parameters) -> dynorow::key::KeyValue {
-                dynorow::key::KeyValue::new_partition_key(
-                    #pk_key.into(),
-                    format!(#pk_value, #generate_format_parameters))
+        // Reports placeholder fields without `Display` at the field instead of inside the generated functions.
+        const _: fn() = || {
+            fn assert_display<T: std::fmt::Display + ?Sized>() {}
+            #display_assertions
+        };
+
+        impl #impl_generics #struct_type #where_clause {
+            pub fn pk_value_template() -> dynorow::key_template::KeyTemplate {
+                dynorow::key_template::KeyTemplate::new(#pk_value, #escape)
+            }
 
+            pub fn generate_pk_value(#(#function_parameters),*) -> #pk_value_type {
+                #into_pk_value_type(dynorow::key::KeyValue::new_partition_key(
+                    #pk_key.into(),
+                    Self::pk_value_template().format(&[#(#generate_format_parameters),*])))
             }
 
             pub fn as_pk_value(&self) -> dynorow::key::KeyValue {
                 dynorow::key::KeyValue::new_partition_key(
                     #pk_key.into(),
-                    format!(#pk_value, #as_format_parameters))
+                    Self::pk_value_template().format(&[#as_format_parameters]))
+
+            }
+        }
+
+        #parse_token
 
+        impl #impl_generics dynorow::traits::matches_template::MatchesTemplate for #struct_type #where_clause {
+            fn matches_template(value: &str) -> bool {
+                #matches_template_token
             }
         }
     }
 }
+
+/// Tokens for the placeholders of a key template, shared by the pk and sk templates.
+pub struct TemplatePartsTokens {
+    /// `part: impl Display` parameters of the generate functions, one per placeholder.
+    pub function_parameters: Vec<proc_macro2::TokenStream>,
+    /// Formatted values of the parameters, borrowing them, one per placeholder.
+    pub generate_format_parameters: Vec<proc_macro2::TokenStream>,
+    /// Formatted values of the fields of `self`.
+    pub as_format_parameters: proc_macro2::TokenStream,
+    pub display_assertions: proc_macro2::TokenStream,
+    /// Fields of the generated parts struct.
+    pub parts_fields: proc_macro2::TokenStream,
+    /// Field initializers of the parts struct, reading the `parts` vector returned by `KeyTemplate::parse`.
+    pub parse_parts: proc_macro2::TokenStream,
+}
+
+/// Numbers of sk templates are zero-padded, see `FieldInfo::sort_key_width`.
+pub fn generate_template_parts_tokens(
+    struct_info: &StructInfo,
+    template_parts: &[String],
+    is_sort_key: bool,
+) -> TemplatePartsTokens {
+    let mut function_parameters = vec![];
+    let mut generate_format_parameters = vec![];
+    let mut as_format_parameters = quote! {};
+    let mut display_assertions = quote! {};
+    let mut parts_fields = quote! {};
+    let mut parse_parts = quote! {};
+    for (index, part) in template_parts.iter().enumerate() {
+        let part_expr = part.as_expr();
+        let part_type = generate_template_part_type(struct_info, part);
+        let field = struct_info.find_in_handled_fields(part).unwrap();
+        let field_syn_type = &field.field_syn_type;
+        let pad = |value: proc_macro2::TokenStream| match field.sort_key_width() {
+            Some(width) if is_sort_key => {
+                quote! { dynorow::key_template::pad_sort_key_part(#value, #width) }
+            }
+            _ => value,
+        };
+        function_parameters.push(quote! { #part_expr: #part_type });
+
+        quote! {
+            pub #part_expr: #field_syn_type,
+        }
+        .to_tokens(&mut parts_fields);
+
+        match get_timestamp_format_token(struct_info, part) {
+            Some(format) => {
+                let as_format_parameter = pad(quote! {
+                    dynorow::Timestamp::to_timestamp_string(&self.#part_expr, #format)
+                });
+                quote! { #as_format_parameter, }.to_tokens(&mut as_format_parameters);
+
+                generate_format_parameters.push(pad(quote! {
+                    dynorow::Timestamp::to_timestamp_string(&#part_expr, #format)
+                }));
+
+                quote! {
+                    #part_expr: dynorow::key_template::parse_timestamp_key_part::<#field_syn_type>(&parts[#index], #format)?,
+                }
+                .to_tokens(&mut parse_parts);
+            }
+            None => {
+                quote_spanned! {field_syn_type.span()=>
+                    assert_display::<#field_syn_type>();
+                }
+                .to_tokens(&mut display_assertions);
+
+                let as_format_parameter = pad(quote! { self.#part_expr.to_string() });
+                quote! { #as_format_parameter, }.to_tokens(&mut as_format_parameters);
+
+                generate_format_parameters.push(pad(quote! { #part_expr.to_string() }));
+
+                let parse_token = quote_spanned! {field_syn_type.span()=>
+                    dynorow::key_template::parse_key_part::<#field_syn_type>(&parts[#index])?
+                };
+                quote! {
+                    #part_expr: #parse_token,
+                }
+                .to_tokens(&mut parse_parts);
+            }
+        }
+    }
+
+    TemplatePartsTokens {
+        function_parameters,
+        generate_format_parameters,
+        as_format_parameters,
+        display_assertions,
+        parts_fields,
+        parse_parts,
+    }
+}
+
+pub fn generate_template_escape_token(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    match struct_info.template_escape {
+        Some(x) => quote! { Some(#x) },
+        None => quote! { None },
+    }
+}
+
+fn get_timestamp_format_token(
+    struct_info: &StructInfo,
+    part: &str,
+) -> Option<proc_macro2::TokenStream> {
+    struct_info
+        .find_in_handled_fields(part)
+        .and_then(|x| x.get_timestamp_format_token())
+}
+
+/// Timestamp parts are formatted the same way they are stored, anything else through `Display`.
+pub fn generate_template_part_type(
+    struct_info: &StructInfo,
+    part: &str,
+) -> proc_macro2::TokenStream {
+    match get_timestamp_format_token(struct_info, part) {
+        Some(_) => quote! { impl dynorow::Timestamp },
+        None => quote! { impl std::fmt::Display },
+    }
+}
@@ -0,0 +1,136 @@
+use quote::{ToTokens, quote};
+
+use crate::{
+    AsExpr, StructInfo,
+    generators::generate_pk_value::{
+        TemplatePartsTokens, generate_template_escape_token, generate_template_parts_tokens,
+    },
+};
+
+pub fn generate_generate_sk_value(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    let Some(sk_value) = struct_info.sk_value.clone() else {
+        return quote! {};
+    };
+
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
+    let sk_key = struct_info.get_sk_key().unwrap();
+    let escape = generate_template_escape_token(struct_info);
+    let parts_name_expr = struct_info.sk_parts_struct_name().as_expr();
+
+    let TemplatePartsTokens {
+        function_parameters,
+        generate_format_parameters,
+        as_format_parameters,
+        display_assertions,
+        parts_fields,
+        parse_parts,
+    } = generate_template_parts_tokens(struct_info, &struct_info.sk_value_parts, true);
+
+    let parse_parts_token = match struct_info.sk_value_parts.is_empty() {
+        true => quote! {
+            Self::sk_value_template().parse(value)?;
+            Ok(#parts_name_expr {})
+        },
+        false => quote! {
+            let parts = Self::sk_value_template().parse(value)?;
+            Ok(#parts_name_expr {
+                #parse_parts
+            })
+        },
+    };
+
+    let (parse_token, matches_template_token) = match struct_info
+        .has_parsable_parts(&struct_info.sk_value_parts)
+    {
+        true => (
+            quote! {
+                /// Placeholder values of the sk value, returned by `parse_sk_value`.
+                #[derive(Debug, Clone)]
+                pub struct #parts_name_expr {
+                    #parts_fields
+                }
+
+                impl #impl_generics #struct_type #where_clause {
+                    pub fn parse_sk_value(value: &str) -> Result<#parts_name_expr, dynorow::error::Error> {
+                        #parse_parts_token
+                    }
+                }
+
+                impl #impl_generics dynorow::traits::parse_sk_value::ParseSkValue for #struct_type #where_clause {
+                    type Parts = #parts_name_expr;
+
+                    fn parse_sk_value(value: &str) -> Result<#parts_name_expr, dynorow::error::Error> {
+                        <#struct_type>::parse_sk_value(value)
+                    }
+                }
+            },
+            quote! { <#struct_type>::parse_sk_value(value).is_ok() },
+        ),
+        false => (
+            quote! {},
+            quote! { <#struct_type>::sk_value_template().matches(value) },
+        ),
+    };
+
+    // One helper per leading prefix, the literal before the first placeholder and then every placeholder but the last,
+    // each followed by its separator.
+    let mut begins_with_tokens = quote! {};
+    let literal_prefix = sk_value.split("{}").next().unwrap_or_default();
+    for count in 0..struct_info.sk_value_parts.len() {
+        if count == 0 && literal_prefix.is_empty() {
+            continue;
+        }
+
+        let function_name_expr = match count {
+            0 => "sk_begins_with".to_string(),
+            _ => format!("sk_begins_with_{}", struct_info.sk_value_parts[count - 1]),
+        }
+        .as_expr();
+        let parameters = &function_parameters[..count];
+        let format_parameters = &generate_format_parameters[..count];
+        quote! {
+            /// `begins_with` condition on the sort key, to be combined with the pk condition of a query.
+            pub fn #function_name_expr(#(#parameters),*) -> dynorow::ConditionalExpression {
+                dynorow::BuildConditionalExpression::begins_with(
+                    String::from(#sk_key),
+                    Self::sk_value_template().format(&[#(#format_parameters),*]),
+                )
+            }
+        }
+        .to_tokens(&mut begins_with_tokens);
+    }
+
+    quote! {
+        // Reports placeholder fields without `Display` at the field instead of inside the generated functions.
+        const _: fn() = || {
+            fn assert_display<T: std::fmt::Display + ?Sized>() {}
+            #display_assertions
+        };
+
+        impl #impl_generics #struct_type #where_clause {
+            pub fn sk_value_template() -> dynorow::key_template::KeyTemplate {
+                dynorow::key_template::KeyTemplate::new(#sk_value, #escape)
+            }
+
+            pub fn generate_sk_value(#(#function_parameters),*) -> String {
+                Self::sk_value_template().format(&[#(#generate_format_parameters),*])
+            }
+
+            pub fn as_sk_value(&self) -> String {
+                Self::sk_value_template().format(&[#as_format_parameters])
+            }
+
+            #begins_with_tokens
+        }
+
+        #parse_token
+
+        impl #impl_generics dynorow::traits::matches_template::MatchesSkTemplate for #struct_type #where_clause {
+            fn matches_sk_template(value: &str) -> bool {
+                #matches_template_token
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use quote::quote;
+
+use crate::StructInfo;
+
+pub fn generate_has_entity_type(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    let (Some(entity_type), Some(entity_type_attr)) =
+        (&struct_info.entity_type, struct_info.get_entity_type_attr())
+    else {
+        return quote! {};
+    };
+
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
+    quote! {
+        impl #impl_generics dynorow::traits::has_entity_type::HasEntityType for #struct_type #where_clause {
+            fn get_entity_type_attribute() -> String {
+                String::from(#entity_type_attr)
+            }
+
+            fn get_entity_type() -> String {
+                String::from(#entity_type)
+            }
+        }
+    }
+}
@@ -1,34 +1,63 @@
 use quote::quote;
 
-use crate::{struct_info::StructInfo, utils::as_expr::AsExpr};
+use crate::struct_info::StructInfo;
 
 pub fn generate_has_key_token(struct_info: &StructInfo) -> proc_macro2::TokenStream {
-    let struct_name = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let predicates: Vec<proc_macro2::TokenStream> =
+        [struct_info.get_pk_field(), struct_info.get_sk_field()]
+            .into_iter()
+            .flatten()
+            .filter(|x| {
+                x.has_scalar_attribute_trait()
+                    && struct_info.generics.uses_type_params(&x.field_syn_type)
+            })
+            .map(|x| {
+                let field_syn_type = &x.field_syn_type;
+                quote! { #field_syn_type: dynorow::traits::scalar_attribute::ScalarAttribute }
+            })
+            .collect();
+    let where_clause = struct_info.generics.where_clause(&predicates);
     let pk_key = struct_info.get_pk_key();
 
+    // Struct level pk and sk values are always strings.
+    let string_type = quote! { dynorow::aws_sdk_dynamodb::types::ScalarAttributeType::S };
+    let pk_type = match struct_info.get_pk_field() {
+        Some(pk_field) => pk_field.get_scalar_attribute_type_token(),
+        None => string_type.clone(),
+    };
+
     let token = match struct_info.get_sk_key() {
         Some(sk_key) => {
+            let sk_type = match struct_info.get_sk_field() {
+                Some(sk_field) => sk_field.get_scalar_attribute_type_token(),
+                None => string_type,
+            };
             quote! {
                 dynorow::key::Key::CompositeKey {
                     partition_key: String::from(#pk_key),
-                    sort_key: String::from(#sk_key)
+                    partition_key_type: #pk_type,
+                    sort_key: String::from(#sk_key),
+                    sort_key_type: #sk_type,
                 }
             }
-        },
+        }
         None => {
             quote! {
                 dynorow::key::Key::PartitionKey {
-                    key: String::from(#pk_key)
+                    key: String::from(#pk_key),
+                    key_type: #pk_type,
                 }
             }
-        },
+        }
     };
 
     quote! {
-        impl dynorow::traits::has_key::HasKey for #struct_name {
+        impl #impl_generics dynorow::traits::has_key::HasKey for #struct_type #where_clause {
             fn get_key() -> dynorow::key::Key {
-                #token          
+                #token
             }
         }
     }
-}
\ No newline at end of file
+}
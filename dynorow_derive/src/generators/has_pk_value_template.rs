@@ -1,16 +1,18 @@
 use quote::quote;
 
-use crate::{AsExpr, StructInfo};
+use crate::StructInfo;
 
 pub fn generate_has_pk_value_template(struct_info: &StructInfo) -> proc_macro2::TokenStream {
     if !struct_info.is_generated_pk_value() {
         return quote! {};
     }
 
-    let struct_name = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
     let pk_value = struct_info.pk_value.clone().unwrap();
     quote! {
-        impl dynorow::traits::has_pk_value_template::HasPkValueTemplate for #struct_name {
+        impl #impl_generics dynorow::traits::has_pk_value_template::HasPkValueTemplate for #struct_type #where_clause {
             fn get_pk_value_template() -> String {
                 #pk_value.into()
             }
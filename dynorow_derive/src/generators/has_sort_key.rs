@@ -1,15 +1,17 @@
 use quote::quote;
 
-use crate::{AsExpr, StructInfo};
+use crate::StructInfo;
 
 pub fn generate_has_sort_key(struct_info: &StructInfo) -> proc_macro2::TokenStream {
     let Some(sk_key) = struct_info.get_sk_key() else {
         return quote! {};
     };
 
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
     quote! {
-        impl dynorow::traits::has_sort_key::HasSortKey for #struct_name_expr {
+        impl #impl_generics dynorow::traits::has_sort_key::HasSortKey for #struct_type #where_clause {
             fn get_sort_key() -> String {
                 #sk_key.into()
             }
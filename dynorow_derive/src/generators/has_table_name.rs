@@ -3,19 +3,20 @@ use quote::quote;
 use crate::{struct_info::StructInfo, utils::as_expr::AsExpr};
 
 pub fn generate_has_table_name(struct_info: &StructInfo) -> proc_macro2::TokenStream {
-
     let Some(table_name_provider) = &struct_info.table_name_provider else {
         return quote! {};
     };
 
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
     let table_name_provider_expr = table_name_provider.as_expr();
 
     quote! {
-        impl dynorow::traits::has_table_name::HasTableName for #struct_name_expr {
+        impl #impl_generics dynorow::traits::has_table_name::HasTableName for #struct_type #where_clause {
             fn get_table_name() -> String {
                 #table_name_provider_expr.clone()
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,62 @@
+use quote::quote;
+
+use crate::{
+    StructInfo,
+    generators::generate_pk_value::{TemplatePartsTokens, generate_template_parts_tokens},
+};
+
+/// `key_condition` takes what is needed to build the partition key value, nothing for a static pk value,
+/// the placeholders of a templated pk value or the value of the pk field.
+pub fn generate_key_condition(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
+
+    let sort_key_type = match (
+        struct_info.get_sk_field(),
+        struct_info.struct_has_sk_value(),
+    ) {
+        (Some(sk_field), _) => sk_field.get_expression_type_token(),
+        (None, true) => quote! { String },
+        (None, false) => quote! { () },
+    };
+    let builder_type = quote! {
+        dynorow::KeyConditionBuilder<#struct_type, #sort_key_type>
+    };
+
+    let (parameters, partition_key_value) = if struct_info.is_static_pk_value() {
+        (
+            vec![],
+            quote! {
+                <#struct_type as dynorow::traits::has_pk_value::HasStaticPkValue>::get_static_pk_value()
+            },
+        )
+    } else if struct_info.is_generated_pk_value() {
+        let TemplatePartsTokens {
+            function_parameters,
+            generate_format_parameters,
+            ..
+        } = generate_template_parts_tokens(struct_info, &struct_info.pk_value_parts, false);
+        (
+            function_parameters,
+            quote! { Self::pk_value_template().format(&[#(#generate_format_parameters),*]) },
+        )
+    } else {
+        let pk_type = struct_info
+            .get_pk_field()
+            .unwrap()
+            .get_expression_type_token();
+        (
+            vec![quote! { partition_key_value: #pk_type }],
+            quote! { partition_key_value },
+        )
+    };
+
+    quote! {
+        impl #impl_generics #struct_type #where_clause {
+            pub fn key_condition(#(#parameters),*) -> #builder_type {
+                dynorow::KeyConditionBuilder::new(#partition_key_value)
+            }
+        }
+    }
+}
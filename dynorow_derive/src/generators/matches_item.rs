@@ -0,0 +1,71 @@
+use quote::quote;
+
+use crate::StructInfo;
+
+/// Templates are matched exactly, a pk or sk field can hold any value so only its presence is checked. <br>
+/// The entity type has to match too when it is set.
+pub fn generate_matches_item(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
+    let pk_key = struct_info.get_pk_key();
+
+    let pk_token = if struct_info.is_static_pk_value() {
+        quote! {
+            item.get(#pk_key)
+                .and_then(|x| x.as_s().ok())
+                .is_some_and(|x| *x == <Self as dynorow::traits::has_pk_value::HasStaticPkValue>::get_static_pk_value())
+        }
+    } else if struct_info.is_generated_pk_value() {
+        quote! {
+            item.get(#pk_key)
+                .and_then(|x| x.as_s().ok())
+                .is_some_and(|x| <Self as dynorow::traits::matches_template::MatchesTemplate>::matches_template(x))
+        }
+    } else {
+        quote! { item.contains_key(#pk_key) }
+    };
+
+    let sk_token = match (struct_info.get_sk_key(), struct_info.struct_has_sk_value()) {
+        (Some(sk_key), true) => quote! {
+            item.get(#sk_key)
+                .and_then(|x| x.as_s().ok())
+                .is_some_and(|x| <Self as dynorow::traits::matches_template::MatchesSkTemplate>::matches_sk_template(x))
+        },
+        (Some(sk_key), false) => quote! { item.contains_key(#sk_key) },
+        (None, _) => quote! { true },
+    };
+
+    let matches_by_presence = !struct_info.is_static_pk_value()
+        && !struct_info.is_generated_pk_value()
+        && !struct_info.struct_has_sk_value()
+        && struct_info.entity_type.is_none();
+    let sort_key_token = match struct_info.get_sk_key() {
+        Some(sk_key) => quote! { Some(#sk_key) },
+        None => quote! { None },
+    };
+    let table_name_provider_token = match &struct_info.table_name_provider {
+        Some(table_name_provider) => quote! { Some(#table_name_provider) },
+        None => quote! { None },
+    };
+
+    let entity_type_token = match struct_info.entity_type {
+        Some(_) => quote! {
+            && <Self as dynorow::traits::has_entity_type::HasEntityType>::matches_entity_type(item)
+        },
+        None => quote! {},
+    };
+
+    quote! {
+        impl #impl_generics dynorow::traits::matches_item::MatchesItem for #struct_type #where_clause {
+            const MATCHES_BY_PRESENCE: bool = #matches_by_presence;
+            const PARTITION_KEY: &'static str = #pk_key;
+            const SORT_KEY: Option<&'static str> = #sort_key_token;
+            const TABLE_NAME_PROVIDER: Option<&'static str> = #table_name_provider_token;
+
+            fn matches_item(item: &std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>) -> bool {
+                #pk_token && #sk_token #entity_type_token
+            }
+        }
+    }
+}
@@ -2,14 +2,27 @@ pub mod as_attribute_values;
 pub mod as_key_value;
 pub mod as_projection;
 pub mod conditional_expression_builder;
+pub mod dyno_collection;
+pub mod dyno_entity;
+pub mod dyno_enum;
+pub mod dyno_projection;
+pub mod dyno_value;
+pub mod flattened_builder;
 pub mod generate_composite_key;
 pub mod generate_pk_value;
+pub mod generate_sk_value;
+pub mod has_entity_type;
 pub mod has_key;
 pub mod has_pk_value_template;
 pub mod has_sort_key;
 pub mod has_static_pk_value;
 pub mod has_table_name;
+pub mod key_condition;
+pub mod matches_item;
+pub mod projection_fields;
+pub mod tagged_enum;
 pub mod try_from_attribute_value_hashmap;
 pub mod try_from_get_item_output;
+pub mod typed_key;
 pub mod update_expression_builder;
 pub mod update_expression_builder_for_dynomap;
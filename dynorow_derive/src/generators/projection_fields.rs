@@ -0,0 +1,91 @@
+use quote::{ToTokens, quote};
+
+use crate::{
+    generators::try_from_attribute_value_hashmap::{
+        generate_attribute_value_to_fields_token, generate_from_attribute_value_predicates,
+    },
+    projection_info::{
+        projection_entity_type_check_name, projection_entity_type_key_name, projection_key_name,
+        projection_reader_name,
+    },
+    struct_info::StructInfo,
+    utils::as_expr::AsExpr,
+};
+
+/// One reader and one attribute name function per handled field, `DynoProjection` structs of this row call them. <br>
+/// A projection field without a parent field, or with another type, fails to compile on these calls. <br>
+/// The entity type functions let the projections read and check the entity type of the row.
+pub fn generate_projection_fields(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause =
+        struct_info
+            .generics
+            .where_clause(&generate_from_attribute_value_predicates(
+                struct_info.get_handled_fields(),
+                &struct_info.generics,
+            ));
+
+    let mut functions = quote! {};
+    for field in struct_info.get_handled_fields() {
+        let field_name_expr = field.name.as_expr();
+        let field_type_token = field.get_type_token();
+        let field_key_str = field.get_key_str();
+        let reader_name_expr = projection_reader_name(&field.name).as_expr();
+        let key_name_expr = projection_key_name(&field.name).as_expr();
+        let field_token = generate_attribute_value_to_fields_token(vec![field]);
+        quote! {
+            #[doc(hidden)]
+            pub fn #reader_name_expr(
+                items: &mut std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>,
+            ) -> Result<#field_type_token, dynorow::error::Error> {
+                #field_token
+                Ok(#field_name_expr)
+            }
+
+            #[doc(hidden)]
+            pub fn #key_name_expr() -> &'static str {
+                #field_key_str
+            }
+        }
+        .to_tokens(&mut functions);
+    }
+
+    let check_name_expr = projection_entity_type_check_name().as_expr();
+    let entity_type_key_name_expr = projection_entity_type_key_name().as_expr();
+    let (check_token, entity_type_key_token) = match (
+        &struct_info.entity_type,
+        struct_info.get_entity_type_attr(),
+    ) {
+        (Some(entity_type), Some(entity_type_attr)) => (
+            quote! {
+                if !<Self as dynorow::traits::has_entity_type::HasEntityType>::matches_entity_type(items) {
+                    return Err(dynorow::error::Error::entity_type_mismatch(#entity_type, items.get(#entity_type_attr)));
+                }
+            },
+            quote! { Some(#entity_type_attr) },
+        ),
+        _ => (quote! {}, quote! { None }),
+    };
+    quote! {
+        #[doc(hidden)]
+        pub fn #check_name_expr(
+            items: &std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>,
+        ) -> Result<(), dynorow::error::Error> {
+            #check_token
+            Ok(())
+        }
+
+        #[doc(hidden)]
+        pub fn #entity_type_key_name_expr() -> Option<&'static str> {
+            #entity_type_key_token
+        }
+    }
+    .to_tokens(&mut functions);
+
+    quote! {
+        impl #impl_generics #struct_type #where_clause {
+            #functions
+        }
+    }
+}
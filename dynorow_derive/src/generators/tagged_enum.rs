@@ -0,0 +1,250 @@
+use quote::{ToTokens, quote};
+
+use crate::{
+    enum_info::tagged_enum_info::{TaggedEnumInfo, TaggedVariantInfo, TaggedVariantKind},
+    generators::{
+        as_attribute_values::{
+            generate_field_attribute_value_token, generate_into_attribute_value_predicates,
+        },
+        try_from_attribute_value_hashmap::{
+            generate_attribute_value_to_fields_token, generate_from_attribute_value_predicates,
+        },
+    },
+    utils::as_expr::AsExpr,
+};
+
+pub fn generate_tagged_enum(enum_info: &TaggedEnumInfo) -> proc_macro2::TokenStream {
+    let impl_generics = enum_info.generics.impl_generics();
+    let enum_type = enum_info.enum_type();
+    let where_clause = enum_info.generics.where_clause(&[]);
+    let as_attribute_key_values = generate_as_attribute_key_values(enum_info);
+    let try_from_attribute_value_hashmap = generate_try_from_attribute_value_hashmap(enum_info);
+
+    quote! {
+        #as_attribute_key_values
+
+        #try_from_attribute_value_hashmap
+
+        impl #impl_generics dynorow::traits::dyno_map_trait::DynoMapTrait for #enum_type #where_clause {}
+    }
+}
+
+fn generate_as_attribute_key_values(enum_info: &TaggedEnumInfo) -> proc_macro2::TokenStream {
+    let enum_name_expr = enum_info.enum_name.as_expr();
+    let tag = &enum_info.tag;
+
+    let mut arms_token = quote! {};
+    for variant in &enum_info.variants {
+        let variant_name_expr = variant.name.as_expr();
+        let value = &variant.value;
+
+        let mut fields_token = quote! {};
+        for field in variant.get_handled_fields() {
+            let field_name_expr = field.name.as_expr();
+            let key_str = field.get_key_str();
+            let attribute_value_token =
+                generate_field_attribute_value_token(field, quote! { #field_name_expr });
+            quote! {
+                fields.insert(#key_str.into(), #attribute_value_token);
+            }
+            .to_tokens(&mut fields_token);
+        }
+
+        let pattern_token = match &variant.kind {
+            TaggedVariantKind::Unit => quote! { #enum_name_expr::#variant_name_expr },
+            TaggedVariantKind::Named(_) => {
+                let names = variant
+                    .get_handled_fields()
+                    .into_iter()
+                    .map(|x| x.name.as_expr());
+                quote! { #enum_name_expr::#variant_name_expr { #(#names,)* .. } }
+            }
+            TaggedVariantKind::Newtype(_) => quote! { #enum_name_expr::#variant_name_expr(inner) },
+        };
+
+        let content_token = match (&enum_info.content, &variant.kind) {
+            (_, TaggedVariantKind::Unit) => quote! {},
+            (Some(content), TaggedVariantKind::Newtype(ty)) => quote! {
+                result.insert(
+                    #content.into(),
+                    <#ty as dynorow::traits::into_attribute_value::IntoAttributeValue>::try_into_attribute_value(inner)?,
+                );
+            },
+            (Some(content), _) => quote! {
+                let mut fields = std::collections::HashMap::<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>::new();
+                #fields_token
+                result.insert(
+                    #content.into(),
+                    dynorow::aws_sdk_dynamodb::types::AttributeValue::M(fields),
+                );
+            },
+            (None, _) => quote! {
+                let fields = &mut result;
+                #fields_token
+            },
+        };
+
+        quote! {
+            #pattern_token => {
+                result.insert(
+                    #tag.into(),
+                    dynorow::aws_sdk_dynamodb::types::AttributeValue::S(#value.into()),
+                );
+                #content_token
+            }
+        }
+        .to_tokens(&mut arms_token);
+    }
+
+    let impl_generics = enum_info.generics.impl_generics();
+    let enum_type = enum_info.enum_type();
+    let mut predicates = generate_into_attribute_value_predicates(
+        enum_info.get_handled_fields(),
+        &enum_info.generics,
+    );
+    for ty in enum_info.get_newtype_types() {
+        if enum_info.generics.uses_type_params(ty) {
+            predicates
+                .push(quote! { #ty: dynorow::traits::into_attribute_value::IntoAttributeValue });
+        }
+    }
+    let where_clause = enum_info.generics.where_clause(&predicates);
+
+    quote! {
+        impl #impl_generics dynorow::traits::as_attribute_key_values::AsAttributeKeyValues for #enum_type #where_clause {
+            fn try_as_attribute_key_values(&self) -> Result<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>, dynorow::error::Error> {
+                let mut result = std::collections::HashMap::<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>::new();
+                match self {
+                    #arms_token
+                }
+                Ok(result)
+            }
+        }
+    }
+}
+
+fn generate_try_from_attribute_value_hashmap(
+    enum_info: &TaggedEnumInfo,
+) -> proc_macro2::TokenStream {
+    let enum_name_str = enum_info.enum_name.clone();
+    let tag = &enum_info.tag;
+
+    let mut arms_token = quote! {};
+    for variant in &enum_info.variants {
+        let value = &variant.value;
+        let variant_token = generate_variant_token(enum_info, variant);
+        quote! {
+            #value => { #variant_token }
+        }
+        .to_tokens(&mut arms_token);
+    }
+
+    let impl_generics = enum_info.generics.impl_generics();
+    let enum_type = enum_info.enum_type();
+    let mut predicates = generate_from_attribute_value_predicates(
+        enum_info.get_handled_fields(),
+        &enum_info.generics,
+    );
+    for ty in enum_info.get_newtype_types() {
+        if enum_info.generics.uses_type_params(ty) {
+            predicates.push(
+                quote! { #ty: dynorow::traits::from_attribute_value::FromAttributeValue<#ty> },
+            );
+        }
+    }
+    let where_clause = enum_info.generics.where_clause(&predicates);
+
+    quote! {
+        impl #impl_generics TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>> for #enum_type #where_clause {
+            type Error = dynorow::error::Error;
+
+            fn try_from(mut items: std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                let tag = match items.remove(#tag) {
+                    Some(dynorow::aws_sdk_dynamodb::types::AttributeValue::S(tag)) => tag,
+                    Some(value) => {
+                        return Err(dynorow::error::Error::parse_error(
+                            value,
+                            #enum_name_str,
+                            format!("Tag `{}` should be a string.", #tag),
+                        ))
+                    }
+                    None => return Err(dynorow::error::Error::value_not_found(#tag)),
+                };
+
+                match tag.as_str() {
+                    #arms_token
+                    _ => Err(dynorow::error::Error::parse_error(
+                        dynorow::aws_sdk_dynamodb::types::AttributeValue::S(tag.clone()),
+                        #enum_name_str,
+                        format!("Unknown variant `{}` for enum `{}`.", tag, #enum_name_str),
+                    )),
+                }
+            }
+        }
+    }
+}
+
+fn generate_variant_token(
+    enum_info: &TaggedEnumInfo,
+    variant: &TaggedVariantInfo,
+) -> proc_macro2::TokenStream {
+    let enum_name_expr = enum_info.enum_name.as_expr();
+    let enum_name_str = enum_info.enum_name.clone();
+    let variant_name_expr = variant.name.as_expr();
+
+    match &variant.kind {
+        TaggedVariantKind::Unit => quote! {
+            Ok(#enum_name_expr::#variant_name_expr)
+        },
+        TaggedVariantKind::Newtype(ty) => {
+            let content = enum_info.content.clone().unwrap();
+            quote! {
+                let Some(inner) = items.remove(#content) else {
+                    return Err(dynorow::error::Error::value_not_found(#content));
+                };
+                Ok(#enum_name_expr::#variant_name_expr(
+                    <#ty as dynorow::traits::from_attribute_value::FromAttributeValue<#ty>>::from_attribute_value(inner)?,
+                ))
+            }
+        }
+        TaggedVariantKind::Named(fields) => {
+            let field_tokens =
+                generate_attribute_value_to_fields_token(variant.get_handled_fields());
+            let mut return_token = quote! {};
+            for field in fields {
+                let field_name_expr = field.name.as_expr();
+                match field.ignore {
+                    true => {
+                        quote! {#field_name_expr: Default::default(),}.to_tokens(&mut return_token)
+                    }
+                    false => quote! {#field_name_expr,}.to_tokens(&mut return_token),
+                }
+            }
+
+            let content_token = match &enum_info.content {
+                Some(content) => quote! {
+                    let mut items = match items.remove(#content) {
+                        Some(dynorow::aws_sdk_dynamodb::types::AttributeValue::M(items)) => items,
+                        Some(value) => {
+                            return Err(dynorow::error::Error::parse_error(
+                                value,
+                                #enum_name_str,
+                                format!("Content `{}` should be a map.", #content),
+                            ))
+                        }
+                        None => return Err(dynorow::error::Error::value_not_found(#content)),
+                    };
+                },
+                None => quote! {},
+            };
+
+            quote! {
+                #content_token
+                #field_tokens
+                Ok(#enum_name_expr::#variant_name_expr {
+                    #return_token
+                })
+            }
+        }
+    }
+}
@@ -1,43 +1,101 @@
 use quote::{ToTokens, quote};
 
 use crate::{
-    struct_info::{StructInfo, field_info::FieldInfo},
-    utils::as_expr::AsExpr,
+    struct_info::{
+        StructInfo,
+        field_info::{FieldDefault, FieldInfo},
+    },
+    utils::{as_expr::AsExpr, generics::GenericsInfo},
 };
 
 pub fn generate_try_from_attribute_value_hashmap(
     struct_info: &StructInfo,
 ) -> proc_macro2::TokenStream {
-    let struct_name = struct_info.struct_name.as_expr();
-    let field_tokens = generate_attribute_value_to_fields_token(&struct_info);
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let mut predicates = generate_from_attribute_value_predicates(
+        struct_info.get_handled_fields(),
+        &struct_info.generics,
+    );
+    for field in struct_info.get_flattened_fields() {
+        let field_syn_type = &field.field_syn_type;
+        if struct_info.generics.uses_type_params(field_syn_type) {
+            predicates.push(quote! {
+                #field_syn_type: TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>, Error = dynorow::error::Error>
+            });
+        }
+    }
+    let where_clause = struct_info.generics.where_clause(&predicates);
+    let field_tokens = generate_attribute_value_to_fields_token(struct_info.get_handled_fields());
+    let flattened_field_tokens =
+        generate_flattened_fields_token(struct_info.get_flattened_fields());
     let return_token = generate_return(struct_info);
+    let entity_type_token = match (&struct_info.entity_type, struct_info.get_entity_type_attr()) {
+        (Some(entity_type), Some(entity_type_attr)) => quote! {
+            if !<Self as dynorow::traits::has_entity_type::HasEntityType>::matches_entity_type(&items) {
+                return Err(dynorow::error::Error::entity_type_mismatch(#entity_type, items.get(#entity_type_attr)));
+            }
+        },
+        _ => quote! {},
+    };
     quote! {
-        impl TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>> for #struct_name {
+        impl #impl_generics TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>> for #struct_type #where_clause {
             type Error = dynorow::error::Error;
 
             fn try_from(mut items: std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                #entity_type_token
+
                 #field_tokens
 
+                #flattened_field_tokens
+
                 #return_token
             }
         }
     }
 }
 
-fn generate_attribute_value_to_fields_token(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+/// Bounds for the fields using type parameters, see `generate_into_attribute_value_predicates`.
+pub fn generate_from_attribute_value_predicates(
+    fields: Vec<&FieldInfo>,
+    generics: &GenericsInfo,
+) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .into_iter()
+        .filter(|x| x.has_default_conversion() && generics.uses_type_params(&x.field_syn_type))
+        .map(|x| {
+            let field_type_token = x.get_type_token();
+            quote! { #field_type_token: dynorow::traits::from_attribute_value::FromAttributeValue<#field_type_token> }
+        })
+        .collect()
+}
+
+/// Declares one local variable per field, read from the `items` hash map in scope.
+pub fn generate_attribute_value_to_fields_token(
+    fields: Vec<&FieldInfo>,
+) -> proc_macro2::TokenStream {
     let mut tokens: proc_macro2::TokenStream = quote::quote! {};
-    for field in struct_info.get_handled_fields() {
+    for field in fields {
         let field_name_expr = field.name.as_expr();
         let field_name_str = field.name.to_string();
         let field_type_token = field.get_type_token();
         let field_key_str = field.get_key_str();
         let attribute_parse_token = generate_attribute_parse_token(&field);
-        let return_token = match field.is_option {
-            true => quote! {None},
-            false => quote! {return Err(dynorow::error::Error::value_not_found(#field_name_str))},
+        let aliases = &field.aliases;
+        let aliases_token = quote! { #(.or_else(|| items.remove(#aliases)))* };
+        let return_token = match (&field.default, field.is_option) {
+            (Some(FieldDefault::Default), _) => quote! {Default::default()},
+            (Some(FieldDefault::Function(function)), _) => {
+                let function_expr = function.as_expr();
+                quote! {#function_expr()}
+            }
+            (None, true) => quote! {None},
+            (None, false) => {
+                quote! {return Err(dynorow::error::Error::value_not_found(#field_name_str))}
+            }
         };
         quote::quote! {
-            let #field_name_expr: #field_type_token = match items.remove(#field_key_str) {
+            let #field_name_expr: #field_type_token = match items.remove(#field_key_str)#aliases_token {
                 Some(#field_name_expr) => {
                     #attribute_parse_token
                 },
@@ -49,11 +107,55 @@ fn generate_attribute_value_to_fields_token(struct_info: &StructInfo) -> proc_ma
     tokens
 }
 
+/// Flattened fields are read from the top level attributes of the parent item.
+fn generate_flattened_fields_token(fields: Vec<&FieldInfo>) -> proc_macro2::TokenStream {
+    let mut tokens = quote! {};
+    for field in fields {
+        let field_name_expr = field.name.as_expr();
+        let field_syn_type = &field.field_syn_type;
+        quote! {
+            let #field_name_expr = <#field_syn_type as TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>>>::try_from(items.clone())?;
+        }
+        .to_tokens(&mut tokens);
+    }
+    tokens
+}
+
 fn generate_attribute_parse_token(field: &FieldInfo) -> proc_macro2::TokenStream {
     let field_name_expr = field.name.as_expr();
     let field_type_token = field.get_type_token();
     let field_str = field.get_type_str();
 
+    if let Some(format) = field.get_timestamp_format_token() {
+        let field_syn_type = &field.field_syn_type;
+        let parse_token = quote! {
+            <#field_syn_type as dynorow::Timestamp>::from_timestamp_attribute_value(#field_name_expr, #format)?
+        };
+        return match field.is_option {
+            true => quote! {
+                match #field_name_expr.is_null() {
+                    true => None,
+                    false => Some(#parse_token),
+                }
+            },
+            false => parse_token,
+        };
+    }
+
+    if let Some(module) = &field.with {
+        let function_expr = format!("{}::from_attribute_value", module).as_expr();
+        return quote! { #function_expr(#field_name_expr)? };
+    }
+
+    if field.is_serde_dynamo {
+        return quote! {
+            dynorow::serde_dynamo::from_attribute_value::<_, #field_type_token>(
+                dynorow::traits::serde_dynamo_attribute_value_into::AwsAttributeValueInto::into_serde_dynamo_attribute_value(#field_name_expr.clone())?,
+            )
+                .map_err(|e| dynorow::error::Error::parse_error(#field_name_expr.clone(), #field_str, format!("{:?}", e)))?
+        };
+    }
+
     match field.is_serde {
         true => quote! {
             dynorow::serde_json::from_str(
@@ -1,13 +1,14 @@
-use crate::{
-    struct_info::StructInfo,
-    utils::as_expr::AsExpr,
-};
+use crate::struct_info::StructInfo;
 
 pub fn generate_try_from_get_item_output(struct_info: &StructInfo) -> proc_macro2::TokenStream {
-    let struct_name = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[quote::quote! {
+        Self: TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>, Error = dynorow::error::Error>
+    }]);
     
     quote::quote! {
-        impl TryFrom<dynorow::aws_sdk_dynamodb::operation::get_item::GetItemOutput> for #struct_name {
+        impl #impl_generics TryFrom<dynorow::aws_sdk_dynamodb::operation::get_item::GetItemOutput> for #struct_type #where_clause {
             type Error = dynorow::error::Error;
 
             fn try_from(value: dynorow::aws_sdk_dynamodb::operation::get_item::GetItemOutput) -> Result<Self, Self::Error> {
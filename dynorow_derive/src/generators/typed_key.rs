@@ -0,0 +1,126 @@
+use quote::{ToTokens, quote};
+
+use crate::{
+    AsExpr, StructInfo,
+    generators::generate_pk_value::{TemplatePartsTokens, generate_template_parts_tokens},
+    struct_info::field_info::FieldInfo,
+};
+
+/// `{Name}Key` holds the fields the key is built from, the pk and sk fields or the placeholders of their templates. <br>
+/// It converts into `TypedKey<Name>`, so a key of another model cannot be passed to the context functions of `Name`.
+pub fn generate_typed_key(struct_info: &StructInfo) -> proc_macro2::TokenStream {
+    let mut key_fields: Vec<&FieldInfo> = vec![];
+    let pk_fields = match struct_info.get_pk_field() {
+        Some(pk_field) => vec![pk_field.name.clone()],
+        None => struct_info.pk_value_parts.clone(),
+    };
+    let sk_fields = match struct_info.get_sk_field() {
+        Some(sk_field) => vec![sk_field.name.clone()],
+        None => struct_info.sk_value_parts.clone(),
+    };
+    for field_name in pk_fields.iter().chain(sk_fields.iter()) {
+        let Some(field) = struct_info.find_in_handled_fields(field_name) else {
+            return quote! {};
+        };
+        if !key_fields.iter().any(|x| x.name == field.name) {
+            key_fields.push(field);
+        }
+    }
+
+    // A key struct generic over the type parameters of the model is not worth it, `TypedKey::from_key_value` covers it.
+    if key_fields
+        .iter()
+        .any(|x| struct_info.generics.uses_type_params(&x.field_syn_type))
+    {
+        return quote! {};
+    }
+
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
+    let key_name_expr = struct_info.key_struct_name().as_expr();
+    let pk_key = struct_info.get_pk_key();
+
+    let mut fields_token = quote! {};
+    let mut field_names = vec![];
+    for field in key_fields {
+        let field_name_expr = field.name.as_expr();
+        let field_syn_type = &field.field_syn_type;
+        quote! {
+            pub #field_name_expr: #field_syn_type,
+        }
+        .to_tokens(&mut fields_token);
+        field_names.push(field_name_expr);
+    }
+
+    let pk_value_token = if struct_info.is_static_pk_value() {
+        quote! {
+            <#struct_type as dynorow::traits::has_pk_value::HasStaticPkValue>::get_static_pk_value()
+        }
+    } else if struct_info.is_generated_pk_value() {
+        let TemplatePartsTokens {
+            generate_format_parameters,
+            ..
+        } = generate_template_parts_tokens(struct_info, &struct_info.pk_value_parts, false);
+        quote! {
+            <#struct_type>::pk_value_template().format(&[#(#generate_format_parameters),*])
+        }
+    } else {
+        generate_field_value_token(struct_info.get_pk_field().unwrap())
+    };
+
+    let key_value_token = match struct_info.get_sk_key() {
+        Some(sk_key) => {
+            let sk_value_token = match struct_info.get_sk_field() {
+                Some(sk_field) => generate_field_value_token(sk_field),
+                None => {
+                    let TemplatePartsTokens {
+                        generate_format_parameters,
+                        ..
+                    } = generate_template_parts_tokens(
+                        struct_info,
+                        &struct_info.sk_value_parts,
+                        true,
+                    );
+                    quote! {
+                        <#struct_type>::sk_value_template().format(&[#(#generate_format_parameters),*])
+                    }
+                }
+            };
+            quote! {
+                dynorow::key::KeyValue::new_composite_key(
+                    #pk_key.into(),
+                    #pk_value_token,
+                    #sk_key.into(),
+                    #sk_value_token,
+                )
+            }
+        }
+        None => quote! {
+            dynorow::key::KeyValue::new_partition_key(#pk_key.into(), #pk_value_token)
+        },
+    };
+
+    quote! {
+        /// Key of a row, converts into a `TypedKey` accepted by the context functions.
+        #[derive(Debug, Clone)]
+        pub struct #key_name_expr {
+            #fields_token
+        }
+
+        impl #impl_generics From<#key_name_expr> for dynorow::key::TypedKey<#struct_type> #where_clause {
+            #[allow(unused_variables)]
+            fn from(key: #key_name_expr) -> Self {
+                let #key_name_expr { #(#field_names),* } = key;
+                dynorow::key::TypedKey::from_key_value(#key_value_token)
+            }
+        }
+    }
+}
+
+/// Key field value as its expression type, so that epoch timestamps are converted the same way as the stored attribute.
+fn generate_field_value_token(field: &FieldInfo) -> proc_macro2::TokenStream {
+    let field_name_expr = field.name.as_expr();
+    let key_type = field.get_expression_type_token();
+    quote! { <#key_type>::from(#field_name_expr) }
+}
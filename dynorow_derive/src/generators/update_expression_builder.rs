@@ -1,47 +1,75 @@
 use crate::{
-    AsExpr, StructInfo,
-    struct_info::{field_info::FieldInfo, field_type::FieldType},
+    AsExpr, StructInfo, generators::flattened_builder::generate_flattened_builder_tokens,
+    struct_info::field_info::FieldInfo,
 };
 
-use quote::{ToTokens, format_ident, quote};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::spanned::Spanned;
 
 pub fn generate_update_expression_builder_token(
     struct_info: &StructInfo,
 ) -> proc_macro2::TokenStream {
-    let struct_name_expr = struct_info.struct_name.as_expr();
-    let struct_name = struct_info.struct_name.clone();
-    let expression_builder_struct_name_expr =
-        format!("{}UpdateExpressionBuilder", struct_name.clone()).as_expr();
+    let expression_builder_struct_name =
+        format!("{}UpdateExpressionBuilder", struct_info.struct_name);
+    let expression_builder_struct_name_expr = expression_builder_struct_name.as_expr();
+    let expression_builder_type = struct_info.generated_type(&expression_builder_struct_name);
 
     let mut field_tokens = quote! {};
     for field in &struct_info.get_handled_fields() {
-        if matches!(field.field_type, FieldType::Map(_)) && !field.is_serde {
+        if field.with.is_some() {
+            let key = field.get_key_str();
+            generate_with_field_function_token(field, quote! { #key }).to_tokens(&mut field_tokens);
+        } else if field.nested {
             generate_dynomap_field_function_token(&field).to_tokens(&mut field_tokens);
         } else {
             generate_field_function_token(&field).to_tokens(&mut field_tokens);
         }
     }
 
+    let flattened = generate_flattened_builder_tokens(
+        struct_info,
+        &expression_builder_struct_name,
+        quote! { dynorow::traits::dyno_map_trait::DynoMapUpdateFields },
+        quote! { dynomap_update_expression_builder },
+        quote! { dynorow::traits::dyno_map_trait::FlattenedUpdateFields },
+        quote! { "" },
+    );
+    let flattened_functions = flattened.functions;
+    let flattened_impls = flattened.flattened_impls;
+
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&flattened.predicates);
+    let marker_field = struct_info.generics.marker_field();
+    let marker_init = struct_info.generics.marker_init();
+
     quote! {
-        impl #struct_name_expr {
-            pub fn update_expression_builder() -> #expression_builder_struct_name_expr {
-                #expression_builder_struct_name_expr {}
+        impl #impl_generics #struct_type #where_clause {
+            pub fn update_expression_builder() -> #expression_builder_type {
+                #expression_builder_struct_name_expr {
+                    #marker_init
+                }
             }
         }
 
-        pub struct #expression_builder_struct_name_expr {
+        pub struct #expression_builder_struct_name_expr #impl_generics #where_clause {
+            #marker_field
         }
 
-        impl #expression_builder_struct_name_expr {
+        impl #impl_generics #expression_builder_type #where_clause {
             #field_tokens
+
+            #flattened_functions
         }
+
+        #flattened_impls
     }
 }
 
 pub fn generate_field_function_token(field: &FieldInfo) -> proc_macro2::TokenStream {
     let key = field.get_key_str();
     let function_name = field.name.to_string().as_expr();
-    let field_type_token = field.get_type_token();
+    let field_type_token = field.get_update_type_token();
     quote! {
         pub fn #function_name(self) -> dynorow::UpdateExpressionBuilder<#field_type_token> {
             dynorow::UpdateExpressionBuilder::<#field_type_token>::new(#key.into())
@@ -49,24 +77,49 @@ pub fn generate_field_function_token(field: &FieldInfo) -> proc_macro2::TokenStr
     }
 }
 
+/// `key_token` has to evaluate to the attribute path as a `&str`.
+pub fn generate_with_field_function_token(
+    field: &FieldInfo,
+    key_token: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let function_name = field.name.to_string().as_expr();
+    let field_type_token = field.get_type_token();
+    let output = generate_with_field_output_token(field);
+    let to_attribute_value_expr =
+        format!("{}::to_attribute_value", field.with.as_ref().unwrap()).as_expr();
+    quote! {
+        pub fn #function_name(&self) -> #output {
+            dynorow::ConvertedUpdateExpressionBuilder::<#field_type_token>::new(#key_token, #to_attribute_value_expr)
+        }
+    }
+}
+
+pub fn generate_with_field_output_token(field: &FieldInfo) -> proc_macro2::TokenStream {
+    let field_type_token = field.get_type_token();
+    quote! { dynorow::ConvertedUpdateExpressionBuilder<#field_type_token> }
+}
+
 pub fn generate_dynomap_field_function_token(field: &FieldInfo) -> proc_macro2::TokenStream {
     let key = field.get_key_str();
     let function_name = field.name.to_string().as_expr();
     let fields_function_name = format!("{}_fields", field.name).to_string().as_expr();
     let field_type_token = field.field_syn_type.clone();
 
-    let x = format_ident!(
-        "{}DynoMapUpdateExpressionBuilder",
-        field.field_type.to_string().replace(" ", "")
-    );
-
-    quote! {
+    let mut tokens = quote! {
         pub fn #function_name(self) -> dynorow::UpdateExpressionBuilder<#field_type_token> {
             dynorow::UpdateExpressionBuilder::<#field_type_token>::new(#key.into())
         }
+    };
 
-        pub fn #fields_function_name(self) -> #x  {
-            #field_type_token::dynomap_update_expression_builder(#key)
+    // Spanned at the field type, so that nested fields of other types are reported there.
+    quote_spanned! {field_type_token.span()=>
+        pub fn #fields_function_name(self) -> <#field_type_token as dynorow::traits::dyno_map_trait::DynoMapUpdateFields>::Builder
+        where
+            #field_type_token: dynorow::traits::dyno_map_trait::DynoMapUpdateFields,
+        {
+            <#field_type_token as dynorow::traits::dyno_map_trait::DynoMapUpdateFields>::dynomap_update_expression_builder(#key)
         }
     }
+    .to_tokens(&mut tokens);
+    tokens
 }
@@ -1,45 +1,152 @@
-use crate::{AsExpr, StructInfo, struct_info::field_info::FieldInfo};
-use quote::{ToTokens, quote};
+use crate::{
+    AsExpr, StructInfo,
+    generators::{
+        flattened_builder::{
+            ForwardedFunction, generate_flattened_builder_tokens, generate_forwarding_trait_token,
+        },
+        update_expression_builder::{
+            generate_with_field_function_token, generate_with_field_output_token,
+        },
+    },
+    struct_info::field_info::FieldInfo,
+};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::spanned::Spanned;
 
 pub fn generate_dynomap_update_expression_builder_token(
     struct_info: &StructInfo,
 ) -> proc_macro2::TokenStream {
-    let struct_name_expr = struct_info.struct_name.as_expr();
-    let expression_builder_struct_name_expr =
-        format!("{}DynoMapUpdateExpressionBuilder", struct_info.struct_name).as_expr();
+    let expression_builder_struct_name =
+        format!("{}DynoMapUpdateExpressionBuilder", struct_info.struct_name);
+    let expression_builder_struct_name_expr = expression_builder_struct_name.as_expr();
+    let expression_builder_type = struct_info.generated_type(&expression_builder_struct_name);
 
     let mut field_tokens = quote! {};
+    let mut forwarded_functions = vec![];
+    let mut nested_predicates = vec![];
     for field in &struct_info.get_handled_fields() {
-        generate_field_function_token(&field).to_tokens(&mut field_tokens);
+        forwarded_functions.push(ForwardedFunction {
+            name: field.name.as_expr(),
+            output: match field.with.is_some() {
+                true => generate_with_field_output_token(field),
+                false => generate_field_output_token(field),
+            },
+        });
+        if field.with.is_some() {
+            let key = field.get_key_str();
+            generate_with_field_function_token(
+                field,
+                quote! { &dynorow::traits::dyno_map_trait::nested_attribute_path(&self.parent_name, #key) },
+            )
+            .to_tokens(&mut field_tokens);
+        } else {
+            generate_field_function_token(&field).to_tokens(&mut field_tokens);
+        }
+
+        if field.nested {
+            let field_syn_type = &field.field_syn_type;
+            let key = field.get_key_str();
+            let function_name = format!("{}_fields", field.name).as_expr();
+            let output = quote! { <#field_syn_type as dynorow::traits::dyno_map_trait::DynoMapUpdateFields>::Builder };
+            quote_spanned! {field_syn_type.span()=>
+                pub fn #function_name(&self) -> #output
+                where
+                    #field_syn_type: dynorow::traits::dyno_map_trait::DynoMapUpdateFields,
+                {
+                    <#field_syn_type as dynorow::traits::dyno_map_trait::DynoMapUpdateFields>::dynomap_update_expression_builder(
+                        &dynorow::traits::dyno_map_trait::nested_attribute_path(&self.parent_name, #key))
+                }
+            }
+            .to_tokens(&mut field_tokens);
+            forwarded_functions.push(ForwardedFunction {
+                name: function_name,
+                output,
+            });
+            if struct_info.generics.uses_type_params(field_syn_type) {
+                nested_predicates.push(quote! { #field_syn_type: dynorow::traits::dyno_map_trait::DynoMapUpdateFields });
+            }
+        }
     }
 
+    let flattened = generate_flattened_builder_tokens(
+        struct_info,
+        &expression_builder_struct_name,
+        quote! { dynorow::traits::dyno_map_trait::DynoMapUpdateFields },
+        quote! { dynomap_update_expression_builder },
+        quote! { dynorow::traits::dyno_map_trait::FlattenedUpdateFields },
+        quote! { &self.parent_name },
+    );
+    let flattened_functions = flattened.functions;
+    let flattened_impls = flattened.flattened_impls;
+    forwarded_functions.extend(flattened.forwarded_functions);
+    // Rows cannot be flattened, only `DynoMap` structs get the forwarding trait.
+    let forwarding_trait = match struct_info.dynorow {
+        true => quote! {},
+        false => generate_forwarding_trait_token(
+            struct_info,
+            &format!("{}FlattenedUpdateFields", struct_info.struct_name),
+            quote! { dynorow::traits::dyno_map_trait::FlattenedUpdateFields },
+            expression_builder_type.clone(),
+            &forwarded_functions,
+            &[flattened.predicates.clone(), nested_predicates].concat(),
+        ),
+    };
+
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&flattened.predicates);
+    let marker_field = struct_info.generics.marker_field();
+    let marker_init = struct_info.generics.marker_init();
+
     quote! {
-        impl #struct_name_expr {
-            pub fn dynomap_update_expression_builder(parent_name: &str) -> #expression_builder_struct_name_expr {
+        impl #impl_generics #struct_type #where_clause {
+            pub fn dynomap_update_expression_builder(parent_name: &str) -> #expression_builder_type {
                 #expression_builder_struct_name_expr {
-                    parent_name: parent_name.into()
+                    parent_name: parent_name.into(),
+                    #marker_init
                 }
             }
         }
 
-        pub struct #expression_builder_struct_name_expr {
-            pub parent_name: String
+        impl #impl_generics dynorow::traits::dyno_map_trait::DynoMapUpdateFields for #struct_type #where_clause {
+            type Builder = #expression_builder_type;
+
+            fn dynomap_update_expression_builder(parent_name: &str) -> #expression_builder_type {
+                <#struct_type>::dynomap_update_expression_builder(parent_name)
+            }
+        }
+
+        pub struct #expression_builder_struct_name_expr #impl_generics #where_clause {
+            pub parent_name: String,
+            #marker_field
         }
 
-        impl #expression_builder_struct_name_expr {
+        impl #impl_generics #expression_builder_type #where_clause {
             #field_tokens
+
+            #flattened_functions
         }
+
+        #flattened_impls
+
+        #forwarding_trait
     }
 }
 
 pub fn generate_field_function_token(field: &FieldInfo) -> proc_macro2::TokenStream {
     let key = field.get_key_str();
     let function_name = field.name.to_string().as_expr();
-    let field_type_token = field.get_type_token();
+    let field_type_token = field.get_update_type_token();
+    let output = generate_field_output_token(field);
     quote! {
-        pub fn #function_name(self) -> dynorow::UpdateExpressionBuilder<#field_type_token> {
+        pub fn #function_name(&self) -> #output {
             dynorow::UpdateExpressionBuilder::<#field_type_token>::new(
-                &format!("{}.{}", self.parent_name, #key))
+                &dynorow::traits::dyno_map_trait::nested_attribute_path(&self.parent_name, #key))
         }
     }
 }
+
+fn generate_field_output_token(field: &FieldInfo) -> proc_macro2::TokenStream {
+    let field_type_token = field.get_update_type_token();
+    quote! { dynorow::UpdateExpressionBuilder<#field_type_token> }
+}
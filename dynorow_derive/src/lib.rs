@@ -1,12 +1,20 @@
 #![deny(unused_crate_dependencies)]
+mod collection_info;
+mod entity_info;
+mod enum_info;
 mod generators;
+mod projection_info;
 mod struct_info;
 mod utils;
+mod value_info;
 
 use generators::{
     as_attribute_values::generate_as_attribute_values, as_key_value::generate_as_key_value_token,
     as_projection::generate_as_projection,
-    conditional_expression_builder::generate_conditional_expression_builder_token,
+    conditional_expression_builder::{
+        generate_conditional_expression_builder_token,
+        generate_dynomap_conditional_expression_builder_token,
+    },
     has_key::generate_has_key_token, has_static_pk_value::generate_has_pk_value_token,
     has_table_name::generate_has_table_name,
     try_from_attribute_value_hashmap::generate_try_from_attribute_value_hashmap,
@@ -14,15 +22,29 @@ use generators::{
 };
 use proc_macro::TokenStream;
 use quote::quote;
+use collection_info::CollectionInfo;
+use entity_info::EntityInfo;
+use enum_info::{EnumInfo, tagged_enum_info::TaggedEnumInfo};
+use projection_info::ProjectionInfo;
 use struct_info::StructInfo;
-use syn::{DeriveInput, parse_macro_input};
-use utils::as_expr::AsExpr;
+use value_info::ValueInfo;
+use syn::{Data, DeriveInput, parse_macro_input};
+use utils::{as_expr::AsExpr, generics::GenericsInfo};
 
 use crate::{
     generate_composite_key::generate_generate_composite_key,
     generators::{
-        generate_composite_key, generate_pk_value::generate_generate_pk_value,
+        dyno_collection::generate_dyno_collection, dyno_entity::generate_dyno_entity,
+        dyno_enum::generate_dyno_enum,
+        dyno_projection::generate_dyno_projection,
+        dyno_value::generate_dyno_value, generate_composite_key,
+        flattened_builder::{generate_dynomap_attribute_names, generate_flattened_attribute_check},
+        generate_pk_value::generate_generate_pk_value, generate_sk_value::generate_generate_sk_value,
+        key_condition::generate_key_condition, matches_item::generate_matches_item,
+        projection_fields::generate_projection_fields,
+        has_entity_type::generate_has_entity_type,
         has_pk_value_template::generate_has_pk_value_template, has_sort_key::generate_has_sort_key,
+        tagged_enum::generate_tagged_enum, typed_key::generate_typed_key,
         update_expression_builder::generate_update_expression_builder_token,
         update_expression_builder_for_dynomap::generate_dynomap_update_expression_builder_token,
     },
@@ -31,7 +53,10 @@ use crate::{
 #[proc_macro_derive(DynoRow, attributes(dynorow))]
 pub fn dynorow_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let struct_info = StructInfo::new(input, true);
+    let struct_info = match StructInfo::new(input, true) {
+        Ok(x) => x,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     let try_from_attribute_value_hashmap = generate_try_from_attribute_value_hashmap(&struct_info);
     let try_from_get_item_output = generate_try_from_get_item_output(&struct_info);
@@ -46,18 +71,33 @@ pub fn dynorow_derive(input: TokenStream) -> TokenStream {
     let update_expression_builder = generate_update_expression_builder_token(&struct_info);
     let dyno_map_update_expression = generate_dynomap_update_expression_builder_token(&struct_info);
 
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
     let generate_pk_value = generate_generate_pk_value(&struct_info);
+    let generate_sk_value = generate_generate_sk_value(&struct_info);
     let pk_value_template = generate_has_pk_value_template(&struct_info);
     let has_sort_key = generate_has_sort_key(&struct_info);
     let generate_composite_key = generate_generate_composite_key(&struct_info);
+    let key_condition = generate_key_condition(&struct_info);
+    let typed_key = generate_typed_key(&struct_info);
+    let projection_fields = generate_projection_fields(&struct_info);
+    let matches_item = generate_matches_item(&struct_info);
+    let has_entity_type = generate_has_entity_type(&struct_info);
+    let flattened_attribute_check = generate_flattened_attribute_check(&struct_info);
     quote! {
         #has_sort_key
 
         #generate_pk_value
 
+        #generate_sk_value
+
         #generate_composite_key
 
+        #key_condition
+
+        #typed_key
+
         #pk_value_template
 
         #conditional_expression_builder
@@ -80,9 +120,17 @@ pub fn dynorow_derive(input: TokenStream) -> TokenStream {
 
         #as_projection_token
 
+        #projection_fields
+
+        #matches_item
+
+        #has_entity_type
+
         #has_table_name_token
 
-        impl dynorow::traits::dyno_map_trait::DynoMapTrait for #struct_name_expr {}
+        #flattened_attribute_check
+
+        impl #impl_generics dynorow::traits::dyno_map_trait::DynoMapTrait for #struct_type #where_clause {}
     }
     .into()
 }
@@ -90,32 +138,106 @@ pub fn dynorow_derive(input: TokenStream) -> TokenStream {
 #[proc_macro_derive(DynoMap, attributes(dynorow))]
 pub fn dynomap_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let struct_info = StructInfo::new(input, false);
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    if matches!(input.data, Data::Enum(_)) {
+        return match TaggedEnumInfo::new(input) {
+            Ok(enum_info) => generate_tagged_enum(&enum_info).into(),
+            Err(e) => e.to_compile_error().into(),
+        };
+    }
+
+    let struct_info = match StructInfo::new(input, false) {
+        Ok(x) => x,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let impl_generics = struct_info.generics.impl_generics();
+    let struct_type = struct_info.struct_type();
+    let where_clause = struct_info.generics.where_clause(&[]);
 
     let try_from_attribute_value_hashmap = generate_try_from_attribute_value_hashmap(&struct_info);
     let as_attribute_values = generate_as_attribute_values(&struct_info);
     let dyno_map_update_expression = generate_dynomap_update_expression_builder_token(&struct_info);
+    let dyno_map_conditional_expression =
+        generate_dynomap_conditional_expression_builder_token(&struct_info);
+    let as_projection_token = generate_as_projection(&struct_info);
+    let attribute_names = generate_dynomap_attribute_names(&struct_info);
+    let flattened_attribute_check = generate_flattened_attribute_check(&struct_info);
 
     quote! {
         #dyno_map_update_expression
 
+        #dyno_map_conditional_expression
+
         #try_from_attribute_value_hashmap
 
         #as_attribute_values
 
-        impl dynorow::traits::dyno_map_trait::DynoMapTrait for #struct_name_expr {}
+        #as_projection_token
+
+        #attribute_names
+
+        #flattened_attribute_check
+
+        impl #impl_generics dynorow::traits::dyno_map_trait::DynoMapTrait for #struct_type #where_clause {}
     }
     .into()
 }
 
+#[proc_macro_derive(DynoEnum, attributes(dynorow))]
+pub fn dynoenum_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match EnumInfo::new(input) {
+        Ok(enum_info) => generate_dyno_enum(&enum_info).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(DynoValue, attributes(dynorow))]
+pub fn dynovalue_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match ValueInfo::new(input) {
+        Ok(value_info) => generate_dyno_value(&value_info).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(DynoProjection, attributes(dynorow))]
+pub fn dynoprojection_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match ProjectionInfo::new(input) {
+        Ok(projection_info) => generate_dyno_projection(&projection_info).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(DynoEntity, attributes(dynorow))]
+pub fn dynoentity_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match EntityInfo::new(input) {
+        Ok(entity_info) => generate_dyno_entity(&entity_info).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(DynoCollection, attributes(dynorow))]
+pub fn dynocollection_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match CollectionInfo::new(input) {
+        Ok(collection_info) => generate_dyno_collection(&collection_info).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 #[proc_macro_derive(Insertable)]
 pub fn insertable_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let struct_info = StructInfo::new(input, true);
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    let (generics, struct_type) = operation_target(&input);
+    let impl_generics = generics.impl_generics();
+    let where_clause = generics.where_clause(&operation_predicates(
+        &generics,
+        quote! { dynorow::traits::as_attribute_key_values::AsAttributeKeyValues + Send + 'static },
+    ));
     quote! {
-        impl dynorow::traits::insertable::Insertable for #struct_name_expr {
+        impl #impl_generics dynorow::traits::insertable::Insertable for #struct_type #where_clause {
 
         }
 
@@ -126,10 +248,14 @@ pub fn insertable_derive(input: TokenStream) -> TokenStream {
 #[proc_macro_derive(Updatable)]
 pub fn updatable_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let struct_info = StructInfo::new(input, true);
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    let (generics, struct_type) = operation_target(&input);
+    let impl_generics = generics.impl_generics();
+    let where_clause = generics.where_clause(&operation_predicates(
+        &generics,
+        quote! { dynorow::traits::as_attribute_key_values::AsAttributeKeyValues + dynorow::traits::as_key_value::AsKeyValue + std::fmt::Debug + 'static },
+    ));
     quote! {
-        impl dynorow::traits::updatable::Updatable for #struct_name_expr {
+        impl #impl_generics dynorow::traits::updatable::Updatable for #struct_type #where_clause {
 
         }
 
@@ -140,13 +266,40 @@ pub fn updatable_derive(input: TokenStream) -> TokenStream {
 #[proc_macro_derive(Fetchable)]
 pub fn fetchable_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let struct_info = StructInfo::new(input, true);
-    let struct_name_expr = struct_info.struct_name.as_expr();
+    let (generics, struct_type) = operation_target(&input);
+    let impl_generics = generics.impl_generics();
+    let where_clause = generics.where_clause(&operation_predicates(
+        &generics,
+        quote! { TryFrom<std::collections::HashMap<String, dynorow::aws_sdk_dynamodb::types::AttributeValue>, Error = dynorow::error::Error>
+            + TryFrom<dynorow::aws_sdk_dynamodb::operation::get_item::GetItemOutput, Error = dynorow::error::Error>
+            + Clone
+            + std::fmt::Debug
+            + dynorow::traits::as_projection::AsProjection },
+    ));
     quote! {
-        impl dynorow::traits::fetchable::Fetchable for #struct_name_expr {
+        impl #impl_generics dynorow::traits::fetchable::Fetchable for #struct_type #where_clause {
             type Error = dynorow::error::Error;
         }
 
     }
     .into()
 }
+
+/// The operation derives only need the type, attribute errors are reported once by `DynoRow`.
+fn operation_target(input: &DeriveInput) -> (GenericsInfo, proc_macro2::TokenStream) {
+    let generics = GenericsInfo::new(input.generics.clone());
+    let ident = &input.ident;
+    let ty_generics = generics.ty_generics();
+    (generics, quote! { #ident #ty_generics })
+}
+
+/// The generated impls of generic structs carry extra bounds, the operation traits require them through their supertraits.
+fn operation_predicates(
+    generics: &GenericsInfo,
+    bounds: proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    match generics.is_empty() {
+        true => vec![],
+        false => vec![quote! { Self: #bounds }],
+    }
+}
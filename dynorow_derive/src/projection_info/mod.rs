@@ -0,0 +1,99 @@
+use quote::ToTokens;
+use syn::{Data, DeriveInput, Fields, Ident, Type};
+
+use crate::utils::{as_expr::AsExpr, generics::GenericsInfo};
+
+/// Struct deriving `DynoProjection`, reading some fields of its parent row. <br>
+/// Attribute names and conversions are the ones of the parent fields with the same name.
+pub struct ProjectionInfo {
+    pub projection_name: String,
+    /// From `#[dynorow(of = Parent)]`.
+    pub parent: Type,
+    pub fields: Vec<(Ident, Type)>,
+    pub generics: GenericsInfo,
+}
+
+impl ProjectionInfo {
+    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+        let mut parent: Option<Type> = None;
+        for attribute in &input.attrs {
+            if !attribute.path().is_ident("dynorow") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if !meta.path.is_ident("of") {
+                    return Err(meta.error(format!(
+                        "Unknown attribute `{}`. Expected of.",
+                        meta.path.to_token_stream()
+                    )));
+                }
+                parent = Some(meta.value()?.parse::<Type>()?);
+                Ok(())
+            })?;
+        }
+
+        let span = input.ident.span();
+        let Some(parent) = parent else {
+            return Err(syn::Error::new(
+                span,
+                "DynoProjection requires #[dynorow(of = Parent)].",
+            ));
+        };
+
+        let Data::Struct(data) = input.data else {
+            return Err(syn::Error::new(
+                span,
+                "DynoProjection only supports structs.",
+            ));
+        };
+        let Fields::Named(fields) = data.fields else {
+            return Err(syn::Error::new(span, "Only named fields are supported."));
+        };
+
+        let mut projection_fields = vec![];
+        for field in fields.named {
+            if let Some(attribute) = field.attrs.iter().find(|x| x.path().is_ident("dynorow")) {
+                return Err(syn::Error::new_spanned(
+                    attribute,
+                    "Projection fields take their attribute name and conversion from the parent field.",
+                ));
+            }
+            projection_fields.push((field.ident.unwrap(), field.ty));
+        }
+
+        Ok(Self {
+            projection_name: input.ident.to_string(),
+            parent,
+            fields: projection_fields,
+            generics: GenericsInfo::new(input.generics),
+        })
+    }
+
+    /// `Name<T>`, the projection with its type parameters.
+    pub fn projection_type(&self) -> proc_macro2::TokenStream {
+        let projection_name_expr = self.projection_name.as_expr();
+        let ty_generics = self.generics.ty_generics();
+        quote::quote! { #projection_name_expr #ty_generics }
+    }
+}
+
+/// Hidden function of the parent reading the field from an item, generated by `DynoRow`.
+pub fn projection_reader_name(field_name: &str) -> String {
+    format!("__dynorow_projection_read_{}", field_name)
+}
+
+/// Hidden function of the parent returning the attribute name of the field, generated by `DynoRow`.
+pub fn projection_key_name(field_name: &str) -> String {
+    format!("__dynorow_projection_key_{}", field_name)
+}
+
+/// Hidden function of the parent rejecting items of another entity type, generated by `DynoRow`.
+pub fn projection_entity_type_check_name() -> String {
+    String::from("__dynorow_projection_check_entity_type")
+}
+
+/// Hidden function of the parent returning the entity type attribute, if any, generated by `DynoRow`.
+pub fn projection_entity_type_key_name() -> String {
+    String::from("__dynorow_projection_entity_type_key")
+}
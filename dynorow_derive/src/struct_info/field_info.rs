@@ -1,5 +1,9 @@
-use quote::{ToTokens, quote};
-use syn::{Field, PathArguments, PathSegment, Type};
+use proc_macro2::Span;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    Field, LitStr, Path, PathArguments, PathSegment, Token, Type, meta::ParseNestedMeta,
+    spanned::Spanned,
+};
 
 use super::{field_type::FieldType, key::Key};
 
@@ -11,9 +15,33 @@ pub struct FieldInfo {
     pub ignore: bool,
     pub is_option: bool,
     pub is_serde: bool,
+    /// Stored as a native attribute value through `serde_dynamo` instead of a json string.
+    pub is_serde_dynamo: bool,
+    /// `TimestampFormat` variant name from `#[dynorow(timestamp = "...")]`.
+    pub timestamp: Option<String>,
+    /// Used when the attribute is missing from the item.
+    pub default: Option<FieldDefault>,
+    /// Set by `#[dynorow(key = "...")]`, struct level `rename_all` does not apply then.
+    pub has_custom_key: bool,
+    /// Legacy attribute names accepted when reading.
+    pub aliases: Vec<String>,
+    /// Attributes of the `DynoMap` field are stored at the top level of the parent item.
+    pub flatten: bool,
+    /// From `#[dynorow(nested)]`, the field is a `DynoMap` whose fields the update builder reaches through `<field>_fields()`.
+    pub nested: bool,
+    /// Module from `#[dynorow(with = "...")]` providing `to_attribute_value` and `from_attribute_value`.
+    pub with: Option<String>,
+    /// Span of the field name, for the errors found after the attributes are parsed.
+    pub span: Span,
+}
+
+pub enum FieldDefault {
+    Default,
+    Function(String),
 }
 
 impl FieldInfo {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: String,
         key: Key,
@@ -22,6 +50,15 @@ impl FieldInfo {
         ignore: bool,
         is_option: bool,
         is_serde: bool,
+        is_serde_dynamo: bool,
+        timestamp: Option<String>,
+        default: Option<FieldDefault>,
+        has_custom_key: bool,
+        aliases: Vec<String>,
+        flatten: bool,
+        nested: bool,
+        with: Option<String>,
+        span: Span,
     ) -> Self {
         Self {
             name,
@@ -31,6 +68,21 @@ impl FieldInfo {
             ignore,
             is_option,
             is_serde,
+            is_serde_dynamo,
+            timestamp,
+            default,
+            has_custom_key,
+            aliases,
+            flatten,
+            nested,
+            with,
+            span,
+        }
+    }
+
+    pub fn set_key_str(&mut self, key: String) {
+        match &mut self.key {
+            Key::Key(x) | Key::Pk(x) | Key::Sk(x) => *x = key,
         }
     }
 
@@ -43,6 +95,134 @@ impl FieldInfo {
         .clone()
     }
 
+    /// Converted through `IntoAttributeValue` and `FromAttributeValue`.
+    pub fn has_default_conversion(&self) -> bool {
+        self.timestamp.is_none() && !self.is_serde && !self.is_serde_dynamo && self.with.is_none()
+    }
+
+    /// Usable in key templates, through `Display` or the timestamp format.
+    pub fn has_display(&self) -> bool {
+        !self.is_option
+            && !matches!(
+                self.field_type,
+                FieldType::Binary(_) | FieldType::Vec(_) | FieldType::HashSet(_)
+            )
+    }
+
+    /// Parsable back from a key template, through the timestamp format or a type known to implement `FromStr`.
+    pub fn has_from_str(&self) -> bool {
+        self.timestamp.is_some()
+            || !matches!(
+                self.field_type,
+                FieldType::Binary(_)
+                    | FieldType::Vec(_)
+                    | FieldType::HashSet(_)
+                    | FieldType::Map(_)
+            )
+    }
+
+    /// Characters of the formatted value of types with a fixed format, `None` when any character can appear.
+    pub fn formatted_value_chars(&self) -> Option<&'static str> {
+        const DIGITS: &str = "0123456789";
+        match self.timestamp.as_deref() {
+            Some("Rfc3339") => return Some("0123456789-:.+TZ"),
+            Some(_) => return Some("0123456789-"),
+            None => {}
+        }
+        match &self.field_type {
+            FieldType::u8
+            | FieldType::u16
+            | FieldType::u32
+            | FieldType::u64
+            | FieldType::u128
+            | FieldType::usize => Some(DIGITS),
+            FieldType::i8
+            | FieldType::i16
+            | FieldType::i32
+            | FieldType::i64
+            | FieldType::i128
+            | FieldType::isize => Some("0123456789-"),
+            FieldType::f32 | FieldType::f64 | FieldType::DynoNumber | FieldType::Decimal => {
+                Some("0123456789-.eEinfNaN")
+            }
+            FieldType::bool => Some("truefals"),
+            _ => None,
+        }
+    }
+
+    /// Digits unsigned and epoch values are zero-padded to in sk templates, so that the sort keys sort numerically. <br>
+    /// `None` for values sorted as text.
+    pub fn sort_key_width(&self) -> Option<usize> {
+        match self.timestamp.as_deref() {
+            Some("Rfc3339") => return None,
+            Some(_) => return Some(19),
+            None => {}
+        }
+        match &self.field_type {
+            FieldType::u8 => Some(3),
+            FieldType::u16 => Some(5),
+            FieldType::u32 => Some(10),
+            FieldType::u64 | FieldType::usize => Some(20),
+            FieldType::u128 => Some(39),
+            _ => None,
+        }
+    }
+
+    /// Numbers that cannot be padded to sort numerically, signed and decimal numbers.
+    pub fn is_unsortable_number(&self) -> bool {
+        self.timestamp.is_none()
+            && matches!(
+                self.field_type,
+                FieldType::i8
+                    | FieldType::i16
+                    | FieldType::i32
+                    | FieldType::i64
+                    | FieldType::i128
+                    | FieldType::isize
+                    | FieldType::f32
+                    | FieldType::f64
+                    | FieldType::DynoNumber
+                    | FieldType::Decimal
+            )
+    }
+
+    /// `ScalarAttributeType` of the stored value when the field is a key, `None` for types that cannot be keys. <br>
+    /// Types unknown to the derive, such as newtypes, provide it through `ScalarAttribute`, see `get_scalar_attribute_type_token`.
+    pub fn scalar_attribute_type(&self) -> Option<&'static str> {
+        match self.timestamp.as_deref() {
+            Some("Rfc3339") => return Some("S"),
+            Some(_) => return Some("N"),
+            None => {}
+        }
+        if self.is_serde {
+            return Some("S");
+        }
+        match &self.field_type {
+            FieldType::String => Some("S"),
+            FieldType::Binary(_) => Some("B"),
+            FieldType::bool | FieldType::Vec(_) | FieldType::HashSet(_) => None,
+            FieldType::Map(_) => Some("S"),
+            _ => Some("N"),
+        }
+    }
+
+    pub fn get_scalar_attribute_type_token(&self) -> proc_macro2::TokenStream {
+        if self.has_scalar_attribute_trait() {
+            let field_syn_type = &self.field_syn_type;
+            return quote! {
+                <#field_syn_type as dynorow::traits::scalar_attribute::ScalarAttribute>::SCALAR_ATTRIBUTE_TYPE
+            };
+        }
+        let scalar_attribute_type =
+            format_ident!("{}", self.scalar_attribute_type().unwrap_or("S"));
+        quote! { dynorow::aws_sdk_dynamodb::types::ScalarAttributeType::#scalar_attribute_type }
+    }
+
+    /// Key type read from the `ScalarAttribute` implementation of the field type.
+    pub fn has_scalar_attribute_trait(&self) -> bool {
+        self.timestamp.is_none() && !self.is_serde && matches!(self.field_type, FieldType::Map(_))
+    }
+
     pub fn as_projection_variable(&self) -> String {
         format!("#v_{}", self.get_key_str())
     }
@@ -62,6 +242,38 @@ impl FieldInfo {
             false => quote! {#field_syn_type},
         }
     }
+
+    pub fn get_timestamp_format_token(&self) -> Option<proc_macro2::TokenStream> {
+        let format = format_ident!("{}", self.timestamp.as_ref()?);
+        Some(quote! {dynorow::TimestampFormat::#format})
+    }
+
+    /// Value type of the expression builders, epoch timestamps are wrapped so that
+    /// values are converted the same way as the field.
+    pub fn get_expression_type_token(&self) -> proc_macro2::TokenStream {
+        let field_syn_type = self.field_syn_type.clone();
+        let value_type = match self.timestamp.as_deref() {
+            Some("EpochSecs") => quote! {dynorow::EpochSecs<#field_syn_type>},
+            Some("EpochMillis") => quote! {dynorow::EpochMillis<#field_syn_type>},
+            _ => return self.get_type_token(),
+        };
+        match self.is_option {
+            true => quote! {Option::<#value_type>},
+            false => value_type,
+        }
+    }
+
+    /// Value type of the update builders, binary fields are wrapped so that they get no list functions.
+    pub fn get_update_type_token(&self) -> proc_macro2::TokenStream {
+        if !matches!(self.field_type, FieldType::Binary(_)) {
+            return self.get_expression_type_token();
+        }
+        let field_syn_type = self.field_syn_type.clone();
+        match self.is_option {
+            true => quote! {Option::<dynorow::Binary<#field_syn_type>>},
+            false => quote! {dynorow::Binary<#field_syn_type>},
+        }
+    }
 }
 
 struct FieldScan {
@@ -74,6 +286,15 @@ struct FieldScan {
     pub ignore: bool,
     pub is_option: bool,
     pub is_serde: bool,
+    pub is_serde_dynamo: bool,
+    pub timestamp: Option<String>,
+    pub default: Option<FieldDefault>,
+    pub has_custom_key: bool,
+    pub aliases: Vec<String>,
+    pub flatten: bool,
+    pub nested: bool,
+    pub with: Option<String>,
+    pub span: Span,
 }
 
 impl Into<FieldInfo> for FieldScan {
@@ -86,59 +307,95 @@ impl Into<FieldInfo> for FieldScan {
             self.ignore,
             self.is_option,
             self.is_serde,
+            self.is_serde_dynamo,
+            self.timestamp,
+            self.default,
+            self.has_custom_key,
+            self.aliases,
+            self.flatten,
+            self.nested,
+            self.with,
+            self.span,
         )
     }
 }
 
-fn get_last_path_segment(ty: &Type) -> &PathSegment {
+/// Field attributes accepted by `DynoRow` and `DynoMap`, listed in the unknown attribute error.
+const FIELD_ATTRIBUTES: &str =
+    "pk, sk, key, alias, ignore, serde, serde_dynamo, with, default, timestamp, flatten or nested";
+
+/// `default` alone falls back to `Default::default()`, `default = path::to::fn` calls the function.
+pub fn parse_default(meta: &ParseNestedMeta) -> syn::Result<FieldDefault> {
+    if !meta.input.peek(Token![=]) {
+        return Ok(FieldDefault::Default);
+    }
+
+    let value = meta.value()?;
+    let function = if value.peek(LitStr) {
+        value.parse::<LitStr>()?.parse::<Path>()
+    } else {
+        value.parse::<Path>()
+    };
+    let Ok(function) = function else {
+        return Err(meta.error("Default should be a path to a function."));
+    };
+    Ok(FieldDefault::Function(
+        function.into_token_stream().to_string(),
+    ))
+}
+
+fn get_last_path_segment(ty: &Type) -> syn::Result<&PathSegment> {
     match ty {
         Type::Path(type_path) => match type_path.path.segments.last() {
-            Some(segment) => {
-                return segment;
-            }
-            None => panic!("Error while fetching type name."),
+            Some(segment) => Ok(segment),
+            None => Err(syn::Error::new_spanned(
+                ty,
+                "Error while fetching type name.",
+            )),
         },
-        _ => panic!("Unhandled syn::Type"),
+        _ => Err(syn::Error::new_spanned(ty, "Unsupported field type.")),
     }
 }
 
-fn get_type_ident(ty: &Type) -> String {
-    //get_last_path_segment(ty).ident.to_string()
+pub fn get_type_ident(ty: &Type) -> syn::Result<String> {
     match ty {
-        Type::Path(type_path) => type_path.into_token_stream().to_string(),
-        _ => panic!("Unhandled syn::Type"),
+        Type::Path(type_path) => Ok(type_path.into_token_stream().to_string()),
+        _ => Err(syn::Error::new_spanned(ty, "Unsupported field type.")),
     }
 }
 
-fn get_generic_type_argument(ty: &Type) -> Type {
-    let segement = get_last_path_segment(ty);
+fn get_generic_type_argument(ty: &Type) -> syn::Result<Type> {
+    let segement = get_last_path_segment(ty)?;
     match &segement.arguments {
         PathArguments::AngleBracketed(args) => match args.args.first() {
-            Some(generic_argument) => match generic_argument {
-                syn::GenericArgument::Type(t) => return t.clone(),
-                _ => panic!("Unhandled generic argument."),
-            },
-            None => panic!("Type does not have any generic argument."),
+            Some(syn::GenericArgument::Type(t)) => Ok(t.clone()),
+            _ => Err(syn::Error::new_spanned(ty, "Expected a type argument.")),
         },
-        _ => panic!("Unhandled path segment argument."),
+        _ => Err(syn::Error::new_spanned(ty, "Expected a type argument.")),
     }
 }
 
 impl FieldScan {
-    fn new(field: &Field) -> Self {
+    fn new(field: &Field) -> syn::Result<Self> {
         let mut is_option = false;
         let mut field_syn_type = field.ty.clone();
-        let mut field_type = get_type_ident(&field.ty);
+        let mut field_type = get_type_ident(&field.ty)?;
         if field_type.starts_with("Option") {
             is_option = true;
-            let generic_argument_type = get_generic_type_argument(&field.ty);
+            let generic_argument_type = get_generic_type_argument(&field.ty)?;
             field_syn_type = generic_argument_type.clone();
-            field_type = get_type_ident(&generic_argument_type);
+            field_type = get_type_ident(&generic_argument_type)?;
         }
 
-        let key = field.ident.as_ref().unwrap().to_string().replace("\"", "");
+        let Some(ident) = field.ident.as_ref() else {
+            return Err(syn::Error::new(
+                field.span(),
+                "Only named fields are supported.",
+            ));
+        };
+        let key = ident.to_string();
 
-        Self {
+        Ok(Self {
             field_name: key.clone(),
             key,
             field_type,
@@ -148,33 +405,169 @@ impl FieldScan {
             ignore: false,
             is_option,
             is_serde: false,
+            is_serde_dynamo: false,
+            timestamp: None,
+            default: None,
+            has_custom_key: false,
+            aliases: vec![],
+            flatten: false,
+            nested: false,
+            with: None,
+            span: ident.span(),
+        })
+    }
+
+    /// `pk` and `sk` optionally take the attribute name, `#[dynorow(sk = "sk")]`.
+    fn parse_key_name(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.input.peek(Token![=]) {
+            let key = meta.value()?.parse::<LitStr>()?;
+            self.set_key(key.value());
         }
+        Ok(())
     }
 
-    fn mark_as_pk(&mut self) {
+    fn mark_as_pk(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
         if self.is_sk_key {
-            panic!("Field is already marked as sk.");
+            return Err(meta.error("Field is already marked as sk."));
         }
         self.is_pk_key = true;
+        self.parse_key_name(meta)
     }
 
-    fn mark_as_sk(&mut self) {
-        if self.is_sk_key {
-            panic!("Field is already marked as pk.");
+    fn mark_as_sk(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if self.is_pk_key {
+            return Err(meta.error("Field is already marked as pk."));
         }
         self.is_sk_key = true;
+        self.parse_key_name(meta)
     }
 
-    fn mark_as_ignored(&mut self) {
+    fn mark_as_ignored(&mut self) -> syn::Result<()> {
         self.ignore = true;
+        Ok(())
     }
 
-    fn mark_as_serde(&mut self) {
+    fn mark_as_serde(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if self.timestamp.is_some() || self.is_serde_dynamo || self.with.is_some() {
+            return Err(
+                meta.error("Field cannot be both serde and timestamp, serde_dynamo or with.")
+            );
+        }
         self.is_serde = true;
+        Ok(())
+    }
+
+    fn mark_as_serde_dynamo(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if self.timestamp.is_some() || self.is_serde || self.with.is_some() {
+            return Err(
+                meta.error("Field cannot be both serde_dynamo and timestamp, serde or with.")
+            );
+        }
+        self.is_serde_dynamo = true;
+        Ok(())
+    }
+
+    fn set_timestamp(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if self.is_serde || self.is_serde_dynamo || self.with.is_some() {
+            return Err(
+                meta.error("Field cannot be both timestamp and serde, serde_dynamo or with.")
+            );
+        }
+        let format = meta.value()?.parse::<LitStr>()?;
+        let timestamp = match format.value().as_str() {
+            "rfc3339" => "Rfc3339",
+            "epoch_secs" => "EpochSecs",
+            "epoch_millis" => "EpochMillis",
+            x => {
+                return Err(syn::Error::new_spanned(
+                    format,
+                    format!(
+                        "Unknown timestamp format : {}. Expected rfc3339, epoch_secs or epoch_millis.",
+                        x
+                    ),
+                ));
+            }
+        };
+        self.timestamp = Some(timestamp.into());
+        Ok(())
+    }
+
+    fn set_with(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if self.is_serde || self.is_serde_dynamo || self.timestamp.is_some() {
+            return Err(
+                meta.error("Field cannot be both with and serde, serde_dynamo or timestamp.")
+            );
+        }
+        let module = meta.value()?.parse::<LitStr>()?;
+        if module.parse::<Path>().is_err() {
+            return Err(syn::Error::new_spanned(
+                &module,
+                format!("with expects a module path : {}", module.value()),
+            ));
+        }
+        self.with = Some(module.value());
+        Ok(())
+    }
+
+    /// Flattened fields have no attribute of their own, so the attributes describing one are rejected.
+    fn check_flatten(&self) -> syn::Result<()> {
+        if !self.flatten {
+            return Ok(());
+        }
+        if self.is_option {
+            return Err(syn::Error::new(
+                self.span,
+                "Flattened field cannot be of type Option<T>.",
+            ));
+        }
+        if self.is_pk_key
+            || self.is_sk_key
+            || self.has_custom_key
+            || self.is_serde
+            || self.is_serde_dynamo
+            || self.timestamp.is_some()
+            || self.with.is_some()
+            || self.default.is_some()
+            || !self.aliases.is_empty()
+        {
+            return Err(syn::Error::new(
+                self.span,
+                format!(
+                    "Flattened field {} cannot be a key or have key, alias, serde, serde_dynamo, timestamp, with or default attributes.",
+                    self.field_name
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Nested fields are converted as `DynoMap` values, so the attributes converting them otherwise are rejected.
+    fn check_nested(&self) -> syn::Result<()> {
+        if !self.nested {
+            return Ok(());
+        }
+        if self.flatten
+            || self.is_pk_key
+            || self.is_sk_key
+            || self.is_serde
+            || self.is_serde_dynamo
+            || self.timestamp.is_some()
+            || self.with.is_some()
+        {
+            return Err(syn::Error::new(
+                self.span,
+                format!(
+                    "Nested field {} cannot be a key or have flatten, serde, serde_dynamo, timestamp or with attributes.",
+                    self.field_name
+                ),
+            ));
+        }
+        Ok(())
     }
 
     fn set_key(&mut self, key: String) {
-        self.key = key.replace("\"", "");
+        self.has_custom_key = true;
+        self.key = key;
     }
 
     fn get_key(&self) -> Key {
@@ -188,36 +581,64 @@ impl FieldScan {
     }
 }
 
-impl From<&Field> for FieldInfo {
-    fn from(field: &Field) -> Self {
-        let mut field_scan = FieldScan::new(&field);
+impl TryFrom<&Field> for FieldInfo {
+    type Error = syn::Error;
+
+    fn try_from(field: &Field) -> syn::Result<Self> {
+        let mut field_scan = FieldScan::new(field)?;
 
         for attribute in &field.attrs {
             if !attribute.path().is_ident("dynorow") {
                 continue;
             }
-            let _ = attribute.parse_nested_meta(|meta| {
-                let Some(ident) = meta.path.get_ident() else {
-                    return Ok(());
-                };
-                match ident.to_string().as_str() {
-                    "pk" => field_scan.mark_as_pk(),
-                    "sk" => field_scan.mark_as_sk(),
+            attribute.parse_nested_meta(|meta| {
+                let name = meta.path.to_token_stream().to_string();
+                match name.as_str() {
+                    "pk" => field_scan.mark_as_pk(&meta),
+                    "sk" => field_scan.mark_as_sk(&meta),
                     "key" => {
-                        let Ok(key) = meta.value() else {
-                            panic!("Error while getting key value.");
-                        };
-                        field_scan.set_key(key.to_string())
+                        let key = meta.value()?.parse::<LitStr>()?;
+                        field_scan.set_key(key.value());
+                        Ok(())
                     }
                     "ignore" => field_scan.mark_as_ignored(),
-                    "serde" => field_scan.mark_as_serde(),
-                    _ => {}
+                    "serde" => field_scan.mark_as_serde(&meta),
+                    "serde_dynamo" => field_scan.mark_as_serde_dynamo(&meta),
+                    "flatten" => {
+                        field_scan.flatten = true;
+                        Ok(())
+                    }
+                    "nested" => {
+                        field_scan.nested = true;
+                        Ok(())
+                    }
+                    "default" => {
+                        field_scan.default = Some(parse_default(&meta)?);
+                        Ok(())
+                    }
+                    "alias" => {
+                        let alias = meta.value()?.parse::<LitStr>()?;
+                        field_scan.aliases.push(alias.value());
+                        Ok(())
+                    }
+                    "with" => field_scan.set_with(&meta),
+                    "timestamp" => field_scan.set_timestamp(&meta),
+                    _ => Err(meta.error(format!(
+                        "Unknown field attribute `{}`. Expected {}.",
+                        name, FIELD_ATTRIBUTES
+                    ))),
                 }
-
-                return Ok(());
-            });
+            })?;
         }
 
-        return field_scan.into();
+        field_scan.check_flatten()?;
+        field_scan.check_nested()?;
+        if field_scan.with.is_some() && (field_scan.is_pk_key || field_scan.is_sk_key) {
+            return Err(syn::Error::new(
+                field_scan.span,
+                "Key fields cannot use with.",
+            ));
+        }
+        Ok(field_scan.into())
     }
 }
@@ -4,10 +4,24 @@ use std::fmt::Display;
 #[derive(Debug)]
 pub enum FieldType {
     String,
+    i8,
+    i16,
     i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
     u32,
+    u64,
+    u128,
+    usize,
     f32,
+    f64,
     bool,
+    DynoNumber,
+    Decimal,
+    Binary(String),
     Vec(String),
     HashSet(String),
     Map(String),
@@ -17,10 +31,24 @@ impl Display for FieldType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             FieldType::String => write!(f, "String"),
+            FieldType::i8 => write!(f, "i8"),
+            FieldType::i16 => write!(f, "i16"),
             FieldType::i32 => write!(f, "i32"),
+            FieldType::i64 => write!(f, "i64"),
+            FieldType::i128 => write!(f, "i128"),
+            FieldType::isize => write!(f, "isize"),
+            FieldType::u8 => write!(f, "u8"),
+            FieldType::u16 => write!(f, "u16"),
             FieldType::u32 => write!(f, "u32"),
+            FieldType::u64 => write!(f, "u64"),
+            FieldType::u128 => write!(f, "u128"),
+            FieldType::usize => write!(f, "usize"),
             FieldType::f32 => write!(f, "f32"),
+            FieldType::f64 => write!(f, "f64"),
             FieldType::bool => write!(f, "bool"),
+            FieldType::DynoNumber => write!(f, "DynoNumber"),
+            FieldType::Decimal => write!(f, "Decimal"),
+            FieldType::Binary(x) => write!(f, "{}", x),
             FieldType::Vec(x) => write!(f, "{}", x),
             FieldType::HashSet(x) => write!(f, "{}", x),
             FieldType::Map(x) => write!(f, "{}", x),
@@ -32,17 +60,35 @@ impl From<String> for FieldType {
     fn from(value: String) -> Self {
         match value.as_str() {
             "String" => Self::String,
+            "i8" => Self::i8,
+            "i16" => Self::i16,
             "i32" => Self::i32,
+            "i64" => Self::i64,
+            "i128" => Self::i128,
+            "isize" => Self::isize,
+            "u8" => Self::u8,
+            "u16" => Self::u16,
             "u32" => Self::u32,
+            "u64" => Self::u64,
+            "u128" => Self::u128,
+            "usize" => Self::usize,
             "f32" => Self::f32,
+            "f64" => Self::f64,
             "bool" => Self::bool,
             x => {
-                if x.starts_with("Vec <") {
-                    Self::Vec(value)
-                } else if x.starts_with("HashSet <") {
-                    Self::HashSet(value)
-                } else {
-                    Self::Map(value)
+                let (path, generics) = match x.split_once(" <") {
+                    Some((path, generics)) => (path, Some(generics)),
+                    None => (x, None),
+                };
+                match (path.rsplit(" :: ").next().unwrap_or(path), generics) {
+                    ("DynoNumber", None) => Self::DynoNumber,
+                    ("Decimal", None) => Self::Decimal,
+                    ("Bytes", None) | ("Blob", None) | ("Vec", Some(" u8 >")) => {
+                        Self::Binary(value)
+                    }
+                    ("Vec", Some(_)) => Self::Vec(value),
+                    ("HashSet", Some(_)) => Self::HashSet(value),
+                    _ => Self::Map(value),
                 }
             }
         }
@@ -1,7 +1,14 @@
-use field_info::FieldInfo;
+use field_info::{FieldDefault, FieldInfo, parse_default};
 use key::Key;
+use proc_macro2::Span;
+use quote::ToTokens;
 use regex::Regex;
-use syn::{Data, DeriveInput, Fields};
+use syn::{Data, DeriveInput, Expr, Fields, Ident, LitStr, meta::ParseNestedMeta};
+
+use crate::{
+    enum_info::parse_rename_all,
+    utils::{as_expr::AsExpr, generics::GenericsInfo, rename_rule::RenameRule},
+};
 
 pub mod field_info;
 pub mod field_type;
@@ -12,13 +19,37 @@ pub struct StructInfo {
     pub pk: Option<String>,
     pub pk_value: Option<String>,
     pub pk_value_parts: Vec<String>,
+    /// Span of the `pk_value` literal, template errors point at it.
+    pub pk_value_span: Option<Span>,
+    /// Struct level sort key name, from `#[dynorow(sk = "...")]`.
+    pub sk: Option<String>,
+    pub sk_value: Option<String>,
+    pub sk_value_parts: Vec<String>,
+    pub sk_value_span: Option<Span>,
+    /// From `#[dynorow(template_escape = "...")]`, escapes the separators inside key template values.
+    pub template_escape: Option<char>,
+    /// From `#[dynorow(parse_key_parts)]`, parses the template placeholders through `FromStr` whatever their type.
+    pub parse_key_parts: bool,
+    /// From `#[dynorow(key_name = "...")]`, `{Name}Key` by default.
+    pub key_name: Option<String>,
+    /// From `#[dynorow(pk_parts_name = "...")]`, `{Name}PkParts` by default.
+    pub pk_parts_name: Option<String>,
+    /// From `#[dynorow(sk_parts_name = "...")]`, `{Name}SkParts` by default.
+    pub sk_parts_name: Option<String>,
+    /// From `#[dynorow(entity_type = "...")]`, written under `entity_type_attr` and checked when reading.
+    pub entity_type: Option<String>,
+    /// From `#[dynorow(attr = "...")]`, `_type` by default.
+    pub entity_type_attr: Option<String>,
     pub fields: Vec<FieldInfo>,
     pub table_name_provider: Option<String>,
     pub dynorow: bool,
+    pub generics: GenericsInfo,
+    /// Span of the struct name, for the errors that are not about a single field or attribute.
+    pub span: Span,
 }
 
 impl StructInfo {
-    pub fn new(input: DeriveInput, dynorow: bool) -> Self {
+    pub fn new(input: DeriveInput, dynorow: bool) -> syn::Result<Self> {
         let mut struct_info = Self {
             struct_name: input.ident.to_string(),
             pk: None,
@@ -27,61 +58,187 @@ impl StructInfo {
             table_name_provider: None,
             dynorow,
             pk_value_parts: vec![],
+            pk_value_span: None,
+            sk: None,
+            sk_value: None,
+            sk_value_parts: vec![],
+            sk_value_span: None,
+            template_escape: None,
+            parse_key_parts: false,
+            key_name: None,
+            pk_parts_name: None,
+            sk_parts_name: None,
+            entity_type: None,
+            entity_type_attr: None,
+            generics: GenericsInfo::new(input.generics.clone()),
+            span: input.ident.span(),
         };
 
-        if struct_info.dynorow {
-            for attribute in input.attrs {
-                if !attribute.path().is_ident("dynorow") {
-                    continue;
-                }
-
-                let _ = attribute.parse_nested_meta(|meta| {
-                    let Some(ident) = meta.path.get_ident() else {
-                        return Ok(());
-                    };
-                    match ident.to_string().as_str() {
-                        "pk" => {
-                            let Ok(key) = meta.value() else {
-                                panic!("Error while getting pk key for struct.");
-                            };
-                            struct_info.set_pk(key.to_string());
-                        }
-                        "pk_value" => {
-                            let Ok(value) = meta.value() else {
-                                panic!("Error while getting pk key value for struct.");
-                            };
-                            struct_info.set_pk_value(value.to_string());
-                        }
-                        "table" => {
-                            let Ok(value) = meta.value() else {
-                                panic!("Error while getting pk key value for struct.");
-                            };
-                            struct_info.table_name_provider = Some(value.to_string());
-                        }
-                        _ => {}
-                    }
-                    return Ok(());
-                });
+        let mut struct_default = false;
+        let mut rename_rule: Option<RenameRule> = None;
+        for attribute in &input.attrs {
+            if !attribute.path().is_ident("dynorow") {
+                continue;
             }
+
+            attribute.parse_nested_meta(|meta| {
+                let name = meta.path.to_token_stream().to_string();
+                match (name.as_str(), struct_info.dynorow) {
+                    ("default", _) => {
+                        let FieldDefault::Default = parse_default(&meta)? else {
+                            return Err(
+                                meta.error("Struct level default does not take a function.")
+                            );
+                        };
+                        struct_default = true;
+                    }
+                    ("rename_all", _) => {
+                        rename_rule = Some(parse_rename_all(&meta)?);
+                    }
+                    ("pk", true) => {
+                        let key = meta.value()?.parse::<LitStr>()?;
+                        struct_info.set_pk(key.value());
+                    }
+                    ("pk_value", true) => {
+                        let value = meta.value()?.parse::<LitStr>()?;
+                        struct_info.set_pk_value(value.value());
+                        struct_info.pk_value_span = Some(value.span());
+                    }
+                    ("sk", true) => {
+                        let key = meta.value()?.parse::<LitStr>()?;
+                        struct_info.sk = Some(key.value());
+                    }
+                    ("sk_value", true) => {
+                        let value = meta.value()?.parse::<LitStr>()?;
+                        let (template, arguments) = parse_template(&value.value());
+                        struct_info.sk_value = Some(template);
+                        struct_info.sk_value_parts = arguments;
+                        struct_info.sk_value_span = Some(value.span());
+                    }
+                    ("template_escape", true) => {
+                        let value = meta.value()?.parse::<LitStr>()?;
+                        let escape = value.value();
+                        let mut chars = escape.chars();
+                        let (Some(escape), None) = (chars.next(), chars.next()) else {
+                            return Err(syn::Error::new_spanned(
+                                &value,
+                                "template_escape should be a single character.",
+                            ));
+                        };
+                        struct_info.template_escape = Some(escape);
+                    }
+                    ("parse_key_parts", true) => {
+                        struct_info.parse_key_parts = true;
+                    }
+                    ("key_name", true) => {
+                        struct_info.key_name = Some(parse_type_name(&meta)?);
+                    }
+                    ("pk_parts_name", true) => {
+                        struct_info.pk_parts_name = Some(parse_type_name(&meta)?);
+                    }
+                    ("sk_parts_name", true) => {
+                        struct_info.sk_parts_name = Some(parse_type_name(&meta)?);
+                    }
+                    ("entity_type", true) => {
+                        let value = meta.value()?.parse::<LitStr>()?;
+                        struct_info.entity_type = Some(value.value());
+                    }
+                    ("attr", true) => {
+                        let value = meta.value()?.parse::<LitStr>()?;
+                        struct_info.entity_type_attr = Some(value.value());
+                    }
+                    ("table", true) => {
+                        let value = meta.value()?.parse::<Expr>()?;
+                        struct_info.table_name_provider =
+                            Some(value.into_token_stream().to_string());
+                    }
+                    (
+                        "pk" | "pk_value" | "sk" | "sk_value" | "template_escape"
+                        | "parse_key_parts" | "key_name" | "pk_parts_name" | "sk_parts_name"
+                        | "entity_type" | "attr" | "table",
+                        false,
+                    ) => {
+                        return Err(meta.error(format!("`{}` is only supported by DynoRow.", name)));
+                    }
+                    _ => {
+                        return Err(meta.error(format!(
+                            "Unknown struct attribute `{}`. Expected {}.",
+                            name,
+                            match struct_info.dynorow {
+                                true =>
+                                    "table, pk, pk_value, sk, sk_value, template_escape, parse_key_parts, key_name, pk_parts_name, sk_parts_name, entity_type, attr, rename_all or default",
+                                false => "rename_all or default",
+                            }
+                        )));
+                    }
+                }
+                Ok(())
+            })?;
         }
 
-        let fields = if let Data::Struct(data) = input.data {
-            if let Fields::Named(fields) = data.fields {
-                fields
-            } else {
-                panic!("Only named fields are supported.");
-            }
-        } else {
-            panic!("Only structs are supported.");
+        let Data::Struct(data) = input.data else {
+            return Err(syn::Error::new(
+                struct_info.span,
+                "Only structs are supported.",
+            ));
+        };
+        let Fields::Named(fields) = data.fields else {
+            return Err(syn::Error::new(
+                struct_info.span,
+                "Only named fields are supported.",
+            ));
         };
 
         for field in fields.named.iter() {
-            struct_info.insert_field(FieldInfo::try_from(field).unwrap());
+            let mut field_info = FieldInfo::try_from(field)?;
+            if struct_default && field_info.default.is_none() && !field_info.flatten {
+                field_info.default = Some(FieldDefault::Default);
+            }
+            if let Some(rule) = rename_rule
+                && !field_info.has_custom_key
+                && !field_info.flatten
+            {
+                field_info.set_key_str(rule.apply_to_field(&field_info.name));
+            }
+            struct_info.insert_field(field_info)?;
         }
 
-        struct_info.panic_at_errors();
+        struct_info.check_errors()?;
+
+        Ok(struct_info)
+    }
+
+    /// `Name<T>`, the struct with its type parameters.
+    pub fn struct_type(&self) -> proc_macro2::TokenStream {
+        self.generated_type(&self.struct_name)
+    }
+
+    /// `{Name}Key`, unless renamed with `key_name`.
+    pub fn key_struct_name(&self) -> String {
+        self.key_name
+            .clone()
+            .unwrap_or_else(|| format!("{}Key", self.struct_name))
+    }
+
+    /// `{Name}PkParts`, unless renamed with `pk_parts_name`.
+    pub fn pk_parts_struct_name(&self) -> String {
+        self.pk_parts_name
+            .clone()
+            .unwrap_or_else(|| format!("{}PkParts", self.struct_name))
+    }
 
-        return struct_info;
+    /// `{Name}SkParts`, unless renamed with `sk_parts_name`.
+    pub fn sk_parts_struct_name(&self) -> String {
+        self.sk_parts_name
+            .clone()
+            .unwrap_or_else(|| format!("{}SkParts", self.struct_name))
+    }
+
+    /// A type generated for the struct, such as its builders, with the type parameters of the struct.
+    pub fn generated_type(&self, name: &str) -> proc_macro2::TokenStream {
+        let name_expr = name.to_string().as_expr();
+        let ty_generics = self.generics.ty_generics();
+        quote::quote! { #name_expr #ty_generics }
     }
 
     pub fn is_static_pk_value(&self) -> bool {
@@ -92,54 +249,76 @@ impl StructInfo {
         self.pk_value.is_some() && !self.pk_value_parts.is_empty()
     }
 
+    /// `parse_pk_value` and `parse_sk_value` are generated when every placeholder of the template is known to implement `FromStr`,
+    /// or when `parse_key_parts` is set.
+    pub fn has_parsable_parts(&self, parts: &[String]) -> bool {
+        self.parse_key_parts
+            || parts.iter().all(|x| {
+                self.find_in_handled_fields(x)
+                    .is_some_and(|x| x.has_from_str())
+            })
+    }
+
     pub fn set_pk(&mut self, pk: String) {
         self.pk = Some(pk.replace("\"", ""));
     }
 
-    pub fn set_pk_value(&mut self, mut pk_value: String) {
-        pk_value = pk_value.replace("\"", "");
-        let regex = Regex::new(r"\{([^}]*)\}").unwrap();
-
-        let pk_value_clone = pk_value.clone();
-        let finds: Vec<_> = regex.find_iter(&pk_value_clone).collect();
-
-        let mut arguments = vec![];
-
-        for find in finds {
-            let argument = find
-                .as_str()
-                .trim_start_matches("{")
-                .trim_end_matches("}")
-                .to_string();
-            pk_value = pk_value.replace(&argument, "");
-            arguments.push(argument);
-        }
+    pub fn set_pk_value(&mut self, pk_value: String) {
+        let (template, arguments) = parse_template(&pk_value);
         self.pk_value_parts = arguments;
-        self.pk_value = Some(pk_value);
+        self.pk_value = Some(template);
     }
 
     pub fn find_in_handled_fields(&self, field_name: &str) -> Option<&FieldInfo> {
         self.fields
             .iter()
-            .find(|x| !x.ignore && x.name == field_name)
+            .find(|x| !x.ignore && !x.flatten && x.name == field_name)
     }
 
+    /// Fields stored under their own attribute, flattened fields are not included.
     pub fn get_handled_fields(&self) -> Vec<&FieldInfo> {
-        self.fields.iter().filter(|x| !x.ignore).collect()
+        self.fields
+            .iter()
+            .filter(|x| !x.ignore && !x.flatten)
+            .collect()
+    }
+
+    pub fn get_flattened_fields(&self) -> Vec<&FieldInfo> {
+        self.fields
+            .iter()
+            .filter(|x| !x.ignore && x.flatten)
+            .collect()
     }
 
-    fn insert_field(&mut self, field: FieldInfo) {
-        if !field.ignore {
+    fn insert_field(&mut self, field: FieldInfo) -> syn::Result<()> {
+        if !field.ignore && !field.flatten {
             let key = field.get_key_str();
             let key_already_exists = self
                 .get_handled_fields()
                 .iter()
                 .any(|x| x.get_key_str() == key);
             if key_already_exists {
-                panic!("Duplicate key : {}", key)
+                return Err(syn::Error::new(
+                    field.span,
+                    format!("Duplicate key : {}", key),
+                ));
+            }
+
+            let handled_fields = self.get_handled_fields();
+            for alias in &field.aliases {
+                let alias_already_exists = handled_fields
+                    .iter()
+                    .any(|x| &x.get_key_str() == alias || x.aliases.contains(alias));
+                if alias_already_exists || alias == &key {
+                    return Err(syn::Error::new(
+                        field.span,
+                        format!("Duplicate key : {}", alias),
+                    ));
+                }
             }
         }
         self.fields.push(field);
+        Ok(())
     }
 
     pub fn get_pk_key(&self) -> String {
@@ -161,6 +340,9 @@ impl StructInfo {
     }
 
     pub fn get_sk_key(&self) -> Option<String> {
+        if let Some(sk) = &self.sk {
+            return Some(sk.clone());
+        }
         let Some(sk_field) = self.fields.iter().find(|x| matches!(x.key, Key::Sk(_))) else {
             return None;
         };
@@ -175,48 +357,296 @@ impl StructInfo {
         self.pk_value.is_some()
     }
 
+    /// Sort key value generated from `#[dynorow(sk_value = "...")]` instead of a field.
+    pub fn struct_has_sk_value(&self) -> bool {
+        self.sk_value.is_some()
+    }
+
+    /// Attribute holding the entity type, when `entity_type` is set.
+    pub fn get_entity_type_attr(&self) -> Option<String> {
+        self.entity_type.as_ref().map(|_| {
+            self.entity_type_attr
+                .clone()
+                .unwrap_or_else(|| String::from("_type"))
+        })
+    }
+
     pub fn generate_projection_expression(&self) -> String {
         self.get_handled_fields()
             .iter()
             .map(|x| x.as_projection_variable())
+            .chain(self.get_entity_type_attr().map(|x| format!("#v_{}", x)))
             .collect::<Vec<String>>()
             .join(", ")
     }
 
-    fn panic_at_errors(&self) {
+    fn check_errors(&self) -> syn::Result<()> {
         if !self.dynorow {
-            return;
+            return Ok(());
         }
 
         let struct_has_pk = self.struct_has_pk();
         let struct_has_pk_value = self.struct_has_pk_value();
-        let pk_field = self.fields.iter().find(|x| matches!(x.key, Key::Pk(_)));
-        let field_has_pk = pk_field.is_some();
+        let pk_field = self.get_pk_field();
+        let sk_field = self.get_sk_field();
+
+        for key_field in [pk_field, sk_field].into_iter().flatten() {
+            let is_pk = matches!(key_field.key, Key::Pk(_));
+            if let Some(duplicate) = self
+                .fields
+                .iter()
+                .filter(|x| matches!(x.key, Key::Pk(_)) == is_pk && !matches!(x.key, Key::Key(_)))
+                .nth(1)
+            {
+                return Err(syn::Error::new(
+                    duplicate.span,
+                    format!(
+                        "Only one field can be marked as {}.",
+                        if is_pk { "pk" } else { "sk" }
+                    ),
+                ));
+            }
+        }
+
+        for key_field in [pk_field, sk_field].into_iter().flatten() {
+            if !key_field.is_option && key_field.scalar_attribute_type().is_none() {
+                return Err(syn::Error::new(
+                    key_field.span,
+                    "Key fields have to be string, number or binary values.",
+                ));
+            }
+        }
 
-        let sk_field = self.fields.iter().find(|x| matches!(x.key, Key::Sk(_)));
-        let field_has_sk = sk_field.is_some();
-        if field_has_sk && sk_field.unwrap().is_option {
-            panic!("SK field cannot be of type Option<T>");
+        if let Some(sk_field) = sk_field
+            && sk_field.is_option
+        {
+            return Err(syn::Error::new(
+                sk_field.span,
+                "SK field cannot be of type Option<T>",
+            ));
         }
 
-        if field_has_pk && pk_field.unwrap().is_option {
-            panic!("Pk field cannot be of type Option<T>");
+        if let Some(pk_field) = pk_field
+            && pk_field.is_option
+        {
+            return Err(syn::Error::new(
+                pk_field.span,
+                "Pk field cannot be of type Option<T>",
+            ));
         }
 
         if struct_has_pk && !struct_has_pk_value {
-            panic!("Provide pk value when defining pk at struct level.");
+            return Err(syn::Error::new(
+                self.span,
+                "Provide pk value when defining pk at struct level.",
+            ));
         }
 
         if !struct_has_pk && struct_has_pk_value {
-            panic!("Provide pk key when providing pk value at struct level.");
+            return Err(syn::Error::new(
+                self.span,
+                "Provide pk key when providing pk value at struct level.",
+            ));
+        }
+
+        if let Some(pk_field) = pk_field
+            && struct_has_pk
+            && struct_has_pk_value
+        {
+            return Err(syn::Error::new(
+                pk_field.span,
+                "Found pk info at both struct and field level. Only one is required.",
+            ));
+        }
+
+        if !(struct_has_pk || struct_has_pk_value) && pk_field.is_none() {
+            return Err(syn::Error::new(self.span, "No pk info found."));
         }
 
-        if (struct_has_pk && struct_has_pk_value) && field_has_pk {
-            panic!("Found pk info at both struct and field level. Only one is required.")
+        if self.sk.is_some() != self.struct_has_sk_value() {
+            return Err(syn::Error::new(
+                self.span,
+                "Provide both sk and sk_value when defining sk at struct level.",
+            ));
         }
 
-        if !(struct_has_pk || struct_has_pk_value) && !field_has_pk {
-            panic!("No pk info found.")
+        if let Some(sk_field) = sk_field
+            && self.sk.is_some()
+        {
+            return Err(syn::Error::new(
+                sk_field.span,
+                "Found sk info at both struct and field level. Only one is required.",
+            ));
         }
+
+        if self.entity_type_attr.is_some() && self.entity_type.is_none() {
+            return Err(syn::Error::new(
+                self.span,
+                "Provide entity_type when defining attr.",
+            ));
+        }
+
+        if let Some(entity_type_attr) = self.get_entity_type_attr()
+            && let Some(field) = self
+                .get_handled_fields()
+                .into_iter()
+                .find(|x| x.get_key_str() == entity_type_attr)
+        {
+            return Err(syn::Error::new(
+                field.span,
+                format!(
+                    "`{}` is the entity type attribute, the field needs another attribute name.",
+                    entity_type_attr
+                ),
+            ));
+        }
+
+        if let Some(sk_value) = &self.sk_value {
+            self.check_template(
+                "sk_value",
+                sk_value,
+                &self.sk_value_parts,
+                self.sk_value_span.unwrap_or(self.span),
+            )?;
+        }
+
+        if let Some(pk_value) = &self.pk_value {
+            self.check_template(
+                "pk_value",
+                pk_value,
+                &self.pk_value_parts,
+                self.pk_value_span.unwrap_or(self.span),
+            )?;
+        }
+
+        Ok(())
     }
+
+    /// Placeholders have to name handled fields that can be formatted, and without `template_escape` the separator after a placeholder
+    /// cannot be made only of characters the field value can contain, parsing would split inside the value.
+    fn check_template(
+        &self,
+        attribute: &str,
+        template: &str,
+        parts: &[String],
+        span: Span,
+    ) -> syn::Result<()> {
+        let separators: Vec<&str> = template.split("{}").collect();
+        if let Some(escape) = self.template_escape
+            && template.contains(escape)
+        {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "{} cannot contain the template_escape character `{}`.",
+                    attribute, escape
+                ),
+            ));
+        }
+        for (index, part) in parts.iter().enumerate() {
+            if parts[..index].contains(part) {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "{} placeholder {{{}}} is used more than once.",
+                        attribute, part
+                    ),
+                ));
+            }
+
+            let Some(field) = self.fields.iter().find(|x| &x.name == part) else {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "{} placeholder {{{}}} does not match any field.",
+                        attribute, part
+                    ),
+                ));
+            };
+            if field.ignore || field.flatten {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "{} placeholder {{{}}} refers to an ignored or flattened field.",
+                        attribute, part
+                    ),
+                ));
+            }
+            if !field.has_display() {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "{} placeholder {{{}}} cannot be formatted, Option, collection and binary fields are not supported.",
+                        attribute, part
+                    ),
+                ));
+            }
+
+            if attribute == "sk_value" && field.is_unsortable_number() {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "{} placeholder {{{}}} would not sort numerically, use an unsigned integer, a timestamp or a string.",
+                        attribute, part
+                    ),
+                ));
+            }
+
+            let separator = separators[index + 1];
+            let is_last = index == parts.len() - 1;
+            if separator.is_empty() && !is_last {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "{} placeholders {{{}}} and {{{}}} need a separator.",
+                        attribute,
+                        part,
+                        parts[index + 1]
+                    ),
+                ));
+            }
+            if self.generics.uses_type_params(&field.field_syn_type) {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "{} placeholder {{{}}} cannot use a type parameter.",
+                        attribute, part
+                    ),
+                ));
+            }
+            if let Some(chars) = field.formatted_value_chars()
+                && self.template_escape.is_none()
+                && !separator.is_empty()
+                && !is_last
+                && separator.chars().all(|x| chars.contains(x))
+            {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "{} separator `{}` after {{{}}} can appear inside the value of the field.",
+                        attribute, separator, part
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Name of a generated type, has to be an identifier.
+fn parse_type_name(meta: &ParseNestedMeta) -> syn::Result<String> {
+    let value = meta.value()?.parse::<LitStr>()?;
+    syn::parse_str::<Ident>(&value.value())
+        .map(|x| x.to_string())
+        .map_err(|_| syn::Error::new_spanned(&value, "Expected a type name."))
+}
+
+/// `Order:{user_id}:{order_id}` becomes the format string `Order:{}:{}` and the placeholder names.
+pub fn parse_template(value: &str) -> (String, Vec<String>) {
+    let regex = Regex::new(r"\{([^}]*)\}").unwrap();
+    let arguments = regex
+        .captures_iter(value)
+        .map(|x| x[1].to_string())
+        .collect();
+    (regex.replace_all(value, "{}").to_string(), arguments)
 }
@@ -0,0 +1,105 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, quote};
+use syn::{GenericParam, Generics, Type};
+
+/// Generics of the derived type, pasted into every generated impl and builder.
+pub struct GenericsInfo {
+    pub generics: Generics,
+}
+
+impl GenericsInfo {
+    pub fn new(generics: Generics) -> Self {
+        Self { generics }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.generics.params.is_empty()
+    }
+
+    /// `<T: Bound>`, for `impl` blocks and builder struct declarations.
+    pub fn impl_generics(&self) -> TokenStream {
+        let (impl_generics, _, _) = self.generics.split_for_impl();
+        impl_generics.to_token_stream()
+    }
+
+    /// The generics with extra type parameters at the end, for the traits forwarding the functions of flattened builders.
+    pub fn with_type_params(&self, params: &[&str]) -> GenericsInfo {
+        let mut generics = self.generics.clone();
+        for param in params {
+            generics
+                .params
+                .push(syn::parse_str::<GenericParam>(param).unwrap());
+        }
+        GenericsInfo::new(generics)
+    }
+
+    /// `<T>`, to follow the name of the type or of its builders.
+    pub fn ty_generics(&self) -> TokenStream {
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        ty_generics.to_token_stream()
+    }
+
+    /// Where clause of the type with `predicates` added, these are only needed for the fields using type parameters.
+    pub fn where_clause(&self, predicates: &[TokenStream]) -> TokenStream {
+        let existing = self
+            .generics
+            .where_clause
+            .as_ref()
+            .map(|x| x.predicates.iter().map(|x| x.to_token_stream()).collect())
+            .unwrap_or(vec![]);
+        let predicates: Vec<&TokenStream> = existing.iter().chain(predicates.iter()).collect();
+        match predicates.is_empty() {
+            true => quote! {},
+            false => quote! { where #(#predicates),* },
+        }
+    }
+
+    /// Builders do not hold values of the type, the marker keeps their type parameters used.
+    pub fn marker_field(&self) -> TokenStream {
+        let params: Vec<TokenStream> = self
+            .generics
+            .params
+            .iter()
+            .filter_map(|x| match x {
+                GenericParam::Type(x) => {
+                    let ident = &x.ident;
+                    Some(quote! { #ident })
+                }
+                GenericParam::Lifetime(x) => {
+                    let lifetime = &x.lifetime;
+                    Some(quote! { &#lifetime () })
+                }
+                GenericParam::Const(_) => None,
+            })
+            .collect();
+        match params.is_empty() {
+            true => quote! {},
+            false => quote! { _marker: std::marker::PhantomData<fn() -> (#(#params,)*)>, },
+        }
+    }
+
+    pub fn marker_init(&self) -> TokenStream {
+        match self.marker_field().is_empty() {
+            true => quote! {},
+            false => quote! { _marker: std::marker::PhantomData, },
+        }
+    }
+
+    /// Whether `ty` mentions one of the type parameters.
+    pub fn uses_type_params(&self, ty: &Type) -> bool {
+        let params: Vec<String> = self
+            .generics
+            .type_params()
+            .map(|x| x.ident.to_string())
+            .collect();
+        !params.is_empty() && tokens_use_idents(ty.to_token_stream(), &params)
+    }
+}
+
+fn tokens_use_idents(tokens: TokenStream, idents: &[String]) -> bool {
+    tokens.into_iter().any(|x| match x {
+        TokenTree::Ident(ident) => idents.contains(&ident.to_string()),
+        TokenTree::Group(group) => tokens_use_idents(group.stream(), idents),
+        _ => false,
+    })
+}
@@ -1 +1,3 @@
-pub mod as_expr;
\ No newline at end of file
+pub mod as_expr;
+pub mod generics;
+pub mod rename_rule;
@@ -0,0 +1,94 @@
+use quote::ToTokens;
+use syn::{Data, DeriveInput, Fields, Type};
+
+use crate::{
+    struct_info::{field_info::get_type_ident, field_type::FieldType},
+    utils::{as_expr::AsExpr, generics::GenericsInfo},
+};
+
+/// Newtype deriving `DynoValue`, converted exactly like its only field.
+pub struct ValueInfo {
+    pub value_name: String,
+    /// `0` for tuple structs, the field name otherwise.
+    pub field_accessor: String,
+    pub inner_type: Type,
+    pub inner_field_type: FieldType,
+    pub generics: GenericsInfo,
+    /// Set by `#[dynorow(display)]`, implements `Display` through the inner value.
+    pub display: bool,
+    /// Set by `#[dynorow(from_str)]`, implements `FromStr` through the inner value.
+    pub from_str: bool,
+}
+
+impl ValueInfo {
+    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+        let mut transparent = false;
+        let mut display = false;
+        let mut from_str = false;
+        for attribute in &input.attrs {
+            if !attribute.path().is_ident("dynorow") {
+                continue;
+            }
+
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("transparent") {
+                    transparent = true;
+                } else if meta.path.is_ident("display") {
+                    display = true;
+                } else if meta.path.is_ident("from_str") {
+                    from_str = true;
+                } else {
+                    return Err(meta.error(format!(
+                        "Unknown attribute `{}`. Expected transparent, display or from_str.",
+                        meta.path.to_token_stream()
+                    )));
+                }
+                Ok(())
+            })?;
+        }
+
+        let span = input.ident.span();
+        if !transparent {
+            return Err(syn::Error::new(
+                span,
+                "DynoValue requires #[dynorow(transparent)].",
+            ));
+        }
+
+        let Data::Struct(data) = input.data else {
+            return Err(syn::Error::new(span, "DynoValue only supports structs."));
+        };
+
+        let fields = match data.fields {
+            Fields::Unnamed(fields) => fields.unnamed,
+            Fields::Named(fields) => fields.named,
+            Fields::Unit => Default::default(),
+        };
+        if fields.len() != 1 {
+            return Err(syn::Error::new(span, "DynoValue requires a single field."));
+        }
+
+        let field = fields.into_iter().next().unwrap();
+        let field_accessor = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => String::from("0"),
+        };
+
+        Ok(Self {
+            value_name: input.ident.to_string(),
+            field_accessor,
+            inner_field_type: FieldType::from(get_type_ident(&field.ty)?),
+            inner_type: field.ty,
+            generics: GenericsInfo::new(input.generics),
+            display,
+            from_str,
+        })
+    }
+
+    /// `Name<T>`, the newtype name followed by its type parameters.
+    pub fn value_type(&self) -> proc_macro2::TokenStream {
+        let value_name_expr = self.value_name.as_expr();
+        let ty_generics = self.generics.ty_generics();
+        quote::quote! { #value_name_expr #ty_generics }
+    }
+}
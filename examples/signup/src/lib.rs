@@ -2,8 +2,8 @@ use std::collections::HashSet;
 
 use chrono::{DateTime, Utc};
 use dynorow::{
-    BuildConditionalExpression, DynoMap, DynoRow, Fetchable, Insertable, Updatable,
-    traits::matches_template::MatchesTemplate,
+    BuildConditionalExpression, DynoMap, DynoProjection, DynoRow, Fetchable, Insertable, Updatable,
+    key::TypedKey, traits::matches_template::MatchesTemplate,
 };
 
 pub fn get_table_name() -> String {
@@ -27,13 +27,21 @@ pub struct SignUp {
 
     pub uid: String,
     pub password: Option<String>,
+    #[dynorow(nested)]
     pub data: Option<Data>,
     pub string_set: HashSet<String>,
 
-    #[dynorow(serde)]
+    #[dynorow(timestamp = "rfc3339")]
     pub deleted_on: Option<DateTime<Utc>>,
 }
 
+#[derive(DynoProjection, Fetchable, Clone, Debug)]
+#[dynorow(of = SignUp)]
+pub struct SignUpSummary {
+    pub uid: String,
+    pub retry_count: i32,
+}
+
 #[derive(Clone, Default, Debug, DynoMap)]
 pub struct Data {
     pub something: i32,
@@ -60,11 +68,11 @@ pub async fn insert() {
 
     let _ = context
         .with_table("RandomTableName") //you can use with_table as an alternative to #[dynorow(table = "table_name")]
-        .get::<SaleConfirmed>(SaleConfirmed::generate_composite_key(
-            "myemail@email.com",
-            "sales_123",
-            "order_1234",
-        ))
+        .get::<SaleConfirmed>(SaleConfirmedKey {
+            email_address: "myemail@email.com".into(),
+            sale_id: "sales_123".into(),
+            order_id: "order_1234".into(),
+        })
         .await;
 
     let update_expression = SignUp::update_expression_builder()
@@ -78,12 +86,23 @@ pub async fn insert() {
 
     let _ = context
         .update_with_condition::<SignUp>(
-            SignUp::generate_composite_key("my_email_address"),
+            SignUpKey {
+                email_address: "my_email_address".into(),
+            },
             update_expression,
             condition,
         )
         .await;
 
+    let _ = context
+        .get::<SignUpSummary>(
+            TypedKey::from(SignUpKey {
+                email_address: "my_email_address".into(),
+            })
+            .into_projection(),
+        )
+        .await;
+
     assert!(SaleConfirmed::matches_template(
         "SaleConfirmed:email@somthing.com:sale_123"
     ));
@@ -106,7 +125,10 @@ pub fn test_update_expression() {
         r##"{"#var_data_something": "data.something"}"##
     );
     assert_eq!(
-        format!("{:?}", update_expression.get_expression_attribute_values()),
+        format!(
+            "{:?}",
+            update_expression.get_expression_attribute_values().unwrap()
+        ),
         r#"{":vu1": N("-1")}"#
     )
 }
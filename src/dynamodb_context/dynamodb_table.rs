@@ -1,16 +1,18 @@
 use crate::{
     GetListResult, UpdateExpression,
-    dynamodb_context::expression::conditional::{
-        ConditionalExpression, expression_builder::BuildConditionalExpression,
+    dynamodb_context::expression::{
+        conditional::{ConditionalExpression, expression_builder::BuildConditionalExpression},
+        key_condition::KeyCondition,
     },
     dynamodb_sdk_extensions::{
         items_from::ItemsFrom, with_expresssion::WithExpression, with_key::WithKey,
     },
     error::Error,
-    key::KeyValue,
+    key::{KeyValue, TypedKey},
     traits::{
-        as_key_value::AsPkAvailableCompositeKeyValue, fetchable::Fetchable, has_key::HasKey,
-        has_pk_value::HasStaticPkValue, insertable::Insertable, updatable::Updatable,
+        as_key_value::AsPkAvailableCompositeKeyValue, collection::Collection, fetchable::Fetchable,
+        has_key::HasKey, has_pk_value::HasStaticPkValue, insertable::Insertable,
+        into_attribute_value::IntoAttributeValue, updatable::Updatable,
     },
 };
 
@@ -20,7 +22,7 @@ use std::{collections::HashMap, time::Duration};
 use tokio::time::sleep;
 
 use async_recursion::async_recursion;
-use aws_sdk_dynamodb::types::{DeleteRequest, PutRequest, WriteRequest};
+use aws_sdk_dynamodb::types::{AttributeValue, DeleteRequest, PutRequest, WriteRequest};
 
 use super::operations::Operation;
 
@@ -36,13 +38,14 @@ impl<'a> DynamodbTable<'a> {
 
     pub async fn exists_with_sort_key<T: Fetchable + AsPkAvailableCompositeKeyValue>(
         &self,
-        sort_key_value: String,
+        sort_key_value: T::SortKey,
     ) -> Result<bool, Error> {
         let key = T::as_pk_available_composite_key_value(sort_key_value);
-        self.exists::<T>(key).await
+        self.exists::<T>(TypedKey::from_key_value(key)).await
     }
 
-    pub async fn exists<T: Fetchable>(&self, key: KeyValue) -> Result<bool, Error> {
+    pub async fn exists<T: Fetchable>(&self, key: impl Into<TypedKey<T>>) -> Result<bool, Error> {
+        let key: KeyValue = key.into().into();
         let row_exists_result = self
             .client
             .get_item()
@@ -58,7 +61,8 @@ impl<'a> DynamodbTable<'a> {
         };
     }
 
-    pub async fn get<T: Fetchable>(&self, key: KeyValue) -> Result<T, Error> {
+    pub async fn get<T: Fetchable>(&self, key: impl Into<TypedKey<T>>) -> Result<T, Error> {
+        let key: KeyValue = key.into().into();
         let get_item_output = self
             .client
             .get_item()
@@ -84,13 +88,17 @@ impl<'a> DynamodbTable<'a> {
 
     pub async fn get_with_sort_key<T: Fetchable + AsPkAvailableCompositeKeyValue>(
         &self,
-        sort_key_value: String,
+        sort_key_value: T::SortKey,
     ) -> Result<T, Error> {
         let key = T::as_pk_available_composite_key_value(sort_key_value);
-        self.get(key).await
+        self.get(TypedKey::from_key_value(key)).await
     }
 
-    pub async fn get_maybe<T: Fetchable>(&self, key: KeyValue) -> Result<Option<T>, Error> {
+    pub async fn get_maybe<T: Fetchable>(
+        &self,
+        key: impl Into<TypedKey<T>>,
+    ) -> Result<Option<T>, Error> {
+        let key: KeyValue = key.into().into();
         let get_item_output = self
             .client
             .get_item()
@@ -122,10 +130,10 @@ impl<'a> DynamodbTable<'a> {
 
     pub async fn get_maybe_with_sort_key<T: Fetchable + AsPkAvailableCompositeKeyValue>(
         &self,
-        sort_key_value: String,
+        sort_key_value: T::SortKey,
     ) -> Result<Option<T>, Error> {
         let key = T::as_pk_available_composite_key_value(sort_key_value);
-        self.get_maybe(key).await
+        self.get_maybe(TypedKey::from_key_value(key)).await
     }
 
     pub async fn get_list_with_pk_value<T: Fetchable + HasKey>(
@@ -155,6 +163,23 @@ impl<'a> DynamodbTable<'a> {
             .await
     }
 
+    pub async fn get_list_with_key_condition<T: Fetchable + HasKey>(
+        &self,
+        key_condition: impl Into<KeyCondition<T>>,
+        count: u16,
+        last_key_value: Option<KeyValue>,
+        accending: bool,
+    ) -> Result<GetListResult<T>, Error> {
+        let key_condition: KeyCondition<T> = key_condition.into();
+        self.get_list_with_condition(
+            key_condition.into_conditional_expression(),
+            count,
+            last_key_value,
+            accending,
+        )
+        .await
+    }
+
     pub async fn get_list_with_condition<T: Fetchable + HasKey>(
         &self,
         key_conditional_expression: ConditionalExpression,
@@ -171,14 +196,14 @@ impl<'a> DynamodbTable<'a> {
                 key_conditional_expression.get_expression_attribute_names(),
             ))
             .set_expression_attribute_values(Some(
-                key_conditional_expression.get_expression_attribute_values(),
+                key_conditional_expression.get_expression_attribute_values()?,
             ))
             .key_condition_expression(key_conditional_expression.to_string())
             .set_expression_attribute_names(Some(
                 key_conditional_expression.get_expression_attribute_names(),
             ))
             .set_expression_attribute_values(Some(
-                key_conditional_expression.get_expression_attribute_values(),
+                key_conditional_expression.get_expression_attribute_values()?,
             ))
             .scan_index_forward(accending)
             .limit(count as i32);
@@ -215,11 +240,48 @@ impl<'a> DynamodbTable<'a> {
         return Ok(GetListResult::new(result, last_key));
     }
 
+    /// Reads every item of the partition, following the pagination, and assembles them into `T`.
+    pub async fn get_collection<T: Collection + HasKey>(
+        &self,
+        partition_key_value: impl IntoAttributeValue,
+    ) -> Result<T, Error> {
+        let key_conditional_expression =
+            T::get_key().get_partition_key().equals(partition_key_value);
+
+        let mut items: Vec<HashMap<String, AttributeValue>> = vec![];
+        let mut last_key: Option<HashMap<String, AttributeValue>> = None;
+        loop {
+            let query_result = self
+                .client
+                .query()
+                .table_name(&self.table_name)
+                .key_condition_expression(key_conditional_expression.to_string())
+                .set_expression_attribute_names(Some(
+                    key_conditional_expression.get_expression_attribute_names(),
+                ))
+                .set_expression_attribute_values(Some(
+                    key_conditional_expression.get_expression_attribute_values()?,
+                ))
+                .set_exclusive_start_key(last_key)
+                .send()
+                .await
+                .map_err(|e| Error::sdk_error("Error while performaing collection query.", e))?;
+
+            items.extend(query_result.items.unwrap_or_default());
+            last_key = query_result.last_evaluated_key;
+            if last_key.is_none() {
+                break;
+            }
+        }
+
+        T::from_items(items)
+    }
+
     pub async fn insert_row<T: Insertable>(&self, row: T) -> Result<(), Error> {
         self.client
             .put_item()
             .table_name(self.table_name.clone())
-            .items_from(&row)
+            .items_from(&row)?
             .send()
             .await
             .map(|_| ())
@@ -233,8 +295,8 @@ impl<'a> DynamodbTable<'a> {
         self.client
             .update_item()
             .table_name(self.table_name.clone())
-            .with_key(&row.as_key_value())
-            .with_expression(&row)
+            .with_key(row.as_key_value().key_value())
+            .with_expression(&row)?
             .send()
             .await
             .map(|_| ())
@@ -246,16 +308,17 @@ impl<'a> DynamodbTable<'a> {
 
     pub async fn update_with_expression<T: Updatable>(
         &self,
-        key_value: KeyValue,
+        key: impl Into<TypedKey<T>>,
         expression: UpdateExpression,
     ) -> Result<(), Error> {
+        let key_value: KeyValue = key.into().into();
         self.client
             .update_item()
             .table_name(&self.table_name)
             .set_key(Some(key_value.clone().into_hash_map()))
             .update_expression(expression.to_string())
             .set_expression_attribute_names(Some(expression.get_expression_attribute_names()))
-            .set_expression_attribute_values(Some(expression.get_expression_attribute_values()))
+            .set_expression_attribute_values(Some(expression.get_expression_attribute_values()?))
             .send()
             .await
             .map(|_| ())
@@ -270,15 +333,16 @@ impl<'a> DynamodbTable<'a> {
 
     pub async fn update_with_condition<T: Updatable>(
         &self,
-        key_value: KeyValue,
+        key: impl Into<TypedKey<T>>,
         update: UpdateExpression,
         condition: ConditionalExpression,
     ) -> Result<(), Error> {
+        let key_value: KeyValue = key.into().into();
         let mut attribute_names = update.get_expression_attribute_names();
         attribute_names.extend(condition.get_expression_attribute_names());
 
-        let mut attribute_values = update.get_expression_attribute_values();
-        attribute_values.extend(condition.get_expression_attribute_values());
+        let mut attribute_values = update.get_expression_attribute_values()?;
+        attribute_values.extend(condition.get_expression_attribute_values()?);
 
         self.client
             .update_item()
@@ -300,7 +364,8 @@ impl<'a> DynamodbTable<'a> {
             })
     }
 
-    pub async fn delete(&self, key_value: KeyValue) -> Result<(), Error> {
+    pub async fn delete<T>(&self, key: impl Into<TypedKey<T>>) -> Result<(), Error> {
+        let key_value: KeyValue = key.into().into();
         self.client
             .delete_item()
             .table_name(self.table_name.clone())
@@ -316,17 +381,18 @@ impl<'a> DynamodbTable<'a> {
 
     pub async fn delete_with_sort_key<T: AsPkAvailableCompositeKeyValue>(
         &self,
-        sort_key_value: String,
+        sort_key_value: T::SortKey,
     ) -> Result<(), Error> {
         let key_value = T::as_pk_available_composite_key_value(sort_key_value);
-        self.delete(key_value).await
+        self.delete::<T>(TypedKey::from_key_value(key_value)).await
     }
 
-    pub async fn delete_with_condition(
+    pub async fn delete_with_condition<T>(
         &self,
-        key_value: KeyValue,
+        key: impl Into<TypedKey<T>>,
         conditional_expression: ConditionalExpression,
     ) -> Result<(), Error> {
+        let key_value: KeyValue = key.into().into();
         self.client
             .delete_item()
             .table_name(self.table_name.clone())
@@ -336,7 +402,7 @@ impl<'a> DynamodbTable<'a> {
                 conditional_expression.get_expression_attribute_names(),
             ))
             .set_expression_attribute_values(Some(
-                conditional_expression.get_expression_attribute_values(),
+                conditional_expression.get_expression_attribute_values()?,
             ))
             .send()
             .await
@@ -451,7 +517,7 @@ impl<'a> DynamodbTable<'a> {
                     let put_request = WriteRequest::builder()
                         .put_request(
                             PutRequest::builder()
-                                .set_item(Some(data.as_attribute_key_values()))
+                                .set_item(Some(data.try_as_attribute_key_values()?))
                                 .build()
                                 .map_err(|e| {
                                     Error::sdk_error("Error while building put request.", e)
@@ -1,56 +1,225 @@
+use std::marker::PhantomData;
+
 use aws_sdk_dynamodb::types::AttributeValue;
 
 use crate::{
-    dynamodb_context::expression::conditional::{
-        ConditionalExpression, operations::RelationalOperation,
+    dynamodb_context::expression::{
+        conditional::{ConditionalExpression, operations::RelationalOperation},
+        operand::{ConditionOperand, IntoConditionOperand},
     },
-    traits::into_attribute_value::IntoAttributeValue,
+    error::Error,
+    traits::{dyno_enum_trait::DynoEnumTrait, into_attribute_value::IntoAttributeValue},
 };
 
-pub struct ConditionalExpressionBuilder {
+/// `V` is the type of the field, derived builders use it to offer typed functions
+/// such as `equals` and `in_list` for `DynoEnum` fields.
+pub struct ConditionalExpressionBuilder<V = AttributeValue> {
     pub key: String,
+    _v: PhantomData<V>,
 }
 
 impl ConditionalExpressionBuilder {
     pub fn new(key: &str) -> Self {
-        Self { key: key.into() }
+        Self::typed(key)
+    }
+}
+
+impl<V> ConditionalExpressionBuilder<V> {
+    pub fn typed(key: &str) -> Self {
+        Self {
+            key: key.into(),
+            _v: PhantomData,
+        }
+    }
+
+    /// `size(key)` of this attribute, to be used as an operand.
+    pub fn size(self) -> ConditionOperand {
+        ConditionOperand::Size(self.key)
+    }
+}
+
+impl<V> ConditionalExpressionBuilder<V>
+where
+    V: DynoEnumTrait + IntoAttributeValue,
+{
+    pub fn equals(self, value: V) -> ConditionalExpression {
+        self.key.equals(value)
+    }
+
+    pub fn in_list(self, values: impl IntoIterator<Item = V>) -> ConditionalExpression {
+        self.key.in_list(values)
     }
 }
 
-impl BuildConditionalExpression for ConditionalExpressionBuilder {
+impl<V> ConditionalExpressionBuilder<Option<V>>
+where
+    V: DynoEnumTrait + IntoAttributeValue,
+{
+    pub fn equals(self, value: V) -> ConditionalExpression {
+        self.key.equals(value)
+    }
+
+    pub fn in_list(self, values: impl IntoIterator<Item = V>) -> ConditionalExpression {
+        self.key.in_list(values)
+    }
+}
+
+impl<V> IntoConditionOperand for ConditionalExpressionBuilder<V> {
+    fn into_condition_operand(self) -> ConditionOperand {
+        ConditionOperand::Path(self.key)
+    }
+}
+
+/// Conditional expression builder of a `#[dynorow(with = "...")]` field. <br>
+/// Takes values of the field type and converts them with the `to_attribute_value` function of the module,
+/// so that they compare with the stored encoding.
+pub struct ConvertedConditionalExpressionBuilder<V> {
+    pub key: String,
+    pub to_attribute_value: fn(&V) -> Result<AttributeValue, Error>,
+}
+
+impl<V> ConvertedConditionalExpressionBuilder<V> {
+    pub fn new(key: &str, to_attribute_value: fn(&V) -> Result<AttributeValue, Error>) -> Self {
+        Self {
+            key: key.into(),
+            to_attribute_value,
+        }
+    }
+
+    /// A conversion error is returned by `get_expression_attribute_values` of the expression.
+    pub fn equals(self, value: V) -> ConditionalExpression {
+        let value = self.convert(&value);
+        ConditionalExpression::unit(self.key, RelationalOperation::Equals(value))
+    }
+
+    /// A conversion error is returned by `get_expression_attribute_values` of the expression.
+    pub fn not_equals(self, value: V) -> ConditionalExpression {
+        let value = self.convert(&value);
+        ConditionalExpression::unit(self.key, RelationalOperation::NotEquals(value))
+    }
+
+    /// A conversion error is returned by `get_expression_attribute_values` of the expression.
+    pub fn in_list(self, values: impl IntoIterator<Item = V>) -> ConditionalExpression {
+        let values = values.into_iter().map(|x| self.convert(&x)).collect();
+        ConditionalExpression::unit(self.key, RelationalOperation::In(values))
+    }
+
+    /// `size(key)` of this attribute, to be used as an operand.
+    pub fn size(self) -> ConditionOperand {
+        ConditionOperand::Size(self.key)
+    }
+
+    fn convert(&self, value: &V) -> ConditionOperand {
+        ConditionOperand::Value((self.to_attribute_value)(value).map_err(|e| format!("{:?}", e)))
+    }
+}
+
+impl<V> IntoConditionOperand for ConvertedConditionalExpressionBuilder<V> {
+    fn into_condition_operand(self) -> ConditionOperand {
+        ConditionOperand::Path(self.key)
+    }
+}
+
+impl<V> BuildConditionalExpression for ConditionalExpressionBuilder<V> {
     fn string_equals(self, value: &str) -> ConditionalExpression {
         self.key.string_equals(value)
     }
 
-    fn equals(self, value: impl IntoAttributeValue) -> ConditionalExpression {
+    fn equals(self, value: impl IntoConditionOperand) -> ConditionalExpression {
         self.key.equals(value)
     }
 
+    fn not_equals(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        self.key.not_equals(value)
+    }
+
+    fn less_than(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        self.key.less_than(value)
+    }
+
+    fn less_than_or_equal_to(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        self.key.less_than_or_equal_to(value)
+    }
+
+    fn greater_than(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        self.key.greater_than(value)
+    }
+
+    fn greater_than_or_equal_to(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        self.key.greater_than_or_equal_to(value)
+    }
+
     fn string_between(self, a1: String, a2: String) -> ConditionalExpression {
         self.key.string_between(a1, a2)
     }
 
     fn between(
         self,
-        a1: impl IntoAttributeValue,
-        a2: impl IntoAttributeValue,
+        a1: impl IntoConditionOperand,
+        a2: impl IntoConditionOperand,
     ) -> ConditionalExpression {
         self.key.between(a1, a2)
     }
+
+    fn in_list<T: IntoConditionOperand>(
+        self,
+        values: impl IntoIterator<Item = T>,
+    ) -> ConditionalExpression {
+        self.key.in_list(values)
+    }
+
+    fn begins_with(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        self.key.begins_with(value)
+    }
 }
 
 impl BuildConditionalExpression for String {
     fn string_equals(self, value: &str) -> ConditionalExpression {
         ConditionalExpression::unit(
             self,
-            RelationalOperation::Equals(AttributeValue::S(value.into())),
+            RelationalOperation::Equals(AttributeValue::S(value.into()).into()),
+        )
+    }
+
+    fn equals(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        ConditionalExpression::unit(
+            self,
+            RelationalOperation::Equals(value.into_condition_operand()),
+        )
+    }
+
+    fn not_equals(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        ConditionalExpression::unit(
+            self,
+            RelationalOperation::NotEquals(value.into_condition_operand()),
+        )
+    }
+
+    fn less_than(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        ConditionalExpression::unit(
+            self,
+            RelationalOperation::LessThan(value.into_condition_operand()),
         )
     }
 
-    fn equals(self, value: impl IntoAttributeValue) -> ConditionalExpression {
+    fn less_than_or_equal_to(self, value: impl IntoConditionOperand) -> ConditionalExpression {
         ConditionalExpression::unit(
             self,
-            RelationalOperation::Equals(value.into_attribute_value()),
+            RelationalOperation::LessThanOrEqualTo(value.into_condition_operand()),
+        )
+    }
+
+    fn greater_than(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        ConditionalExpression::unit(
+            self,
+            RelationalOperation::GreaterThan(value.into_condition_operand()),
+        )
+    }
+
+    fn greater_than_or_equal_to(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        ConditionalExpression::unit(
+            self,
+            RelationalOperation::GreaterThanOrEqualTo(value.into_condition_operand()),
         )
     }
 
@@ -60,24 +229,58 @@ impl BuildConditionalExpression for String {
 
     fn between(
         self,
-        a1: impl IntoAttributeValue,
-        a2: impl IntoAttributeValue,
+        a1: impl IntoConditionOperand,
+        a2: impl IntoConditionOperand,
     ) -> ConditionalExpression {
         ConditionalExpression::unit(
             self,
-            RelationalOperation::Between(a1.into_attribute_value(), a2.into_attribute_value()),
+            RelationalOperation::Between(a1.into_condition_operand(), a2.into_condition_operand()),
+        )
+    }
+
+    fn in_list<T: IntoConditionOperand>(
+        self,
+        values: impl IntoIterator<Item = T>,
+    ) -> ConditionalExpression {
+        ConditionalExpression::unit(
+            self,
+            RelationalOperation::In(
+                values
+                    .into_iter()
+                    .map(|x| x.into_condition_operand())
+                    .collect(),
+            ),
+        )
+    }
+    fn begins_with(self, value: impl IntoConditionOperand) -> ConditionalExpression {
+        ConditionalExpression::unit(
+            self,
+            RelationalOperation::BeginsWith(value.into_condition_operand()),
         )
     }
 }
 
+/// Every value taking function accepts either a literal value, another attribute
+/// (a `ConditionalExpressionBuilder` of the same model) or a `ConditionOperand`.
 pub trait BuildConditionalExpression {
     #[allow(unused)]
     fn string_equals(self, value: &str) -> ConditionalExpression;
-    fn equals(self, value: impl IntoAttributeValue) -> ConditionalExpression;
+    fn equals(self, value: impl IntoConditionOperand) -> ConditionalExpression;
+    fn not_equals(self, value: impl IntoConditionOperand) -> ConditionalExpression;
+    fn less_than(self, value: impl IntoConditionOperand) -> ConditionalExpression;
+    fn less_than_or_equal_to(self, value: impl IntoConditionOperand) -> ConditionalExpression;
+    fn greater_than(self, value: impl IntoConditionOperand) -> ConditionalExpression;
+    fn greater_than_or_equal_to(self, value: impl IntoConditionOperand) -> ConditionalExpression;
     fn between(
         self,
-        a1: impl IntoAttributeValue,
-        a2: impl IntoAttributeValue,
+        a1: impl IntoConditionOperand,
+        a2: impl IntoConditionOperand,
     ) -> ConditionalExpression;
     fn string_between(self, a1: String, a2: String) -> ConditionalExpression;
+    fn in_list<T: IntoConditionOperand>(
+        self,
+        values: impl IntoIterator<Item = T>,
+    ) -> ConditionalExpression;
+    /// `begins_with(key, value)`, also usable in key conditions on the sort key.
+    fn begins_with(self, value: impl IntoConditionOperand) -> ConditionalExpression;
 }
@@ -6,12 +6,15 @@ use std::collections::HashMap;
 
 use aws_sdk_dynamodb::types::AttributeValue;
 
-use crate::dynamodb_context::expression::{
-    AsVariable, ExpressionContext,
-    conditional::{
-        joiner::ConditionalExpressionJoiner,
-        operations::{ConditionalOpeartion, RelationalOperation},
+use crate::{
+    dynamodb_context::expression::{
+        AsVariable, ExpressionContext,
+        conditional::{
+            joiner::ConditionalExpressionJoiner,
+            operations::{ConditionalOpeartion, RelationalOperation},
+        },
     },
+    error::Error,
 };
 
 // pending refactor
@@ -46,7 +49,7 @@ impl ConditionalExpression {
     }
 
     pub fn or(self) -> ConditionalExpressionJoiner {
-        ConditionalExpressionJoiner::new(self, ConditionalOpeartion::And)
+        ConditionalExpressionJoiner::new(self, ConditionalOpeartion::Or)
     }
 
     pub fn get_expression_attribute_names(&self) -> HashMap<String, String> {
@@ -58,9 +61,10 @@ impl ConditionalExpression {
             }
             ConditionalExpression::Unit {
                 key,
-                relational_operation: _,
+                relational_operation,
             } => {
                 result.insert(key.as_variable(), key.clone());
+                result.extend(relational_operation.get_expression_attribute_names());
             }
             ConditionalExpression::Binary {
                 left,
@@ -75,7 +79,10 @@ impl ConditionalExpression {
         return result;
     }
 
-    pub fn get_expression_attribute_values(&self) -> HashMap<String, AttributeValue> {
+    /// Returns the conversion error of a value operand instead of panicking.
+    pub fn get_expression_attribute_values(
+        &self,
+    ) -> Result<HashMap<String, AttributeValue>, Error> {
         let mut context = ExpressionContext::new("vc");
         self.get_expression_attribute_values_with_context(&mut context)
     }
@@ -83,36 +90,28 @@ impl ConditionalExpression {
     fn get_expression_attribute_values_with_context(
         &self,
         context: &mut ExpressionContext,
-    ) -> HashMap<String, AttributeValue> {
+    ) -> Result<HashMap<String, AttributeValue>, Error> {
         let mut result: HashMap<String, AttributeValue> = HashMap::new();
 
         match self {
             ConditionalExpression::Bracket(expression) => {
-                result.extend(expression.get_expression_attribute_values_with_context(context))
+                result.extend(expression.get_expression_attribute_values_with_context(context)?)
             }
             ConditionalExpression::Unit {
                 key: _,
                 relational_operation,
-            } => match relational_operation {
-                RelationalOperation::Equals(attribute_value) => {
-                    result.insert(context.next(), attribute_value.clone());
-                }
-                RelationalOperation::Between(a1, a2) => {
-                    result.insert(context.next(), a1.clone());
-                    result.insert(context.next(), a2.clone());
-                }
-            },
+            } => result.extend(relational_operation.get_expression_attribute_values(context)?),
             ConditionalExpression::Binary {
                 left,
                 conditional_operation: _,
                 right,
             } => {
-                result.extend(left.get_expression_attribute_values_with_context(context));
-                result.extend(right.get_expression_attribute_values_with_context(context));
+                result.extend(left.get_expression_attribute_values_with_context(context)?);
+                result.extend(right.get_expression_attribute_values_with_context(context)?);
             }
         }
 
-        return result;
+        Ok(result)
     }
 
     pub fn to_string(&self) -> String {
@@ -128,11 +127,7 @@ impl ConditionalExpression {
             ConditionalExpression::Unit {
                 key,
                 relational_operation,
-            } => format!(
-                "{} {}",
-                key.as_variable(),
-                relational_operation.to_string(context)
-            ),
+            } => relational_operation.to_string(&key.as_variable(), context),
             ConditionalExpression::Binary {
                 left,
                 conditional_operation,
@@ -158,9 +153,9 @@ fn test_conditional_expression_generation() {
         .and()
         .expr(sk.string_equals("user123"));
 
-    assert_eq!("#var_pk = :v1 AND #var_sk = :v2", expression.to_string());
+    assert_eq!("#var_pk = :vc1 AND #var_sk = :vc2", expression.to_string());
 
-    let attribute_values = expression.get_expression_attribute_values();
+    let attribute_values = expression.get_expression_attribute_values().unwrap();
     assert!(attribute_values.len() == 2);
     assert!({
         attribute_values.get(":vc1").unwrap().as_s().unwrap() == "User"
@@ -174,3 +169,33 @@ fn test_conditional_expression_generation() {
             && attribute_names.get("#var_sk").unwrap() == "sk"
     })
 }
+
+#[test]
+fn test_conditional_expression_with_attribute_operand() {
+    use crate::dynamodb_context::expression::conditional::expression_builder::{
+        BuildConditionalExpression, ConditionalExpressionBuilder,
+    };
+
+    let expression = ConditionalExpressionBuilder::new("retry_count")
+        .less_than(ConditionalExpressionBuilder::new("max_retries"))
+        .or()
+        .expr(
+            ConditionalExpressionBuilder::new("tag_count")
+                .greater_than(ConditionalExpressionBuilder::new("tags").size()),
+        )
+        .and()
+        .expr(ConditionalExpressionBuilder::new("tag_count").less_than(3));
+
+    assert_eq!(
+        "#var_retry_count < #var_max_retries OR #var_tag_count > size(#var_tags) AND #var_tag_count < :vc1",
+        expression.to_string()
+    );
+
+    let attribute_values = expression.get_expression_attribute_values().unwrap();
+    assert!(attribute_values.len() == 1);
+    assert!(attribute_values.get(":vc1").unwrap().as_n().unwrap() == "3");
+
+    let attribute_names = expression.get_expression_attribute_names();
+    assert!(attribute_names.len() == 4);
+    assert!(attribute_names.get("#var_max_retries").unwrap() == "max_retries");
+}
@@ -1,5 +1,9 @@
+use std::collections::HashMap;
+
 use aws_sdk_dynamodb::types::AttributeValue;
 
+use crate::{dynamodb_context::expression::operand::ConditionOperand, error::Error};
+
 use super::ExpressionContext;
 
 #[derive(Debug, Clone)]
@@ -19,24 +23,84 @@ impl ConditionalOpeartion {
 
 #[derive(Debug, Clone)]
 pub enum RelationalOperation {
-    Equals(AttributeValue),
-    Between(AttributeValue, AttributeValue),
-    // In(Vec<AttributeValue>)
-    // LessThan,
-    // LessThanOrEqualTo,
-    // GreaterThan,
-    // GreaterThanOrEqualTo,
-    // Between,
-    // BeginsWith
+    Equals(ConditionOperand),
+    NotEquals(ConditionOperand),
+    LessThan(ConditionOperand),
+    LessThanOrEqualTo(ConditionOperand),
+    GreaterThan(ConditionOperand),
+    GreaterThanOrEqualTo(ConditionOperand),
+    Between(ConditionOperand, ConditionOperand),
+    In(Vec<ConditionOperand>),
+    BeginsWith(ConditionOperand),
 }
 
 impl RelationalOperation {
-    pub fn to_string(&self, context: &mut ExpressionContext) -> String {
+    /// `key` is the expression attribute name of the compared attribute.
+    pub fn to_string(&self, key: &str, context: &mut ExpressionContext) -> String {
         match self {
-            RelationalOperation::Equals(_) => format!("= {}", context.next()),
-            RelationalOperation::Between(_, _) => {
-                format!("BETWEEN {} AND {}", context.next(), context.next())
+            RelationalOperation::Equals(x) => format!("{} = {}", key, x.to_string(context)),
+            RelationalOperation::NotEquals(x) => format!("{} <> {}", key, x.to_string(context)),
+            RelationalOperation::LessThan(x) => format!("{} < {}", key, x.to_string(context)),
+            RelationalOperation::LessThanOrEqualTo(x) => {
+                format!("{} <= {}", key, x.to_string(context))
+            }
+            RelationalOperation::GreaterThan(x) => format!("{} > {}", key, x.to_string(context)),
+            RelationalOperation::GreaterThanOrEqualTo(x) => {
+                format!("{} >= {}", key, x.to_string(context))
+            }
+            RelationalOperation::Between(a1, a2) => {
+                format!(
+                    "{} BETWEEN {} AND {}",
+                    key,
+                    a1.to_string(context),
+                    a2.to_string(context)
+                )
             }
+            RelationalOperation::In(values) => format!(
+                "{} IN ({})",
+                key,
+                values
+                    .iter()
+                    .map(|x| x.to_string(context))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            RelationalOperation::BeginsWith(x) => {
+                format!("begins_with({}, {})", key, x.to_string(context))
+            }
+        }
+    }
+
+    pub fn get_operands(&self) -> Vec<&ConditionOperand> {
+        match self {
+            RelationalOperation::Equals(x)
+            | RelationalOperation::NotEquals(x)
+            | RelationalOperation::LessThan(x)
+            | RelationalOperation::LessThanOrEqualTo(x)
+            | RelationalOperation::GreaterThan(x)
+            | RelationalOperation::GreaterThanOrEqualTo(x)
+            | RelationalOperation::BeginsWith(x) => vec![x],
+            RelationalOperation::Between(a1, a2) => vec![a1, a2],
+            RelationalOperation::In(values) => values.iter().collect(),
+        }
+    }
+
+    pub fn get_expression_attribute_names(&self) -> HashMap<String, String> {
+        let mut result: HashMap<String, String> = HashMap::new();
+        for operand in self.get_operands() {
+            result.extend(operand.get_expression_attribute_names());
+        }
+        result
+    }
+
+    pub fn get_expression_attribute_values(
+        &self,
+        context: &mut ExpressionContext,
+    ) -> Result<HashMap<String, AttributeValue>, Error> {
+        let mut result: HashMap<String, AttributeValue> = HashMap::new();
+        for operand in self.get_operands() {
+            result.extend(operand.get_expression_attribute_values(context)?);
         }
+        Ok(result)
     }
 }
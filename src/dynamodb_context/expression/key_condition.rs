@@ -0,0 +1,232 @@
+use std::marker::PhantomData;
+
+use crate::{
+    dynamodb_context::expression::{
+        conditional::{ConditionalExpression, expression_builder::BuildConditionalExpression},
+        operand::{ConditionOperand, IntoConditionOperand},
+    },
+    traits::{
+        has_key::HasKey, has_sort_key::HasSortKey, into_attribute_value::IntoAttributeValue,
+        projection_of::ProjectionOf, scalar_attribute::SortKeyPrefix,
+    },
+};
+
+/// Key condition of a query on `T`, built with the generated `T::key_condition(..)`. <br>
+/// It always compares the partition key with `=` and at most once the sort key.
+#[derive(Debug, Clone)]
+pub struct KeyCondition<T> {
+    expression: ConditionalExpression,
+    _t: PhantomData<T>,
+}
+
+impl<T> KeyCondition<T> {
+    pub fn into_conditional_expression(self) -> ConditionalExpression {
+        self.expression
+    }
+
+    /// Same condition for a projection of `T`.
+    pub fn into_projection<P: ProjectionOf<Parent = T>>(self) -> KeyCondition<P> {
+        KeyCondition {
+            expression: self.expression,
+            _t: PhantomData,
+        }
+    }
+}
+
+/// Partition key condition of `T`, `S` is the value type of the sort key, as in the conditional expression builders. <br>
+/// Every sort key function completes the condition, so only one can be used.
+#[derive(Debug, Clone)]
+pub struct KeyConditionBuilder<T, S = ()> {
+    partition_key_value: ConditionOperand,
+    _t: PhantomData<(T, S)>,
+}
+
+impl<T: HasKey, S> KeyConditionBuilder<T, S> {
+    pub fn new(partition_key_value: impl IntoAttributeValue) -> Self {
+        Self {
+            partition_key_value: partition_key_value.into_condition_operand(),
+            _t: PhantomData,
+        }
+    }
+
+    /// Condition on the partition key only.
+    pub fn build(self) -> KeyCondition<T> {
+        self.with_sort_key_condition(None)
+    }
+
+    fn with_sort_key_condition(
+        self,
+        sort_key_condition: Option<ConditionalExpression>,
+    ) -> KeyCondition<T> {
+        let partition_key_condition = T::get_key()
+            .get_partition_key()
+            .equals(self.partition_key_value);
+        let expression = match sort_key_condition {
+            Some(x) => partition_key_condition.and().expr(x),
+            None => partition_key_condition,
+        };
+        KeyCondition {
+            expression,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T, S> KeyConditionBuilder<T, S>
+where
+    T: HasKey + HasSortKey,
+    S: IntoAttributeValue,
+{
+    pub fn sort_key_equals(self, value: S) -> KeyCondition<T> {
+        let condition = T::get_sort_key().equals(value);
+        self.with_sort_key_condition(Some(condition))
+    }
+
+    pub fn sort_key_less_than(self, value: S) -> KeyCondition<T> {
+        let condition = T::get_sort_key().less_than(value);
+        self.with_sort_key_condition(Some(condition))
+    }
+
+    pub fn sort_key_less_than_or_equal_to(self, value: S) -> KeyCondition<T> {
+        let condition = T::get_sort_key().less_than_or_equal_to(value);
+        self.with_sort_key_condition(Some(condition))
+    }
+
+    pub fn sort_key_greater_than(self, value: S) -> KeyCondition<T> {
+        let condition = T::get_sort_key().greater_than(value);
+        self.with_sort_key_condition(Some(condition))
+    }
+
+    pub fn sort_key_greater_than_or_equal_to(self, value: S) -> KeyCondition<T> {
+        let condition = T::get_sort_key().greater_than_or_equal_to(value);
+        self.with_sort_key_condition(Some(condition))
+    }
+
+    pub fn sort_key_between(self, a1: S, a2: S) -> KeyCondition<T> {
+        let condition = T::get_sort_key().between(a1, a2);
+        self.with_sort_key_condition(Some(condition))
+    }
+}
+
+/// `begins_with` is only available for sort keys stored as strings or binary.
+impl<T, S> KeyConditionBuilder<T, S>
+where
+    T: HasKey + HasSortKey,
+    S: SortKeyPrefix,
+{
+    pub fn sort_key_begins_with(self, prefix: impl Into<S::Prefix>) -> KeyCondition<T> {
+        let condition = T::get_sort_key().begins_with(prefix.into());
+        self.with_sort_key_condition(Some(condition))
+    }
+}
+
+impl<T: HasKey, S> From<KeyConditionBuilder<T, S>> for KeyCondition<T> {
+    fn from(value: KeyConditionBuilder<T, S>) -> Self {
+        value.build()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::{self as dynorow, DynoRow, DynoValue};
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Score")]
+    pub struct Score {
+        #[dynorow(sk)]
+        pub points: u32,
+        pub player: String,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Player#{player_id}")]
+    #[dynorow(sk = "sk", sk_value = "GAME#{game_id}")]
+    pub struct PlayerGame {
+        pub player_id: String,
+        pub game_id: String,
+    }
+
+    #[derive(Debug, Clone, DynoValue)]
+    #[dynorow(transparent)]
+    pub struct MoveId(pub String);
+
+    #[derive(Debug, Clone, DynoRow)]
+    pub struct Move {
+        #[dynorow(pk)]
+        pub game_id: String,
+        #[dynorow(sk)]
+        pub move_id: MoveId,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    pub struct Replay {
+        #[dynorow(pk)]
+        pub game_id: String,
+        #[dynorow(sk)]
+        pub digest: Vec<u8>,
+    }
+
+    #[test]
+    pub fn test_key_condition() {
+        let expression = Score::key_condition()
+            .sort_key_between(10, 20)
+            .into_conditional_expression();
+        assert_eq!(
+            "#var_pk = :vc1 AND #var_points BETWEEN :vc2 AND :vc3",
+            expression.to_string()
+        );
+        let attribute_values = expression.get_expression_attribute_values().unwrap();
+        assert_eq!(
+            attribute_values.get(":vc1").unwrap().as_s().unwrap(),
+            "Score"
+        );
+        assert_eq!(attribute_values.get(":vc3").unwrap().as_n().unwrap(), "20");
+
+        let expression = PlayerGame::key_condition("player_1")
+            .sort_key_begins_with("GAME#")
+            .into_conditional_expression();
+        assert_eq!(
+            "#var_pk = :vc1 AND begins_with(#var_sk, :vc2)",
+            expression.to_string()
+        );
+        let attribute_values = expression.get_expression_attribute_values().unwrap();
+        assert_eq!(
+            attribute_values.get(":vc1").unwrap().as_s().unwrap(),
+            "Player#player_1"
+        );
+
+        let expression = PlayerGame::key_condition("player_1")
+            .build()
+            .into_conditional_expression();
+        assert_eq!("#var_pk = :vc1", expression.to_string());
+    }
+    #[test]
+    pub fn test_begins_with_stored_string_or_binary() {
+        let expression = Move::key_condition("game_1".to_string())
+            .sort_key_begins_with("2024-")
+            .into_conditional_expression();
+        assert_eq!(
+            "#var_game_id = :vc1 AND begins_with(#var_move_id, :vc2)",
+            expression.to_string()
+        );
+        let attribute_values = expression.get_expression_attribute_values().unwrap();
+        assert_eq!(
+            attribute_values.get(":vc2").unwrap().as_s().unwrap(),
+            "2024-"
+        );
+
+        let expression = Replay::key_condition("game_1".to_string())
+            .sort_key_begins_with(vec![0x1f, 0x8b])
+            .into_conditional_expression();
+        let attribute_values = expression.get_expression_attribute_values().unwrap();
+        assert_eq!(
+            attribute_values
+                .get(":vc2")
+                .unwrap()
+                .as_b()
+                .unwrap()
+                .as_ref(),
+            &[0x1f, 0x8b]
+        );
+    }
+}
@@ -1,4 +1,6 @@
 pub mod conditional;
+pub mod key_condition;
+pub mod operand;
 pub mod update;
 
 pub struct ExpressionContext {
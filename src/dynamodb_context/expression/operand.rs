@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::types::AttributeValue;
+
+use crate::{
+    dynamodb_context::expression::{AsVariable, ExpressionContext},
+    error::Error,
+    traits::into_attribute_value::IntoAttributeValue,
+};
+
+/// Result of converting an operand value, the error is kept as a message since operands are `Clone`.
+pub(crate) fn try_value(value: impl IntoAttributeValue) -> Result<AttributeValue, String> {
+    value
+        .try_into_attribute_value()
+        .map_err(|e| format!("{:?}", e))
+}
+
+pub(crate) fn value_or_error(
+    value: &Result<AttributeValue, String>,
+) -> Result<AttributeValue, Error> {
+    value.clone().map_err(Error::IntoAttributeError)
+}
+
+/// Right hand side of a condition. <br>
+/// Can be a literal value, another attribute path or the size of an attribute,
+/// conditions do not support arithmetic or the `SET` functions. <br>
+/// A value that fails to convert is returned by `get_expression_attribute_values` of the expression.
+#[derive(Debug, Clone)]
+pub enum ConditionOperand {
+    Value(Result<AttributeValue, String>),
+    Path(String),
+    Size(String),
+}
+
+impl ConditionOperand {
+    pub fn value(value: impl IntoAttributeValue) -> ConditionOperand {
+        ConditionOperand::Value(try_value(value))
+    }
+
+    pub fn path(key: &str) -> ConditionOperand {
+        ConditionOperand::Path(key.into())
+    }
+
+    pub fn size(key: &str) -> ConditionOperand {
+        ConditionOperand::Size(key.into())
+    }
+
+    pub fn to_string(&self, context: &mut ExpressionContext) -> String {
+        match self {
+            ConditionOperand::Value(_) => context.next(),
+            ConditionOperand::Path(key) => key.as_variable(),
+            ConditionOperand::Size(key) => format!("size({})", key.as_variable()),
+        }
+    }
+
+    pub fn get_expression_attribute_names(&self) -> HashMap<String, String> {
+        let mut result: HashMap<String, String> = HashMap::new();
+        match self {
+            ConditionOperand::Value(_) => {}
+            ConditionOperand::Path(key) | ConditionOperand::Size(key) => {
+                result.insert(key.as_variable(), key.clone());
+            }
+        }
+        result
+    }
+
+    /// Has to walk the operand in the same order as `to_string`, so that the placeholders line up.
+    pub fn get_expression_attribute_values(
+        &self,
+        context: &mut ExpressionContext,
+    ) -> Result<HashMap<String, AttributeValue>, Error> {
+        let mut result: HashMap<String, AttributeValue> = HashMap::new();
+        if let ConditionOperand::Value(value) = self {
+            result.insert(context.next(), value_or_error(value)?);
+        }
+        Ok(result)
+    }
+}
+
+pub trait IntoConditionOperand {
+    fn into_condition_operand(self) -> ConditionOperand;
+}
+
+impl IntoConditionOperand for ConditionOperand {
+    fn into_condition_operand(self) -> ConditionOperand {
+        self
+    }
+}
+
+impl<T> IntoConditionOperand for T
+where
+    T: IntoAttributeValue,
+{
+    fn into_condition_operand(self) -> ConditionOperand {
+        ConditionOperand::Value(try_value(self))
+    }
+}
+
+impl From<AttributeValue> for ConditionOperand {
+    fn from(value: AttributeValue) -> Self {
+        ConditionOperand::Value(Ok(value))
+    }
+}
+
+/// Value of a `SET` action. <br>
+/// Can be a literal value, another attribute path, `if_not_exists`, `list_append` or a sum or difference of them. <br>
+/// A value that fails to convert is returned by `get_expression_attribute_values` of the expression.
+#[derive(Debug, Clone)]
+pub enum UpdateOperand {
+    Value(Result<AttributeValue, String>),
+    Path(String),
+    IfNotExists {
+        key: String,
+        value: Box<UpdateOperand>,
+    },
+    ListAppend(Box<UpdateOperand>, Box<UpdateOperand>),
+    Plus(Box<UpdateOperand>, Box<UpdateOperand>),
+    Minus(Box<UpdateOperand>, Box<UpdateOperand>),
+}
+
+impl UpdateOperand {
+    pub fn value(value: impl IntoAttributeValue) -> UpdateOperand {
+        UpdateOperand::Value(try_value(value))
+    }
+
+    pub fn path(key: &str) -> UpdateOperand {
+        UpdateOperand::Path(key.into())
+    }
+
+    pub fn if_not_exists(key: &str, value: impl IntoUpdateOperand) -> UpdateOperand {
+        UpdateOperand::IfNotExists {
+            key: key.into(),
+            value: Box::new(value.into_update_operand()),
+        }
+    }
+
+    pub fn list_append(
+        list: impl IntoUpdateOperand,
+        values: impl IntoUpdateOperand,
+    ) -> UpdateOperand {
+        UpdateOperand::ListAppend(
+            Box::new(list.into_update_operand()),
+            Box::new(values.into_update_operand()),
+        )
+    }
+
+    pub fn plus(self, other: impl IntoUpdateOperand) -> UpdateOperand {
+        UpdateOperand::Plus(Box::new(self), Box::new(other.into_update_operand()))
+    }
+
+    pub fn minus(self, other: impl IntoUpdateOperand) -> UpdateOperand {
+        UpdateOperand::Minus(Box::new(self), Box::new(other.into_update_operand()))
+    }
+
+    pub fn to_string(&self, context: &mut ExpressionContext) -> String {
+        match self {
+            UpdateOperand::Value(_) => context.next(),
+            UpdateOperand::Path(key) => key.as_variable(),
+            UpdateOperand::IfNotExists { key, value } => format!(
+                "if_not_exists({}, {})",
+                key.as_variable(),
+                value.to_string(context)
+            ),
+            UpdateOperand::ListAppend(list, values) => format!(
+                "list_append({}, {})",
+                list.to_string(context),
+                values.to_string(context)
+            ),
+            UpdateOperand::Plus(left, right) => {
+                format!("{} + {}", left.to_string(context), right.to_string(context))
+            }
+            UpdateOperand::Minus(left, right) => {
+                format!("{} - {}", left.to_string(context), right.to_string(context))
+            }
+        }
+    }
+
+    pub fn get_expression_attribute_names(&self) -> HashMap<String, String> {
+        let mut result: HashMap<String, String> = HashMap::new();
+
+        match self {
+            UpdateOperand::Value(_) => {}
+            UpdateOperand::Path(key) => {
+                result.insert(key.as_variable(), key.clone());
+            }
+            UpdateOperand::IfNotExists { key, value } => {
+                result.insert(key.as_variable(), key.clone());
+                result.extend(value.get_expression_attribute_names());
+            }
+            UpdateOperand::ListAppend(left, right)
+            | UpdateOperand::Plus(left, right)
+            | UpdateOperand::Minus(left, right) => {
+                result.extend(left.get_expression_attribute_names());
+                result.extend(right.get_expression_attribute_names());
+            }
+        }
+
+        result
+    }
+
+    /// Has to walk the operand in the same order as `to_string`, so that the placeholders line up.
+    pub fn get_expression_attribute_values(
+        &self,
+        context: &mut ExpressionContext,
+    ) -> Result<HashMap<String, AttributeValue>, Error> {
+        let mut result: HashMap<String, AttributeValue> = HashMap::new();
+
+        match self {
+            UpdateOperand::Value(value) => {
+                result.insert(context.next(), value_or_error(value)?);
+            }
+            UpdateOperand::Path(_) => {}
+            UpdateOperand::IfNotExists { key: _, value } => {
+                result.extend(value.get_expression_attribute_values(context)?);
+            }
+            UpdateOperand::ListAppend(left, right)
+            | UpdateOperand::Plus(left, right)
+            | UpdateOperand::Minus(left, right) => {
+                result.extend(left.get_expression_attribute_values(context)?);
+                result.extend(right.get_expression_attribute_values(context)?);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub trait IntoUpdateOperand {
+    fn into_update_operand(self) -> UpdateOperand;
+}
+
+impl IntoUpdateOperand for UpdateOperand {
+    fn into_update_operand(self) -> UpdateOperand {
+        self
+    }
+}
+
+impl<T> IntoUpdateOperand for T
+where
+    T: IntoAttributeValue,
+{
+    fn into_update_operand(self) -> UpdateOperand {
+        UpdateOperand::Value(try_value(self))
+    }
+}
+
+impl From<AttributeValue> for UpdateOperand {
+    fn from(value: AttributeValue) -> Self {
+        UpdateOperand::Value(Ok(value))
+    }
+}
@@ -3,8 +3,12 @@ use std::{collections::HashSet, marker::PhantomData};
 use aws_sdk_dynamodb::types::AttributeValue;
 
 use crate::{
-    dynamodb_context::expression::update::{SetOperation, UpdateExpression},
-    traits::into_attribute_value::IntoAttributeValue,
+    dynamodb_context::expression::{
+        operand::{IntoUpdateOperand, UpdateOperand, try_value},
+        update::{SetOperation, UpdateExpression},
+    },
+    error::Error,
+    traits::{into_attribute_value::IntoAttributeValue, numeric::Numeric},
 };
 
 pub struct UpdateExpressionBuilder<V> {
@@ -20,53 +24,81 @@ impl<V> UpdateExpressionBuilder<V> {
         }
     }
 
-    pub fn set_new_value(self, value: impl IntoAttributeValue) -> UpdateExpression {
+    /// Accepts a literal value, another attribute's builder or an `UpdateOperand`. <br>
+    /// `builder.total().set_new_value(builder.price().plus(builder.tax()))`
+    pub fn set_new_value(self, value: impl IntoUpdateOperand) -> UpdateExpression {
         UpdateExpression::new_set(SetOperation::Assign {
             key: self.key,
-            value: value.into_attribute_value(),
+            value: value.into_update_operand(),
         })
     }
+
+    /// `key + value`, to be used as the operand of another set.
+    pub fn plus(self, value: impl IntoUpdateOperand) -> UpdateOperand {
+        self.into_update_operand().plus(value)
+    }
+
+    /// `key - value`, to be used as the operand of another set.
+    pub fn minus(self, value: impl IntoUpdateOperand) -> UpdateOperand {
+        self.into_update_operand().minus(value)
+    }
+
+    /// `if_not_exists(key, value)`, to be used as the operand of another set.
+    pub fn if_not_exists(self, value: impl IntoUpdateOperand) -> UpdateOperand {
+        UpdateOperand::if_not_exists(&self.key, value)
+    }
 }
 
+impl<V> Clone for UpdateExpressionBuilder<V> {
+    fn clone(&self) -> Self {
+        Self::new(&self.key)
+    }
+}
+
+impl<V> IntoUpdateOperand for UpdateExpressionBuilder<V> {
+    fn into_update_operand(self) -> UpdateOperand {
+        UpdateOperand::Path(self.key)
+    }
+}
+
+/// Value type of the update builders of binary fields, `Vec<u8>` is stored as a `B` attribute rather than a list,
+/// so these builders have no list functions.
+pub struct Binary<T>(PhantomData<T>);
+
 impl<V> UpdateExpressionBuilder<Vec<V>> {
-    pub fn set_list_append(self, values: impl IntoAttributeValue) -> UpdateExpression {
+    pub fn set_list_append(self, values: impl IntoUpdateOperand) -> UpdateExpression {
         UpdateExpression::new_set(SetOperation::ListAppend {
             key: self.key,
-            value: values.into_attribute_value(),
+            value: values.into_update_operand(),
         })
     }
 
-    pub fn set_list_prepend(self, values: impl IntoAttributeValue) -> UpdateExpression {
+    pub fn set_list_prepend(self, values: impl IntoUpdateOperand) -> UpdateExpression {
         UpdateExpression::new_set(SetOperation::ListPrepend {
             key: self.key,
-            value: values.into_attribute_value(),
+            value: values.into_update_operand(),
         })
     }
 }
 
-impl UpdateExpressionBuilder<HashSet<String>> {
-    pub fn delete_element(self, value: HashSet<String>) -> UpdateExpression {
-        UpdateExpression::new_delete(self.key, value.into_attribute_value())
+impl<V> UpdateExpressionBuilder<HashSet<V>>
+where
+    HashSet<V>: IntoAttributeValue,
+{
+    pub fn add_element(self, value: HashSet<V>) -> UpdateExpression {
+        UpdateExpression::try_new_add(self.key, try_value(value))
     }
-}
-
-impl UpdateExpressionBuilder<HashSet<i32>> {
-    pub fn delete_element(self, value: HashSet<i32>) -> UpdateExpression {
-        UpdateExpression::new_delete(self.key, value.into_attribute_value())
-    }
-}
 
-impl UpdateExpressionBuilder<HashSet<u32>> {
-    pub fn delete_element(self, value: HashSet<u32>) -> UpdateExpression {
-        UpdateExpression::new_delete(self.key, value.into_attribute_value())
+    pub fn delete_element(self, value: HashSet<V>) -> UpdateExpression {
+        UpdateExpression::try_new_delete(self.key, try_value(value))
     }
 }
 
 impl<V> UpdateExpressionBuilder<Option<V>> {
-    pub fn set_if_not_exists(self, value: impl IntoAttributeValue) -> UpdateExpression {
+    pub fn set_if_not_exists(self, value: impl IntoUpdateOperand) -> UpdateExpression {
         UpdateExpression::new_set(SetOperation::IfNotExists {
             key: self.key,
-            value: value.into_attribute_value(),
+            value: value.into_update_operand(),
         })
     }
 
@@ -75,74 +107,160 @@ impl<V> UpdateExpressionBuilder<Option<V>> {
     }
 }
 
-impl UpdateExpressionBuilder<i32> {
-    pub fn set_increment(self, increment_value: i32) -> UpdateExpression {
+impl<V: Numeric> UpdateExpressionBuilder<V> {
+    pub fn set_increment(self, increment_value: V) -> UpdateExpression {
         UpdateExpression::new_set(SetOperation::Increment {
             key: self.key,
-            value: AttributeValue::N(increment_value.to_string()),
+            value: increment_value.into_update_operand(),
         })
     }
 
-    pub fn set_decrement(self, decremet_value: i32) -> UpdateExpression {
+    pub fn set_decrement(self, decremet_value: V) -> UpdateExpression {
         UpdateExpression::new_set(SetOperation::Decrement {
             key: self.key,
-            value: AttributeValue::N(decremet_value.to_string()),
+            value: decremet_value.into_update_operand(),
         })
     }
 
-    pub fn add_increment(self, increment_value: i32) -> UpdateExpression {
-        UpdateExpression::new_add(self.key, AttributeValue::N(increment_value.to_string()))
+    pub fn add_increment(self, increment_value: V) -> UpdateExpression {
+        UpdateExpression::try_new_add(self.key, try_value(increment_value))
+    }
+
+    pub fn add_decrement(self, decrement_value: V) -> UpdateExpression {
+        let value = decrement_value
+            .try_negated_attribute_value()
+            .map_err(|e| format!("{:?}", e));
+        UpdateExpression::try_new_add(self.key, value)
     }
 
-    pub fn add_decrement(self, decrement_value: i32) -> UpdateExpression {
-        UpdateExpression::new_add(self.key, AttributeValue::N(format!("-{}", decrement_value)))
+    #[deprecated(note = "Use `add_decrement`.")]
+    pub fn add_decremenet(self, decrement_value: V) -> UpdateExpression {
+        self.add_decrement(decrement_value)
     }
 }
 
-impl UpdateExpressionBuilder<f32> {
-    pub fn set_increment(self, increment_value: f32) -> UpdateExpression {
-        UpdateExpression::new_set(SetOperation::Increment {
+/// Update expression builder of a `#[dynorow(with = "...")]` field. <br>
+/// Takes values of the field type and converts them with the `to_attribute_value` function of the module.
+pub struct ConvertedUpdateExpressionBuilder<V> {
+    pub key: String,
+    pub to_attribute_value: fn(&V) -> Result<AttributeValue, Error>,
+}
+
+impl<V> ConvertedUpdateExpressionBuilder<V> {
+    pub fn new(key: &str, to_attribute_value: fn(&V) -> Result<AttributeValue, Error>) -> Self {
+        Self {
+            key: key.into(),
+            to_attribute_value,
+        }
+    }
+
+    /// A conversion error is returned by `get_expression_attribute_values` of the expression.
+    pub fn set_new_value(self, value: V) -> UpdateExpression {
+        let value = self.convert(&value);
+        UpdateExpression::new_set(SetOperation::Assign {
             key: self.key,
-            value: AttributeValue::N(increment_value.to_string()),
+            value,
         })
     }
 
-    pub fn set_decrement(self, decremet_value: f32) -> UpdateExpression {
-        UpdateExpression::new_set(SetOperation::Decrement {
+    /// A conversion error is returned by `get_expression_attribute_values` of the expression.
+    pub fn set_if_not_exists(self, value: V) -> UpdateExpression {
+        let value = self.convert(&value);
+        UpdateExpression::new_set(SetOperation::IfNotExists {
             key: self.key,
-            value: AttributeValue::N(decremet_value.to_string()),
+            value,
         })
     }
 
-    pub fn add_increment(self, increment_value: f32) -> UpdateExpression {
-        UpdateExpression::new_add(self.key, AttributeValue::N(increment_value.to_string()))
+    fn convert(&self, value: &V) -> UpdateOperand {
+        UpdateOperand::Value((self.to_attribute_value)(value).map_err(|e| format!("{:?}", e)))
     }
 
-    pub fn add_decremenet(self, decrement_value: f32) -> UpdateExpression {
-        UpdateExpression::new_add(self.key, AttributeValue::N(format!("-{}", decrement_value)))
+    pub fn remove(self) -> UpdateExpression {
+        UpdateExpression::new_remove(self.key)
     }
 }
 
-impl UpdateExpressionBuilder<u32> {
-    pub fn set_increment(self, increment_value: u32) -> UpdateExpression {
-        UpdateExpression::new_set(SetOperation::Increment {
-            key: self.key,
-            value: AttributeValue::N(increment_value.to_string()),
-        })
+impl<V> IntoUpdateOperand for ConvertedUpdateExpressionBuilder<V> {
+    fn into_update_operand(self) -> UpdateOperand {
+        UpdateOperand::Path(self.key)
     }
+}
 
-    pub fn set_decrement(self, decremet_value: u32) -> UpdateExpression {
-        UpdateExpression::new_set(SetOperation::Decrement {
-            key: self.key,
-            value: AttributeValue::N(decremet_value.to_string()),
-        })
+#[cfg(test)]
+pub mod test {
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use dynorow_derive::DynoRow;
+
+    use crate::{self as dynorow, traits::as_attribute_key_values::AsAttributeKeyValues};
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Flags(pub Vec<String>);
+
+    /// Legacy encoding, flags are stored as a single comma separated string.
+    mod comma_separated {
+        use aws_sdk_dynamodb::types::AttributeValue;
+
+        use super::Flags;
+        use crate::error::Error;
+
+        pub fn to_attribute_value(value: &Flags) -> Result<AttributeValue, Error> {
+            Ok(AttributeValue::S(value.0.join(",")))
+        }
+
+        pub fn from_attribute_value(value: AttributeValue) -> Result<Flags, Error> {
+            match value.as_s() {
+                Ok(x) => Ok(Flags(x.split(',').map(String::from).collect())),
+                Err(_) => Err(Error::parse_error(value, "Flags", String::new())),
+            }
+        }
     }
 
-    pub fn add_increment(self, increment_value: u32) -> UpdateExpression {
-        UpdateExpression::new_add(self.key, AttributeValue::N(increment_value.to_string()))
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Feature")]
+    pub struct Feature {
+        #[dynorow(sk)]
+        pub feature_id: String,
+        #[dynorow(with = "comma_separated")]
+        pub flags: Flags,
     }
 
-    pub fn add_decremenet(self, decrement_value: u32) -> UpdateExpression {
-        UpdateExpression::new_add(self.key, AttributeValue::N(format!("-{}", decrement_value)))
+    #[test]
+    pub fn test_with_module() {
+        let feature = Feature {
+            feature_id: "1".into(),
+            flags: Flags(vec!["beta".into(), "internal".into()]),
+        };
+        let attributes = feature.as_attribute_key_values();
+        assert_eq!(
+            attributes.get("flags"),
+            Some(&AttributeValue::S("beta,internal".into()))
+        );
+        assert_eq!(Feature::try_from(attributes).unwrap().flags, feature.flags);
+
+        let expression = Feature::update_expression_builder()
+            .flags()
+            .set_new_value(Flags(vec!["beta".into()]));
+        assert_eq!(expression.to_string(), "\nSET #var_flags = :vu1");
+        assert_eq!(
+            expression
+                .get_expression_attribute_values()
+                .unwrap()
+                .get(":vu1"),
+            Some(&AttributeValue::S("beta".into()))
+        );
+
+        let condition = Feature::conditional_expression_builder()
+            .flags()
+            .in_list([Flags(vec!["beta".into()]), Flags(vec![])]);
+        assert_eq!(condition.to_string(), "#var_flags IN (:vc1, :vc2)");
+        assert_eq!(
+            condition
+                .get_expression_attribute_values()
+                .unwrap()
+                .get(":vc1"),
+            Some(&AttributeValue::S("beta".into()))
+        );
     }
 }
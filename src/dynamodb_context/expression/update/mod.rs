@@ -1,6 +1,12 @@
 use std::collections::HashMap;
 
-use crate::dynamodb_context::expression::{AsVariable, ExpressionContext};
+use crate::{
+    dynamodb_context::expression::{
+        AsVariable, ExpressionContext,
+        operand::{UpdateOperand, value_or_error},
+    },
+    error::Error,
+};
 use aws_sdk_dynamodb::types::AttributeValue;
 
 pub mod expression_builder;
@@ -27,6 +33,14 @@ impl UpdateExpression {
     }
 
     pub fn new_add(key: String, value: AttributeValue) -> UpdateExpression {
+        Self::try_new_add(key, Ok(value))
+    }
+
+    /// A failed conversion of `value` is returned by `get_expression_attribute_values`.
+    pub(crate) fn try_new_add(
+        key: String,
+        value: Result<AttributeValue, String>,
+    ) -> UpdateExpression {
         UpdateExpression {
             sets: vec![],
             adds: vec![AddOperation { key, value }],
@@ -45,6 +59,14 @@ impl UpdateExpression {
     }
 
     pub fn new_delete(key: String, value: AttributeValue) -> UpdateExpression {
+        Self::try_new_delete(key, Ok(value))
+    }
+
+    /// A failed conversion of `value` is returned by `get_expression_attribute_values`.
+    pub(crate) fn try_new_delete(
+        key: String,
+        value: Result<AttributeValue, String>,
+    ) -> UpdateExpression {
         UpdateExpression {
             sets: vec![],
             adds: vec![],
@@ -114,15 +136,8 @@ impl UpdateExpression {
     pub fn get_expression_attribute_names(&self) -> HashMap<String, String> {
         let mut result: HashMap<String, String> = HashMap::new();
 
-        self.sets.iter().for_each(|x| match x {
-            SetOperation::Assign { key, value: _ }
-            | SetOperation::Increment { key, value: _ }
-            | SetOperation::Decrement { key, value: _ }
-            | SetOperation::IfNotExists { key, value: _ }
-            | SetOperation::ListAppend { key, value: _ }
-            | SetOperation::ListPrepend { key, value: _ } => {
-                result.insert(key.as_variable(), key.clone());
-            }
+        self.sets.iter().for_each(|x| {
+            result.extend(x.get_expression_attribute_names());
         });
 
         self.adds.iter().for_each(|x| {
@@ -140,98 +155,104 @@ impl UpdateExpression {
         return result;
     }
 
-    pub fn get_expression_attribute_values(&self) -> HashMap<String, AttributeValue> {
+    /// Returns the conversion error of a value operand instead of panicking.
+    pub fn get_expression_attribute_values(
+        &self,
+    ) -> Result<HashMap<String, AttributeValue>, Error> {
         let mut context = ExpressionContext::new("vu");
         let mut result: HashMap<String, AttributeValue> = HashMap::new();
 
-        self.sets.iter().for_each(|x| match x {
-            SetOperation::Assign { key: _, value }
-            | SetOperation::Increment { key: _, value }
-            | SetOperation::Decrement { key: _, value }
-            | SetOperation::IfNotExists { key: _, value }
-            | SetOperation::ListAppend { key: _, value }
-            | SetOperation::ListPrepend { key: _, value } => {
-                result.insert(context.next(), value.clone());
-            }
-        });
+        for set in &self.sets {
+            result.extend(set.get_expression_attribute_values(&mut context)?);
+        }
 
-        self.adds.iter().for_each(|x| {
-            result.insert(context.next(), x.value.clone());
-        });
+        for add in &self.adds {
+            result.insert(context.next(), value_or_error(&add.value)?);
+        }
 
-        self.deletes.iter().for_each(|x| {
-            result.insert(context.next(), x.value.clone());
-        });
+        for delete in &self.deletes {
+            result.insert(context.next(), value_or_error(&delete.value)?);
+        }
 
-        return result;
+        Ok(result)
     }
 }
 
 #[derive(Debug, Clone)]
 pub enum SetOperation {
-    Assign { key: String, value: AttributeValue },
-    Increment { key: String, value: AttributeValue },
-    Decrement { key: String, value: AttributeValue },
-    IfNotExists { key: String, value: AttributeValue },
-    ListAppend { key: String, value: AttributeValue },
-    ListPrepend { key: String, value: AttributeValue },
+    Assign { key: String, value: UpdateOperand },
+    Increment { key: String, value: UpdateOperand },
+    Decrement { key: String, value: UpdateOperand },
+    IfNotExists { key: String, value: UpdateOperand },
+    ListAppend { key: String, value: UpdateOperand },
+    ListPrepend { key: String, value: UpdateOperand },
 }
 
 impl SetOperation {
+    pub fn get_key(&self) -> &String {
+        match self {
+            SetOperation::Assign { key, value: _ }
+            | SetOperation::Increment { key, value: _ }
+            | SetOperation::Decrement { key, value: _ }
+            | SetOperation::IfNotExists { key, value: _ }
+            | SetOperation::ListAppend { key, value: _ }
+            | SetOperation::ListPrepend { key, value: _ } => key,
+        }
+    }
+
+    pub fn get_value(&self) -> &UpdateOperand {
+        match self {
+            SetOperation::Assign { key: _, value }
+            | SetOperation::Increment { key: _, value }
+            | SetOperation::Decrement { key: _, value }
+            | SetOperation::IfNotExists { key: _, value }
+            | SetOperation::ListAppend { key: _, value }
+            | SetOperation::ListPrepend { key: _, value } => value,
+        }
+    }
+
     pub fn to_string(&self, context: &mut ExpressionContext) -> String {
-        let variable = context.next();
+        let key = self.get_key().as_variable();
+        let value = self.get_value().to_string(context);
         match self {
-            SetOperation::Assign { key, value: _ } => {
-                format!("{} = {}", key.as_variable(), variable)
-            }
-            SetOperation::Increment { key, value: _ } => {
-                format!(
-                    "{} = {} + {}",
-                    key.as_variable(),
-                    key.as_variable(),
-                    variable
-                )
+            SetOperation::Assign { key: _, value: _ } => format!("{} = {}", key, value),
+            SetOperation::Increment { key: _, value: _ } => {
+                format!("{} = {} + {}", key, key, value)
             }
-            SetOperation::Decrement { key, value: _ } => {
-                format!(
-                    "{} = {} - {}",
-                    key.as_variable(),
-                    key.as_variable(),
-                    variable
-                )
+            SetOperation::Decrement { key: _, value: _ } => {
+                format!("{} = {} - {}", key, key, value)
             }
-            SetOperation::IfNotExists { key, value: _ } => {
-                format!(
-                    "{} = if_not_exists({}, {})",
-                    key.as_variable(),
-                    key.as_variable(),
-                    variable
-                )
+            SetOperation::IfNotExists { key: _, value: _ } => {
+                format!("{} = if_not_exists({}, {})", key, key, value)
             }
-            SetOperation::ListAppend { key, value: _ } => {
-                format!(
-                    "{} = list_append({}, {})",
-                    key.as_variable(),
-                    key.as_variable(),
-                    variable
-                )
+            SetOperation::ListAppend { key: _, value: _ } => {
+                format!("{} = list_append({}, {})", key, key, value)
             }
-            SetOperation::ListPrepend { key, value: _ } => {
-                format!(
-                    "{} = list_append({}, {})",
-                    key.as_variable(),
-                    key.as_variable(),
-                    variable
-                )
+            SetOperation::ListPrepend { key: _, value: _ } => {
+                format!("{} = list_append({}, {})", key, value, key)
             }
         }
     }
+
+    pub fn get_expression_attribute_names(&self) -> HashMap<String, String> {
+        let mut result = self.get_value().get_expression_attribute_names();
+        let key = self.get_key();
+        result.insert(key.as_variable(), key.clone());
+        result
+    }
+
+    pub fn get_expression_attribute_values(
+        &self,
+        context: &mut ExpressionContext,
+    ) -> Result<HashMap<String, AttributeValue>, Error> {
+        self.get_value().get_expression_attribute_values(context)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct AddOperation {
     pub key: String,
-    pub value: AttributeValue,
+    pub value: Result<AttributeValue, String>,
 }
 
 impl AddOperation {
@@ -243,7 +264,7 @@ impl AddOperation {
 #[derive(Debug, Clone)]
 pub struct DeleteOperation {
     pub key: String,
-    pub value: AttributeValue,
+    pub value: Result<AttributeValue, String>,
 }
 
 impl DeleteOperation {
@@ -259,7 +280,8 @@ pub mod test_update_expression_to_string {
     use aws_sdk_dynamodb::types::AttributeValue;
 
     use crate::{
-        UpdateExpression, dynamodb_context::expression::update::SetOperation,
+        UpdateExpression, UpdateExpressionBuilder,
+        dynamodb_context::expression::update::SetOperation,
         traits::into_attribute_value::IntoAttributeValue,
     };
 
@@ -267,15 +289,15 @@ pub mod test_update_expression_to_string {
     fn test_sets() {
         let expression = UpdateExpression::new_set(SetOperation::Assign {
             key: "user".into(),
-            value: AttributeValue::S("Username1".into()),
+            value: AttributeValue::S("Username1".into()).into(),
         })
         .and(UpdateExpression::new_set(SetOperation::Increment {
             key: "total_sales".into(),
-            value: AttributeValue::N(1.to_string()),
+            value: AttributeValue::N(1.to_string()).into(),
         }))
         .and(UpdateExpression::new_set(SetOperation::IfNotExists {
             key: "DeactivatedOn".into(),
-            value: AttributeValue::S("172432342".into()),
+            value: AttributeValue::S("172432342".into()).into(),
         }));
 
         assert_eq!(
@@ -321,15 +343,15 @@ pub mod test_update_expression_to_string {
         ids_to_remove.insert("abc123".into());
         let expression = UpdateExpression::new_set(SetOperation::Assign {
             key: "user".into(),
-            value: AttributeValue::S("Username1".into()),
+            value: AttributeValue::S("Username1".into()).into(),
         })
         .and(UpdateExpression::new_set(SetOperation::Increment {
             key: "total_sales".into(),
-            value: AttributeValue::N(1.to_string()),
+            value: AttributeValue::N(1.to_string()).into(),
         }))
         .and(UpdateExpression::new_set(SetOperation::IfNotExists {
             key: "DeactivatedOn".into(),
-            value: AttributeValue::S("172432342".into()),
+            value: AttributeValue::S("172432342".into()).into(),
         }))
         .and(UpdateExpression::new_add(
             "count".into(),
@@ -354,4 +376,33 @@ REMOVE disabled_on, deleted_on
 DELETE #var_valid_ids :vu6"#
         );
     }
+
+    #[test]
+    fn test_operands() {
+        let builder = || UpdateExpressionBuilder::<i32>::new("a");
+        let expression = UpdateExpressionBuilder::<i32>::new("total")
+            .set_new_value(
+                UpdateExpressionBuilder::<i32>::new("price")
+                    .plus(UpdateExpressionBuilder::<i32>::new("tax")),
+            )
+            .and(
+                UpdateExpressionBuilder::<String>::new("backup")
+                    .set_new_value(UpdateExpressionBuilder::<String>::new("current")),
+            )
+            .and(builder().set_new_value(builder().if_not_exists(0).plus(1)));
+
+        assert_eq!(
+            expression.to_string(),
+            "\nSET #var_total = #var_price + #var_tax, #var_backup = #var_current, #var_a = if_not_exists(#var_a, :vu1) + :vu2"
+        );
+
+        let attribute_values = expression.get_expression_attribute_values().unwrap();
+        assert_eq!(attribute_values.len(), 2);
+        assert_eq!(attribute_values.get(":vu1").unwrap().as_n().unwrap(), "0");
+        assert_eq!(attribute_values.get(":vu2").unwrap().as_n().unwrap(), "1");
+
+        let attribute_names = expression.get_expression_attribute_names();
+        assert_eq!(attribute_names.len(), 6);
+        assert_eq!(attribute_names.get("#var_current").unwrap(), "current");
+    }
 }
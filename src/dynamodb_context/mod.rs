@@ -7,12 +7,15 @@ use dynamodb_table::DynamodbTable;
 
 use crate::{
     GetListResult, UpdateExpression,
-    dynamodb_context::expression::conditional::ConditionalExpression,
+    dynamodb_context::expression::{
+        conditional::ConditionalExpression, key_condition::KeyCondition,
+    },
     error::Error,
-    key::KeyValue,
+    key::{KeyValue, TypedKey},
     traits::{
-        as_key_value::AsPkAvailableCompositeKeyValue, fetchable::Fetchable, has_key::HasKey,
-        has_pk_value::HasStaticPkValue, has_table_name::HasTableName, insertable::Insertable,
+        as_key_value::AsPkAvailableCompositeKeyValue, collection::Collection, fetchable::Fetchable,
+        has_key::HasKey, has_pk_value::HasStaticPkValue, has_table_name::HasTableName,
+        insertable::Insertable, into_attribute_value::IntoAttributeValue,
     },
 };
 
@@ -32,7 +35,7 @@ impl DynamodbContext {
 
     pub async fn exists<T: Fetchable + HasTableName>(
         &self,
-        key: crate::key::KeyValue,
+        key: impl Into<TypedKey<T>>,
     ) -> Result<bool, crate::error::Error> {
         self.with_table(&T::get_table_name()).exists::<T>(key).await
     }
@@ -41,7 +44,7 @@ impl DynamodbContext {
         T: Fetchable + HasTableName + AsPkAvailableCompositeKeyValue,
     >(
         &self,
-        sort_key_value: String,
+        sort_key_value: T::SortKey,
     ) -> Result<bool, crate::error::Error> {
         self.with_table(&T::get_table_name())
             .exists_with_sort_key::<T>(sort_key_value)
@@ -50,14 +53,14 @@ impl DynamodbContext {
 
     pub async fn get<T: Fetchable + HasTableName>(
         &self,
-        key: crate::key::KeyValue,
+        key: impl Into<TypedKey<T>>,
     ) -> Result<T, crate::error::Error> {
         self.with_table(&T::get_table_name()).get(key).await
     }
 
     pub async fn get_with_sort_key<T: Fetchable + AsPkAvailableCompositeKeyValue + HasTableName>(
         &self,
-        sort_key_value: String,
+        sort_key_value: T::SortKey,
     ) -> Result<T, crate::error::Error> {
         self.with_table(&T::get_table_name())
             .get_with_sort_key(sort_key_value)
@@ -66,7 +69,7 @@ impl DynamodbContext {
 
     pub async fn get_maybe<T: Fetchable + HasTableName>(
         &self,
-        key: crate::key::KeyValue,
+        key: impl Into<TypedKey<T>>,
     ) -> Result<Option<T>, crate::error::Error> {
         self.with_table(&T::get_table_name()).get_maybe(key).await
     }
@@ -75,7 +78,7 @@ impl DynamodbContext {
         T: Fetchable + AsPkAvailableCompositeKeyValue + HasTableName,
     >(
         &self,
-        sort_key_value: String,
+        sort_key_value: T::SortKey,
     ) -> Result<Option<T>, crate::error::Error> {
         self.with_table(&T::get_table_name())
             .get_maybe_with_sort_key(sort_key_value)
@@ -98,34 +101,32 @@ impl DynamodbContext {
 
     pub async fn update_with_expression<T: crate::traits::updatable::Updatable + HasTableName>(
         &self,
-        key_value: KeyValue,
+        key: impl Into<TypedKey<T>>,
         expression: UpdateExpression,
     ) -> Result<(), Error> {
         self.with_table(&T::get_table_name())
-            .update_with_expression::<T>(key_value, expression)
+            .update_with_expression::<T>(key, expression)
             .await
     }
 
     pub async fn update_with_condition<T: crate::traits::updatable::Updatable + HasTableName>(
         &self,
-        key_value: KeyValue,
+        key: impl Into<TypedKey<T>>,
         update: UpdateExpression,
         condition: ConditionalExpression,
     ) -> Result<(), Error> {
         self.with_table(&T::get_table_name())
-            .update_with_condition::<T>(key_value, update, condition)
+            .update_with_condition::<T>(key, update, condition)
             .await
     }
 
-    pub async fn delete<T: HasTableName>(&self, key_value: KeyValue) -> Result<(), Error> {
-        self.with_table(&T::get_table_name())
-            .delete(key_value)
-            .await
+    pub async fn delete<T: HasTableName>(&self, key: impl Into<TypedKey<T>>) -> Result<(), Error> {
+        self.with_table(&T::get_table_name()).delete(key).await
     }
 
     pub async fn delete_with_sort_key<T: AsPkAvailableCompositeKeyValue + HasTableName>(
         &self,
-        sort_key_value: String,
+        sort_key_value: T::SortKey,
     ) -> Result<(), Error> {
         self.with_table(&T::get_table_name())
             .delete_with_sort_key::<T>(sort_key_value)
@@ -134,11 +135,11 @@ impl DynamodbContext {
 
     pub async fn delete_with_condition<T: HasTableName>(
         &self,
-        key_value: KeyValue,
+        key: impl Into<TypedKey<T>>,
         conditional_expression: ConditionalExpression,
     ) -> Result<(), Error> {
         self.with_table(&T::get_table_name())
-            .delete_with_condition(key_value, conditional_expression)
+            .delete_with_condition(key, conditional_expression)
             .await
     }
 
@@ -165,6 +166,27 @@ impl DynamodbContext {
             .await
     }
 
+    pub async fn get_list_with_key_condition<T: Fetchable + HasKey + HasTableName>(
+        &self,
+        key_condition: impl Into<KeyCondition<T>>,
+        count: u16,
+        last_key_value: Option<KeyValue>,
+        accending: bool,
+    ) -> Result<GetListResult<T>, Error> {
+        self.with_table(&T::get_table_name())
+            .get_list_with_key_condition(key_condition, count, last_key_value, accending)
+            .await
+    }
+
+    pub async fn get_collection<T: Collection + HasKey + HasTableName>(
+        &self,
+        partition_key_value: impl IntoAttributeValue,
+    ) -> Result<T, Error> {
+        self.with_table(&T::get_table_name())
+            .get_collection(partition_key_value)
+            .await
+    }
+
     pub async fn get_list_with_condition<T: Fetchable + HasKey + HasTableName>(
         &self,
         conditional_expression: ConditionalExpression,
@@ -1,4 +1,7 @@
-use crate::{key::KeyValue, traits::insertable::Insertable};
+use crate::{
+    key::{KeyValue, TypedKey},
+    traits::insertable::Insertable,
+};
 
 pub enum Operation {
     Insert(Box<dyn Insertable>),
@@ -9,4 +12,8 @@ impl Operation {
     pub fn new_insert<T: Insertable>(item: T) -> Operation {
         Operation::Insert(Box::new(item))
     }
+
+    pub fn new_delete<T>(key: impl Into<TypedKey<T>>) -> Operation {
+        Operation::Delete(key.into().into())
+    }
 }
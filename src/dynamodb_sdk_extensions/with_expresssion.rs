@@ -5,23 +5,27 @@ use aws_sdk_dynamodb::{
 };
 
 use crate::{
+    error::Error,
     key::KeyValue,
     traits::{as_attribute_key_values::AsAttributeKeyValues, as_key_value::AsKeyValue},
 };
 
 pub trait WithExpression {
-    fn with_expression<T: AsAttributeKeyValues + AsKeyValue>(self, row: &T) -> Self;
+    /// Returns the serialization error of the row instead of panicking.
+    fn with_expression<T: AsAttributeKeyValues + AsKeyValue>(self, row: &T) -> Result<Self, Error>
+    where
+        Self: Sized;
 }
 
 impl WithExpression for UpdateItemFluentBuilder {
-    fn with_expression<T: AsAttributeKeyValues + AsKeyValue>(self, row: &T) -> Self {
-        let variable_value_map = generate_expression_variable_value_map(row);
+    fn with_expression<T: AsAttributeKeyValues + AsKeyValue>(self, row: &T) -> Result<Self, Error> {
+        let variable_value_map = generate_expression_variable_value_map(row)?;
         let update_expression = generate_update_expression(&variable_value_map);
 
         let mut builder = self.update_expression(update_expression);
         builder = generate_expression_attribute_names(builder, &variable_value_map);
         builder = generate_expression_attribute_values(builder, &variable_value_map);
-        builder
+        Ok(builder)
     }
 }
 
@@ -64,18 +68,18 @@ fn generate_update_expression(
 
 fn generate_expression_variable_value_map<T: AsAttributeKeyValues + AsKeyValue>(
     row: &T,
-) -> HashMap<String, (String, AttributeValue)> {
+) -> Result<HashMap<String, (String, AttributeValue)>, Error> {
     let mut result: HashMap<String, (String, AttributeValue)> = HashMap::new();
 
-    let key = row.as_key_value();
+    let key = row.as_untyped_key_value();
 
-    for attribute_key_value in row.as_attribute_key_values() {
+    for attribute_key_value in row.try_as_attribute_key_values()? {
         if !is_key(&key, &attribute_key_value.0) {
             result.insert(as_variable(&attribute_key_value.0), attribute_key_value);
         }
     }
 
-    return result;
+    return Ok(result);
 }
 
 fn as_variable(key: &String) -> String {
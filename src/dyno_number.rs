@@ -0,0 +1,260 @@
+use std::{fmt::Display, str::FromStr};
+
+use aws_sdk_dynamodb::types::AttributeValue;
+
+use crate::{
+    error::Error,
+    traits::{
+        from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+        numeric::Numeric,
+    },
+};
+
+/// Dynamodb allows up to 38 significant digits.
+const MAX_PRECISION: usize = 38;
+/// Dynamodb numbers range from `1E-130` to `9.9999999999999999999999999999999999999E+125`, both positive and negative.
+const MIN_EXPONENT: i64 = -130;
+const MAX_EXPONENT: i64 = 125;
+
+/// A dynamodb number kept in the exact string form of `AttributeValue::N`. <br>
+/// Use this instead of floats for amounts where precision matters. <br>
+/// Equality compares the string form, so `1.0` and `1` are not equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynoNumber(String);
+
+impl DynoNumber {
+    pub fn new(number: &str) -> Result<Self, Error> {
+        number.parse()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Parses into any type that can be parsed from a string, such as `i64` or `rust_decimal::Decimal`.
+    pub fn parse<T: FromStr>(&self) -> Result<T, Error>
+    where
+        T::Err: std::fmt::Debug,
+    {
+        self.0.parse::<T>().map_err(|e| {
+            Error::parse_error(
+                AttributeValue::N(self.0.clone()),
+                std::any::type_name::<T>(),
+                format!("{:?}", e),
+            )
+        })
+    }
+}
+
+fn validate(number: &str) -> Result<(), String> {
+    let unsigned = number
+        .strip_prefix('-')
+        .or_else(|| number.strip_prefix('+'))
+        .unwrap_or(number);
+
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (unsigned, None),
+    };
+
+    let exponent = match exponent {
+        Some(exponent) => {
+            let digits = exponent
+                .strip_prefix('-')
+                .or_else(|| exponent.strip_prefix('+'))
+                .unwrap_or(exponent);
+            if digits.is_empty() || !digits.chars().all(|x| x.is_ascii_digit()) {
+                return Err(String::from("Invalid exponent."));
+            }
+            // Exponents too large for an i64 are out of range anyway.
+            exponent.parse::<i64>().unwrap_or(i64::MAX)
+        }
+        None => 0,
+    };
+
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(String::from("No digits found."));
+    }
+
+    if !integer
+        .chars()
+        .chain(fraction.chars())
+        .all(|x| x.is_ascii_digit())
+    {
+        return Err(String::from("Invalid digit."));
+    }
+
+    let digits = format!("{}{}", integer, fraction);
+    let significant_digits = digits.trim_start_matches('0').trim_end_matches('0').len();
+    if significant_digits > MAX_PRECISION {
+        return Err(format!("More than {} significant digits.", MAX_PRECISION));
+    }
+
+    // Zero has no magnitude to check.
+    let Some(first_digit) = digits.find(|x| x != '0') else {
+        return Ok(());
+    };
+    // Exponent of the number written as `d.ddd` x 10^n.
+    let magnitude = (integer.len() as i64 - 1 - first_digit as i64).saturating_add(exponent);
+    if !(MIN_EXPONENT..=MAX_EXPONENT).contains(&magnitude) {
+        return Err(String::from(
+            "Out of the range of 1E-130 to 9.9999999999999999999999999999999999999E+125.",
+        ));
+    }
+
+    Ok(())
+}
+
+impl FromStr for DynoNumber {
+    type Err = Error;
+
+    /// A leading `+` is dropped, so that the number can be negated by prefixing `-`.
+    fn from_str(number: &str) -> Result<Self, Self::Err> {
+        let number = number.trim();
+        validate(number)
+            .map_err(|e| Error::parse_error(AttributeValue::N(number.into()), "DynoNumber", e))?;
+        let number = number.strip_prefix('+').unwrap_or(number);
+        Ok(Self(number.into()))
+    }
+}
+
+impl Display for DynoNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! impl_from_integer_for_dyno_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for DynoNumber {
+                fn from(value: $number) -> Self {
+                    Self(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer_for_dyno_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// 128 bit integers can have more than 38 digits, so they go through the validation of `DynoNumber::new`.
+macro_rules! impl_try_from_wide_integer_for_dyno_number {
+    ($($number:ty),*) => {
+        $(
+            impl TryFrom<$number> for DynoNumber {
+                type Error = Error;
+
+                fn try_from(value: $number) -> Result<Self, Self::Error> {
+                    Self::new(&value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_wide_integer_for_dyno_number!(i128, u128);
+
+impl IntoAttributeValue for DynoNumber {
+    fn into_attribute_value(&self) -> AttributeValue {
+        AttributeValue::N(self.0.clone())
+    }
+}
+
+impl FromAttributeValue<DynoNumber> for DynoNumber {
+    fn from_attribute_value(value: AttributeValue) -> Result<DynoNumber, Error> {
+        match value {
+            AttributeValue::N(number) => Ok(Self(number)),
+            _ => Err(Error::parse_error(value, "DynoNumber", String::new())),
+        }
+    }
+}
+
+impl Numeric for DynoNumber {}
+
+#[cfg(test)]
+pub mod test {
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use dynorow_derive::DynoRow;
+
+    use crate::{
+        self as dynorow, DynoNumber,
+        traits::{
+            from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+            numeric::Numeric,
+        },
+    };
+
+    #[test]
+    pub fn test_precision_is_kept() {
+        let amount = "12345678901234567890.123456789012345678";
+        let number = DynoNumber::from_attribute_value(AttributeValue::N(amount.into())).unwrap();
+        assert_eq!(number.as_str(), amount);
+        assert_eq!(
+            number.into_attribute_value(),
+            AttributeValue::N(amount.into())
+        );
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Wallet")]
+    pub struct Wallet {
+        #[dynorow(sk)]
+        pub wallet_id: String,
+        pub balance: DynoNumber,
+    }
+
+    #[test]
+    pub fn test_update_expression() {
+        let expression = Wallet::update_expression_builder()
+            .balance()
+            .add_decrement(DynoNumber::new("0.000000000000000000000000000000000001").unwrap());
+
+        assert_eq!(
+            expression
+                .get_expression_attribute_values()
+                .unwrap()
+                .get(":vu1"),
+            Some(&AttributeValue::N(
+                "-0.000000000000000000000000000000000001".into()
+            ))
+        );
+    }
+
+    #[test]
+    pub fn test_validation() {
+        assert!(DynoNumber::new("-0.001").is_ok());
+        assert!(DynoNumber::new("1.5E+10").is_ok());
+        assert!(DynoNumber::new("100000000000000000000000000000000000000000").is_ok());
+        assert!(DynoNumber::new("1234567890123456789012345678901234567891").is_err());
+        assert!(DynoNumber::new("12a").is_err());
+        assert!(DynoNumber::new("NaN").is_err());
+        assert!(DynoNumber::new(".").is_err());
+        assert_eq!(DynoNumber::new("42").unwrap().parse::<u64>().unwrap(), 42);
+
+        assert!(DynoNumber::new("1E-130").is_ok());
+        assert!(DynoNumber::new("0.01E-128").is_ok());
+        assert!(DynoNumber::new("-9.99E+125").is_ok());
+        assert!(DynoNumber::new("0E+500").is_ok());
+        assert!(DynoNumber::new("1E-131").is_err());
+        assert!(DynoNumber::new("10E+125").is_err());
+        assert!(DynoNumber::new("1E+99999999999999999999").is_err());
+
+        assert_eq!(DynoNumber::from(u64::MAX).as_str(), u64::MAX.to_string());
+        assert!(DynoNumber::try_from(10_i128.pow(37)).is_ok());
+        assert!(DynoNumber::try_from(i128::MIN).is_err());
+        assert!(DynoNumber::try_from(u128::MAX).is_err());
+    }
+
+    #[test]
+    pub fn test_leading_plus_is_dropped() {
+        let number = DynoNumber::new("+5").unwrap();
+        assert_eq!(number.as_str(), "5");
+        assert_eq!(
+            number.negated_attribute_value(),
+            AttributeValue::N("-5".into())
+        );
+    }
+}
@@ -16,6 +16,17 @@ pub enum Error {
     },
     #[error("Error from aws dynamodb sdk")]
     SdkError { info: String, error: String },
+    #[error("'{value}' does not match the key template '{template}'.")]
+    TemplateMismatch { value: String, template: String },
+    #[error("Item does not match any variant of '{0}'.")]
+    EntityMismatch(String),
+    #[error("More than one item matches `{0}`.")]
+    DuplicateItem(String),
+    #[error("Expected entity type '{expected}', found '{found:?}'.")]
+    EntityTypeMismatch {
+        expected: String,
+        found: Option<String>,
+    },
     #[error("Batch operation was abandoned after retrying.")]
     BatchOperationAbandon {
         unprocessed_items: HashMap<String, Vec<WriteRequest>>,
@@ -35,6 +46,31 @@ impl Error {
         }
     }
 
+    pub fn template_mismatch(value: &str, template: &str) -> Self {
+        Self::TemplateMismatch {
+            value: value.into(),
+            template: template.into(),
+        }
+    }
+
+    pub fn entity_mismatch(entity_name: &str) -> Self {
+        Self::EntityMismatch(entity_name.into())
+    }
+
+    pub fn duplicate_item(field_name: &str) -> Self {
+        Self::DuplicateItem(field_name.into())
+    }
+
+    pub fn entity_type_mismatch(expected: &str, found: Option<&AttributeValue>) -> Self {
+        Self::EntityTypeMismatch {
+            expected: expected.into(),
+            found: found.map(|x| match x.as_s() {
+                Ok(x) => x.clone(),
+                Err(_) => format!("{:?}", x),
+            }),
+        }
+    }
+
     pub fn sdk_error(info: &str, error: impl 'static + Debug) -> Self {
         Self::SdkError {
             info: info.into(),
@@ -0,0 +1,206 @@
+use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+use chrono::{DateTime, FixedOffset, NaiveDate, SecondsFormat, Utc};
+
+use crate::{
+    Timestamp, TimestampFormat,
+    error::Error,
+    traits::{
+        from_attribute_value::FromAttributeValue,
+        into_attribute_value::IntoAttributeValue,
+        scalar_attribute::{ScalarAttribute, SortKeyPrefix},
+    },
+};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+fn parse_epoch(value: &str, format: TimestampFormat) -> Result<DateTime<Utc>, String> {
+    let number = value.parse::<i64>().map_err(|e| format!("{:?}", e))?;
+    let date_time = match format {
+        TimestampFormat::EpochMillis => DateTime::from_timestamp_millis(number),
+        _ => DateTime::from_timestamp(number, 0),
+    };
+    date_time.ok_or_else(|| String::from("Timestamp out of range."))
+}
+
+impl Timestamp for DateTime<Utc> {
+    fn to_timestamp_string(&self, format: TimestampFormat) -> String {
+        match format {
+            TimestampFormat::Rfc3339 => self.to_rfc3339_opts(SecondsFormat::Nanos, true),
+            TimestampFormat::EpochSecs => self.timestamp().to_string(),
+            TimestampFormat::EpochMillis => self.timestamp_millis().to_string(),
+        }
+    }
+
+    fn from_timestamp_string(value: &str, format: TimestampFormat) -> Result<Self, String> {
+        match format {
+            TimestampFormat::Rfc3339 => DateTime::parse_from_rfc3339(value)
+                .map(|x| x.to_utc())
+                .map_err(|e| format!("{:?}", e)),
+            _ => parse_epoch(value, format),
+        }
+    }
+}
+
+/// Stored in UTC like `DateTime<Utc>`, so that the stored strings sort in time order. <br>
+/// The offset is not kept, values are read back with a `+00:00` offset.
+impl Timestamp for DateTime<FixedOffset> {
+    fn to_timestamp_string(&self, format: TimestampFormat) -> String {
+        self.to_utc().to_timestamp_string(format)
+    }
+
+    fn from_timestamp_string(value: &str, format: TimestampFormat) -> Result<Self, String> {
+        match format {
+            TimestampFormat::Rfc3339 => {
+                DateTime::parse_from_rfc3339(value).map_err(|e| format!("{:?}", e))
+            }
+            _ => parse_epoch(value, format).map(|x| x.fixed_offset()),
+        }
+    }
+}
+
+/// Stored as `YYYY-MM-DD`, or as midnight UTC for the epoch formats.
+impl Timestamp for NaiveDate {
+    fn to_timestamp_string(&self, format: TimestampFormat) -> String {
+        match format {
+            TimestampFormat::Rfc3339 => self.format(DATE_FORMAT).to_string(),
+            _ => self
+                .and_time(Default::default())
+                .and_utc()
+                .to_timestamp_string(format),
+        }
+    }
+
+    fn from_timestamp_string(value: &str, format: TimestampFormat) -> Result<Self, String> {
+        match format {
+            TimestampFormat::Rfc3339 => {
+                NaiveDate::parse_from_str(value, DATE_FORMAT).map_err(|e| format!("{:?}", e))
+            }
+            _ => parse_epoch(value, format).map(|x| x.date_naive()),
+        }
+    }
+}
+
+macro_rules! impl_attribute_value_for_timestamp {
+    ($($timestamp:ty),*) => {
+        $(
+            impl IntoAttributeValue for $timestamp {
+                fn into_attribute_value(&self) -> AttributeValue {
+                    self.to_timestamp_attribute_value(TimestampFormat::Rfc3339)
+                }
+            }
+
+            impl FromAttributeValue<$timestamp> for $timestamp {
+                fn from_attribute_value(value: AttributeValue) -> Result<$timestamp, Error> {
+                    <$timestamp>::from_timestamp_attribute_value(value, TimestampFormat::Rfc3339)
+                }
+            }
+
+            impl ScalarAttribute for $timestamp {
+                const SCALAR_ATTRIBUTE_TYPE: ScalarAttributeType = ScalarAttributeType::S;
+            }
+
+            impl SortKeyPrefix for $timestamp {
+                type Prefix = String;
+            }
+        )*
+    };
+}
+
+impl_attribute_value_for_timestamp!(DateTime<Utc>, DateTime<FixedOffset>, NaiveDate);
+
+#[cfg(test)]
+pub mod test {
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+    use dynorow_derive::DynoRow;
+
+    use crate::{
+        self as dynorow, BuildConditionalExpression, EpochMillis,
+        traits::{
+            as_attribute_key_values::AsAttributeKeyValues,
+            from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+        },
+    };
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Session:{started_at}")]
+    pub struct Session {
+        #[dynorow(sk)]
+        pub session_id: String,
+        #[dynorow(timestamp = "epoch_secs")]
+        pub started_at: DateTime<Utc>,
+        #[dynorow(timestamp = "epoch_millis")]
+        pub expires_at: Option<DateTime<Utc>>,
+        pub created_at: DateTime<Utc>,
+        pub day: NaiveDate,
+    }
+
+    #[test]
+    pub fn test_chrono_round_trip() {
+        let now = DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap();
+        assert_eq!(
+            now.into_attribute_value(),
+            AttributeValue::S("2023-11-14T22:13:20.123456789Z".into())
+        );
+        assert_eq!(
+            DateTime::<Utc>::from_attribute_value(now.into_attribute_value()).unwrap(),
+            now
+        );
+
+        let session = Session {
+            session_id: "1".into(),
+            started_at: now,
+            expires_at: Some(now),
+            created_at: now,
+            day: NaiveDate::from_ymd_opt(2023, 11, 14).unwrap(),
+        };
+        let items = session.as_attribute_key_values();
+        assert_eq!(items["pk"], AttributeValue::S("Session:1700000000".into()));
+        assert_eq!(items["started_at"], AttributeValue::N("1700000000".into()));
+        assert_eq!(
+            items["expires_at"],
+            AttributeValue::N("1700000000123".into())
+        );
+        assert_eq!(items["day"], AttributeValue::S("2023-11-14".into()));
+
+        let parsed = Session::try_from(items).unwrap();
+        assert_eq!(
+            parsed.started_at,
+            DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+        );
+        assert_eq!(parsed.created_at, now);
+        assert_eq!(
+            Session::generate_pk_value(now).get_partition_key_value(),
+            AttributeValue::S("Session:1700000000".into())
+        );
+    }
+
+    #[test]
+    pub fn test_fixed_offset_is_stored_in_utc() {
+        let local = DateTime::parse_from_rfc3339("2023-11-15T01:13:20+03:00").unwrap();
+        assert_eq!(
+            local.into_attribute_value(),
+            AttributeValue::S("2023-11-14T22:13:20.000000000Z".into())
+        );
+        assert_eq!(
+            DateTime::<FixedOffset>::from_attribute_value(local.into_attribute_value()).unwrap(),
+            local
+        );
+    }
+
+    #[test]
+    pub fn test_range_condition_uses_field_format() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let expression = Session::conditional_expression_builder()
+            .expires_at()
+            .less_than(Some(EpochMillis(now)));
+        assert_eq!(
+            expression
+                .get_expression_attribute_values()
+                .unwrap()
+                .get(":vc1"),
+            Some(&AttributeValue::N("1700000000000".into()))
+        );
+    }
+}
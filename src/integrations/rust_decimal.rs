@@ -0,0 +1,118 @@
+use std::{collections::HashSet, str::FromStr};
+
+use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+use rust_decimal::Decimal;
+
+use crate::{
+    DynoNumber,
+    error::Error,
+    traits::{
+        from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+        numeric::Numeric, scalar_attribute::ScalarAttribute,
+    },
+};
+
+/// Dynamodb may return numbers in scientific notation, which `Decimal::from_str` does not accept.
+fn parse_decimal(number: &str) -> Result<Decimal, rust_decimal::Error> {
+    Decimal::from_str(number).or_else(|_| Decimal::from_scientific(number))
+}
+
+impl IntoAttributeValue for Decimal {
+    fn into_attribute_value(&self) -> AttributeValue {
+        AttributeValue::N(self.normalize().to_string())
+    }
+}
+
+impl FromAttributeValue<Decimal> for Decimal {
+    fn from_attribute_value(value: AttributeValue) -> Result<Decimal, Error> {
+        let Ok(number) = value.as_n() else {
+            return Err(Error::parse_error(value, "Decimal", String::new()));
+        };
+        parse_decimal(number)
+            .map_err(|e| Error::parse_error(value.clone(), "Decimal", format!("{:?}", e)))
+    }
+}
+
+impl IntoAttributeValue for HashSet<Decimal> {
+    fn into_attribute_value(&self) -> AttributeValue {
+        if self.is_empty() {
+            AttributeValue::Null(true)
+        } else {
+            AttributeValue::Ns(self.iter().map(|x| x.normalize().to_string()).collect())
+        }
+    }
+}
+
+impl FromAttributeValue<HashSet<Decimal>> for HashSet<Decimal> {
+    fn from_attribute_value(value: AttributeValue) -> Result<HashSet<Decimal>, Error> {
+        if value.is_null() {
+            return Ok(HashSet::new());
+        }
+
+        let Ok(numeric_set) = value.as_ns() else {
+            return Err(Error::parse_error(value, "HashSet<Decimal>", String::new()));
+        };
+
+        let mut result: HashSet<Decimal> = HashSet::new();
+        for item in numeric_set {
+            let number = parse_decimal(item).map_err(|e| {
+                Error::parse_error(value.clone(), "HashSet<Decimal>", format!("{:?}", e))
+            })?;
+            result.insert(number);
+        }
+        Ok(result)
+    }
+}
+
+impl Numeric for Decimal {}
+
+impl ScalarAttribute for Decimal {
+    const SCALAR_ATTRIBUTE_TYPE: ScalarAttributeType = ScalarAttributeType::N;
+}
+
+impl From<Decimal> for DynoNumber {
+    fn from(value: Decimal) -> Self {
+        DynoNumber::new(&value.normalize().to_string())
+            .expect("Decimal should always be a valid dynamodb number.")
+    }
+}
+
+impl TryFrom<DynoNumber> for Decimal {
+    type Error = Error;
+
+    fn try_from(value: DynoNumber) -> Result<Self, Self::Error> {
+        parse_decimal(value.as_str()).map_err(|e| {
+            Error::parse_error(value.into_attribute_value(), "Decimal", format!("{:?}", e))
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::str::FromStr;
+
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use rust_decimal::Decimal;
+
+    use crate::{
+        DynoNumber,
+        traits::{
+            from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+        },
+    };
+
+    #[test]
+    pub fn test_decimal_round_trip() {
+        let amount = Decimal::from_str("1999.990").unwrap();
+        assert_eq!(
+            amount.into_attribute_value(),
+            AttributeValue::N("1999.99".into())
+        );
+
+        let parsed = Decimal::from_attribute_value(AttributeValue::N("1.5E+3".into())).unwrap();
+        assert_eq!(parsed, Decimal::from(1500));
+
+        let number = DynoNumber::from(amount);
+        assert_eq!(Decimal::try_from(number).unwrap(), amount);
+    }
+}
@@ -0,0 +1,100 @@
+use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+use time::{
+    OffsetDateTime, UtcOffset, format_description::well_known::Rfc3339, macros::format_description,
+};
+
+use crate::{
+    Timestamp, TimestampFormat,
+    error::Error,
+    traits::{
+        from_attribute_value::FromAttributeValue,
+        into_attribute_value::IntoAttributeValue,
+        scalar_attribute::{ScalarAttribute, SortKeyPrefix},
+    },
+};
+
+impl Timestamp for OffsetDateTime {
+    fn to_timestamp_string(&self, format: TimestampFormat) -> String {
+        match format {
+            TimestampFormat::Rfc3339 => self
+                .to_offset(UtcOffset::UTC)
+                .format(format_description!(
+                    "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:9]Z"
+                ))
+                .expect("Should be able to format a UTC timestamp."),
+            TimestampFormat::EpochSecs => self.unix_timestamp().to_string(),
+            TimestampFormat::EpochMillis => (self.unix_timestamp_nanos() / 1_000_000).to_string(),
+        }
+    }
+
+    fn from_timestamp_string(value: &str, format: TimestampFormat) -> Result<Self, String> {
+        match format {
+            TimestampFormat::Rfc3339 => {
+                OffsetDateTime::parse(value, &Rfc3339).map_err(|e| format!("{:?}", e))
+            }
+            TimestampFormat::EpochSecs => value
+                .parse::<i64>()
+                .map_err(|e| format!("{:?}", e))
+                .and_then(|x| {
+                    OffsetDateTime::from_unix_timestamp(x).map_err(|e| format!("{:?}", e))
+                }),
+            TimestampFormat::EpochMillis => value
+                .parse::<i128>()
+                .map_err(|e| format!("{:?}", e))
+                .and_then(|x| {
+                    OffsetDateTime::from_unix_timestamp_nanos(x * 1_000_000)
+                        .map_err(|e| format!("{:?}", e))
+                }),
+        }
+    }
+}
+
+impl IntoAttributeValue for OffsetDateTime {
+    fn into_attribute_value(&self) -> AttributeValue {
+        self.to_timestamp_attribute_value(TimestampFormat::Rfc3339)
+    }
+}
+
+impl FromAttributeValue<OffsetDateTime> for OffsetDateTime {
+    fn from_attribute_value(value: AttributeValue) -> Result<OffsetDateTime, Error> {
+        OffsetDateTime::from_timestamp_attribute_value(value, TimestampFormat::Rfc3339)
+    }
+}
+
+impl ScalarAttribute for OffsetDateTime {
+    const SCALAR_ATTRIBUTE_TYPE: ScalarAttributeType = ScalarAttributeType::S;
+}
+
+impl SortKeyPrefix for OffsetDateTime {
+    type Prefix = String;
+}
+
+#[cfg(test)]
+pub mod test {
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use time::OffsetDateTime;
+
+    use crate::{
+        Timestamp, TimestampFormat,
+        traits::{
+            from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+        },
+    };
+
+    #[test]
+    pub fn test_time_round_trip() {
+        let now = OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_123_456_789).unwrap();
+        assert_eq!(
+            now.into_attribute_value(),
+            AttributeValue::S("2023-11-14T22:13:20.123456789Z".into())
+        );
+        assert_eq!(
+            OffsetDateTime::from_attribute_value(now.into_attribute_value()).unwrap(),
+            now
+        );
+        assert_eq!(
+            now.to_timestamp_attribute_value(TimestampFormat::EpochMillis),
+            AttributeValue::N("1700000000123".into())
+        );
+    }
+}
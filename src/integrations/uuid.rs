@@ -0,0 +1,59 @@
+use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+use uuid::Uuid;
+
+use crate::{
+    error::Error,
+    traits::{
+        from_attribute_value::FromAttributeValue,
+        into_attribute_value::IntoAttributeValue,
+        scalar_attribute::{ScalarAttribute, SortKeyPrefix},
+    },
+};
+
+/// Stored in the lowercase hyphenated form, the same as its `Display`, so it can be used in key templates.
+impl IntoAttributeValue for Uuid {
+    fn into_attribute_value(&self) -> AttributeValue {
+        AttributeValue::S(self.hyphenated().to_string())
+    }
+}
+
+impl ScalarAttribute for Uuid {
+    const SCALAR_ATTRIBUTE_TYPE: ScalarAttributeType = ScalarAttributeType::S;
+}
+
+impl SortKeyPrefix for Uuid {
+    type Prefix = String;
+}
+
+impl FromAttributeValue<Uuid> for Uuid {
+    fn from_attribute_value(value: AttributeValue) -> Result<Uuid, Error> {
+        let Ok(uuid) = value.as_s() else {
+            return Err(Error::parse_error(value, "Uuid", String::new()));
+        };
+        Uuid::parse_str(uuid)
+            .map_err(|e| Error::parse_error(value.clone(), "Uuid", format!("{:?}", e)))
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use uuid::Uuid;
+
+    use crate::traits::{
+        from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+    };
+
+    #[test]
+    pub fn test_uuid_round_trip() {
+        let id = Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+        assert_eq!(
+            id.into_attribute_value(),
+            AttributeValue::S("67e55044-10b1-426f-9247-bb680e5fe0c8".into())
+        );
+        assert_eq!(
+            Uuid::from_attribute_value(id.into_attribute_value()).unwrap(),
+            id
+        );
+    }
+}
@@ -1,55 +1,76 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, marker::PhantomData};
 
 use aws_lambda_events::dynamodb::EventRecord;
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
 
 use crate::{
     ConditionalExpression,
     dynamodb_context::expression::conditional::expression_builder::BuildConditionalExpression,
     error::Error,
     traits::{
-        has_pk_value::HasStaticPkValue, has_pk_value_template::HasPkValueTemplate,
-        has_sort_key::HasSortKey, into_attribute_value::IntoAttributeValue,
-        matches_template::MatchesTemplate,
+        as_key_value::AsKeyValue,
+        has_pk_value::HasStaticPkValue,
+        has_sort_key::HasSortKey,
+        into_attribute_value::IntoAttributeValue,
+        matches_template::{MatchesSkTemplate, MatchesTemplate},
+        projection_of::ProjectionOf,
         serde_dynamo_attribute_value_into::SerdeDynamoAttributeValueHashMapInto,
     },
 };
 
+/// Key attribute names with their `ScalarAttributeType`, `S` for string, `N` for number and `B` for binary keys.
 #[derive(Debug, Clone)]
 pub enum Key {
     CompositeKey {
         partition_key: String,
+        partition_key_type: ScalarAttributeType,
         sort_key: String,
+        sort_key_type: ScalarAttributeType,
     },
     PartitionKey {
         key: String,
+        key_type: ScalarAttributeType,
     },
 }
 
 impl Key {
+    /// Composite key with string partition and sort keys.
     pub fn new_composite_key(pk: &str, sk: &str) -> Key {
         Key::CompositeKey {
             partition_key: pk.into(),
+            partition_key_type: ScalarAttributeType::S,
             sort_key: sk.into(),
+            sort_key_type: ScalarAttributeType::S,
         }
     }
+
     pub fn get_partition_key(&self) -> String {
+        match self {
+            Key::CompositeKey { partition_key, .. } => partition_key.clone(),
+            Key::PartitionKey { key, .. } => key.clone(),
+        }
+    }
+
+    pub fn get_partition_key_type(&self) -> ScalarAttributeType {
         match self {
             Key::CompositeKey {
-                partition_key,
-                sort_key: _,
-            } => partition_key.clone(),
-            Key::PartitionKey { key } => key.clone(),
+                partition_key_type, ..
+            } => partition_key_type.clone(),
+            Key::PartitionKey { key_type, .. } => key_type.clone(),
         }
     }
 
     pub fn get_sort_key(&self) -> Option<String> {
         match self {
-            Key::CompositeKey {
-                partition_key: _,
-                sort_key,
-            } => Some(sort_key.clone()),
-            Key::PartitionKey { key: _ } => None,
+            Key::CompositeKey { sort_key, .. } => Some(sort_key.clone()),
+            Key::PartitionKey { .. } => None,
+        }
+    }
+
+    pub fn get_sort_key_type(&self) -> Option<ScalarAttributeType> {
+        match self {
+            Key::CompositeKey { sort_key_type, .. } => Some(sort_key_type.clone()),
+            Key::PartitionKey { .. } => None,
         }
     }
 
@@ -57,15 +78,7 @@ impl Key {
         self,
         partition_key_value: impl IntoAttributeValue,
     ) -> KeyValue {
-        let partition_key = match self {
-            Key::CompositeKey {
-                partition_key,
-                sort_key: _,
-            } => partition_key,
-            Key::PartitionKey { key } => key,
-        };
-
-        KeyValue::new_partition_key(partition_key, partition_key_value)
+        KeyValue::new_partition_key(self.get_partition_key(), partition_key_value)
     }
 
     pub fn is_equal_to(&self, key_hash_map: &HashMap<String, AttributeValue>) -> bool {
@@ -73,16 +86,28 @@ impl Key {
             Key::CompositeKey {
                 partition_key,
                 sort_key,
+                ..
             } => {
                 key_hash_map.len() == 2
                     && key_hash_map.contains_key(partition_key)
                     && key_hash_map.contains_key(sort_key)
             }
-            Key::PartitionKey { key } => key_hash_map.len() == 1 && key_hash_map.contains_key(key),
+            Key::PartitionKey { key, .. } => {
+                key_hash_map.len() == 1 && key_hash_map.contains_key(key)
+            }
         }
     }
 }
 
+/// `ScalarAttributeType` of a key attribute value, anything but a number or binary value is a string key.
+fn scalar_attribute_type(value: &AttributeValue) -> ScalarAttributeType {
+    match value {
+        AttributeValue::N(_) => ScalarAttributeType::N,
+        AttributeValue::B(_) => ScalarAttributeType::B,
+        _ => ScalarAttributeType::S,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum KeyValue {
     CompositeKey {
@@ -146,13 +171,20 @@ impl KeyValue {
         T::get_static_pk_value().into_attribute_value() == self.get_partition_key_value()
     }
 
-    pub fn matches_pk_template<T: HasPkValueTemplate>(&self) -> bool {
+    pub fn matches_pk_template<T: MatchesTemplate>(&self) -> bool {
         if let Ok(value) = self.get_partition_key_value().as_s() {
             return T::matches_template(value);
         }
         return false;
     }
 
+    pub fn matches_sk_template<T: MatchesSkTemplate>(&self) -> bool {
+        match self.get_sort_key_value() {
+            Some(AttributeValue::S(value)) => T::matches_sk_template(&value),
+            _ => false,
+        }
+    }
+
     pub fn new_partition_key(key: String, value: impl IntoAttributeValue) -> Self {
         Self::PartitionKey {
             key,
@@ -257,14 +289,19 @@ impl KeyValue {
         match self {
             KeyValue::CompositeKey {
                 partition_key,
-                partition_key_value: _,
+                partition_key_value,
                 sort_key,
-                sort_key_value: _,
+                sort_key_value,
             } => Key::CompositeKey {
                 partition_key: partition_key.clone(),
+                partition_key_type: scalar_attribute_type(partition_key_value),
                 sort_key: sort_key.clone(),
+                sort_key_type: scalar_attribute_type(sort_key_value),
+            },
+            KeyValue::PartitionKey { key, value } => Key::PartitionKey {
+                key: key.clone(),
+                key_type: scalar_attribute_type(value),
             },
-            KeyValue::PartitionKey { key, value: _ } => Key::PartitionKey { key: key.clone() },
         }
     }
 
@@ -365,3 +402,203 @@ impl KeyValue {
         }
     }
 }
+
+/// Key of a row of `T`, context functions take it so that a key of another model does not compile. <br>
+/// Built from the generated `{Name}Key` struct or from a row, `from_key_value` is the unchecked escape hatch.
+#[derive(Debug, Clone)]
+pub struct TypedKey<T> {
+    key_value: KeyValue,
+    _t: PhantomData<T>,
+}
+
+impl<T> TypedKey<T> {
+    /// Wraps a key value without checking that it belongs to `T`.
+    pub fn from_key_value(key_value: KeyValue) -> Self {
+        Self {
+            key_value,
+            _t: PhantomData,
+        }
+    }
+
+    pub fn key_value(&self) -> &KeyValue {
+        &self.key_value
+    }
+
+    pub fn into_key_value(self) -> KeyValue {
+        self.key_value
+    }
+
+    /// Key of a projection of `T`, projections share the key of their parent.
+    pub fn into_projection<P: ProjectionOf<Parent = T>>(self) -> TypedKey<P> {
+        TypedKey::from_key_value(self.key_value)
+    }
+}
+
+impl<T: AsKeyValue> From<&T> for TypedKey<T> {
+    fn from(value: &T) -> Self {
+        value.as_key_value()
+    }
+}
+
+impl<T> From<TypedKey<T>> for KeyValue {
+    fn from(value: TypedKey<T>) -> Self {
+        value.key_value
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+
+    use crate::{
+        self as dynorow, DynoRow, DynoValue,
+        key::{KeyValue, TypedKey},
+        traits::{
+            as_key_value::{AsCompositeKeyValue, AsKeyValue, AsPartitionKeyValue},
+            has_key::HasKey,
+        },
+    };
+
+    #[derive(Debug, Clone, DynoRow)]
+    pub struct Account {
+        #[dynorow(pk)]
+        pub account_id: i64,
+        pub name: String,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    pub struct Attachment {
+        #[dynorow(pk)]
+        pub message_id: String,
+        #[dynorow(sk)]
+        pub digest: Vec<u8>,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Event")]
+    pub struct Event {
+        #[dynorow(sk)]
+        pub occurred_at: u64,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Settings")]
+    pub struct Settings {
+        pub theme: String,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Team#{team_id}")]
+    pub struct Team {
+        pub team_id: u32,
+    }
+
+    #[derive(Debug, Clone, DynoValue)]
+    #[dynorow(transparent)]
+    pub struct LedgerId(pub u64);
+
+    #[derive(Debug, Clone, DynoValue)]
+    #[dynorow(transparent)]
+    pub struct EntryId(pub String);
+
+    #[derive(Debug, Clone, DynoRow)]
+    pub struct LedgerEntry {
+        #[dynorow(pk)]
+        pub ledger_id: LedgerId,
+        #[dynorow(sk)]
+        pub entry_id: EntryId,
+    }
+
+    #[test]
+    pub fn test_non_string_keys() {
+        let key = Account::get_key();
+        assert_eq!(key.get_partition_key_type(), ScalarAttributeType::N);
+        let key_value = Account::as_partition_key_value(42);
+        assert_eq!(
+            key_value.get_partition_key_value(),
+            AttributeValue::N("42".into())
+        );
+
+        let key = Attachment::get_key();
+        assert_eq!(key.get_partition_key_type(), ScalarAttributeType::S);
+        assert_eq!(key.get_sort_key_type(), Some(ScalarAttributeType::B));
+        let key_value = Attachment::as_composite_key_value("m1".into(), vec![1, 2]);
+        assert!(key_value.get_sort_key_value().unwrap().is_b());
+
+        let event = Event { occurred_at: 1700 };
+        let key_value = event.as_key_value().into_key_value();
+        assert_eq!(
+            key_value.get_sort_key_value(),
+            Some(AttributeValue::N("1700".into()))
+        );
+        assert_eq!(
+            key_value.to_key().get_sort_key_type(),
+            Some(ScalarAttributeType::N)
+        );
+        assert_eq!(
+            Event::get_key().get_sort_key_type(),
+            Some(ScalarAttributeType::N)
+        );
+
+        let settings = Settings {
+            theme: "dark".into(),
+        };
+        assert_eq!(
+            settings
+                .as_key_value()
+                .key_value()
+                .get_partition_key_value(),
+            AttributeValue::S("Settings".into())
+        );
+        let team = Team { team_id: 7 };
+        assert_eq!(
+            team.as_key_value().key_value().get_partition_key_value(),
+            AttributeValue::S("Team#7".into())
+        );
+
+        let key = LedgerEntry::get_key();
+        assert_eq!(key.get_partition_key_type(), ScalarAttributeType::N);
+        assert_eq!(key.get_sort_key_type(), Some(ScalarAttributeType::S));
+    }
+
+    #[test]
+    pub fn test_typed_key() {
+        let attachment = Attachment {
+            message_id: "m1".into(),
+            digest: vec![1, 2],
+        };
+        let key_value: KeyValue = TypedKey::from(AttachmentKey {
+            message_id: "m1".into(),
+            digest: vec![1, 2],
+        })
+        .into();
+        let row_key_value = TypedKey::from(&attachment).into_key_value();
+        assert_eq!(
+            key_value.get_partition_key_value(),
+            row_key_value.get_partition_key_value()
+        );
+        assert_eq!(
+            key_value.get_sort_key_value(),
+            row_key_value.get_sort_key_value()
+        );
+
+        let key_value = TypedKey::from(EventKey { occurred_at: 1700 }).into_key_value();
+        assert_eq!(
+            key_value.get_sort_key_value(),
+            Some(AttributeValue::N("1700".into()))
+        );
+
+        let key_value = TypedKey::from(SettingsKey {}).into_key_value();
+        assert_eq!(
+            key_value.get_partition_key_value(),
+            AttributeValue::S("Settings".into())
+        );
+
+        let key_value = TypedKey::from(TeamKey { team_id: 7 }).into_key_value();
+        assert_eq!(
+            key_value.get_partition_key_value(),
+            AttributeValue::S("Team#7".into())
+        );
+        assert!(key_value.get_sort_key_value().is_none());
+    }
+}
@@ -0,0 +1,190 @@
+use std::{fmt::Debug, str::FromStr};
+
+use aws_sdk_dynamodb::types::AttributeValue;
+
+use crate::{
+    error::Error,
+    timestamp::{Timestamp, TimestampFormat},
+};
+
+/// Key value template such as `Order:{}:{}`, generated from `#[dynorow(pk_value = "...")]` and `#[dynorow(sk_value = "...")]`. <br>
+/// Formats the placeholder values and parses them back with an anchored match,
+/// the text after a placeholder ends at the first occurrence of the next literal. <br>
+/// With an escape character, the escape character and the characters of the separators are escaped inside values,
+/// so that any value round trips.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyTemplate {
+    template: &'static str,
+    escape: Option<char>,
+}
+
+impl KeyTemplate {
+    pub const fn new(template: &'static str, escape: Option<char>) -> Self {
+        Self { template, escape }
+    }
+
+    pub fn template(&self) -> &'static str {
+        self.template
+    }
+
+    /// Literal text around the placeholders, one more than the placeholders.
+    pub fn literals(&self) -> Vec<&'static str> {
+        self.template.split("{}").collect()
+    }
+
+    /// With fewer parts than placeholders, the result is the prefix ending with the separator after the last given part,
+    /// used for `begins_with` conditions.
+    pub fn format(&self, parts: &[String]) -> String {
+        let literals = self.literals();
+        let mut result = String::from(literals[0]);
+        for (part, literal) in parts.iter().zip(&literals[1..]) {
+            result.push_str(&self.escape_part(part));
+            result.push_str(literal);
+        }
+        result
+    }
+
+    /// Escapes the escape character and every character of the separators, the value is returned as is without escape character.
+    pub fn escape_part(&self, part: &str) -> String {
+        let Some(escape) = self.escape else {
+            return part.to_string();
+        };
+        let separators = self.literals()[1..].concat();
+        let mut result = String::with_capacity(part.len());
+        for x in part.chars() {
+            if x == escape || separators.contains(x) {
+                result.push(escape);
+            }
+            result.push(x);
+        }
+        result
+    }
+
+    /// Unescaped placeholder values, empty values are read back as empty parts like `format` writes them.
+    pub fn parse(&self, value: &str) -> Result<Vec<String>, Error> {
+        self.try_parse(value)
+            .ok_or_else(|| Error::template_mismatch(value, self.template))
+    }
+
+    pub fn matches(&self, value: &str) -> bool {
+        self.try_parse(value).is_some()
+    }
+
+    fn try_parse(&self, value: &str) -> Option<Vec<String>> {
+        let literals = self.literals();
+        let mut rest = value.strip_prefix(literals[0])?;
+        let mut parts = vec![];
+        for (index, literal) in literals[1..].iter().enumerate() {
+            let is_last = index == literals.len() - 2;
+            let (part, remaining) = self.split_part(rest, literal, is_last)?;
+            parts.push(part);
+            rest = remaining;
+        }
+        rest.is_empty().then_some(parts)
+    }
+
+    /// Reads a placeholder value up to `literal`, the last placeholder has to be followed by `literal` only.
+    fn split_part<'a>(
+        &self,
+        value: &'a str,
+        literal: &str,
+        is_last: bool,
+    ) -> Option<(String, &'a str)> {
+        let mut part = String::new();
+        let mut chars = value.char_indices();
+        loop {
+            let (index, x) = match chars.next() {
+                Some(x) => x,
+                None => {
+                    return (is_last && literal.is_empty()).then_some((part, ""));
+                }
+            };
+
+            let rest = &value[index..];
+            let found = match is_last {
+                true => !literal.is_empty() && rest == literal,
+                false => rest.starts_with(literal),
+            };
+            if found {
+                return Some((part, &rest[literal.len()..]));
+            }
+
+            match self.escape {
+                Some(escape) if x == escape => part.push(chars.next()?.1),
+                _ => part.push(x),
+            }
+        }
+    }
+}
+
+/// Zero-pads an unsigned or epoch placeholder value of a sk template to `width` digits, so that `ORDER#10` sorts after `ORDER#9`. <br>
+/// Negative epoch values are returned as is, the parse functions accept the leading zeros.
+pub fn pad_sort_key_part(value: String, width: usize) -> String {
+    match value.starts_with('-') {
+        true => value,
+        false => format!("{:0>width$}", value),
+    }
+}
+
+/// Parses a placeholder value through `FromStr`.
+pub fn parse_key_part<T>(value: &str) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    T::from_str(value).map_err(|e| {
+        Error::parse_error(
+            AttributeValue::S(value.into()),
+            std::any::type_name::<T>(),
+            format!("{:?}", e),
+        )
+    })
+}
+
+/// Parses a `#[dynorow(timestamp = "...")]` placeholder value, formatted like the stored attribute.
+pub fn parse_timestamp_key_part<T: Timestamp>(
+    value: &str,
+    format: TimestampFormat,
+) -> Result<T, Error> {
+    T::from_timestamp_string(value, format).map_err(|e| {
+        Error::parse_error(
+            AttributeValue::S(value.into()),
+            std::any::type_name::<T>(),
+            e,
+        )
+    })
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::KeyTemplate;
+
+    #[test]
+    pub fn test_escaped_parts_round_trip() {
+        let template = KeyTemplate::new("Note#{}#{}", Some('\\'));
+        let parts = vec!["a#b".to_string(), "c\\d".to_string()];
+        let value = template.format(&parts);
+        assert_eq!(value, "Note#a\\#b#c\\\\d");
+        assert_eq!(template.parse(&value).unwrap(), parts);
+
+        let template = KeyTemplate::new("Note#{}#{}", None);
+        assert_eq!(
+            template.parse("Note#a#b#c").unwrap(),
+            vec!["a".to_string(), "b#c".to_string()]
+        );
+        assert!(!template.matches("Other#a#b"));
+
+        let parts = vec![String::new(), String::new()];
+        assert_eq!(template.format(&parts), "Note##");
+        assert_eq!(template.parse("Note##").unwrap(), parts);
+        assert_eq!(
+            template.parse("Note#a#").unwrap(),
+            vec!["a".to_string(), String::new()]
+        );
+
+        let template = KeyTemplate::new("Order#{}#{}", None);
+        assert_eq!(template.format(&["2024".to_string()]), "Order#2024#");
+        assert!(KeyTemplate::new("PROFILE", None).matches("PROFILE"));
+        assert!(!KeyTemplate::new("PROFILE", None).matches("PROFILE#1"));
+    }
+}
@@ -1,8 +1,12 @@
 mod dynamodb_context;
 pub mod dynamodb_sdk_extensions;
+mod dyno_number;
 pub mod error;
+mod integrations;
 pub mod key;
+pub mod key_template;
 pub mod streams;
+mod timestamp;
 pub mod traits;
 
 extern crate dynorow_derive;
@@ -10,9 +14,16 @@ extern crate dynorow_derive;
 pub use dynamodb_context::DynamodbContext;
 pub use dynamodb_context::get_result_list::GetListResult;
 pub use dynamodb_context::operations::Operation;
+pub use dyno_number::DynoNumber;
+pub use timestamp::{EpochMillis, EpochSecs, Timestamp, TimestampFormat};
 
+pub use dynorow_derive::DynoCollection;
+pub use dynorow_derive::DynoEntity;
+pub use dynorow_derive::DynoEnum;
 pub use dynorow_derive::DynoMap;
+pub use dynorow_derive::DynoProjection;
 pub use dynorow_derive::DynoRow;
+pub use dynorow_derive::DynoValue;
 pub use dynorow_derive::Fetchable;
 pub use dynorow_derive::Insertable;
 pub use dynorow_derive::Updatable;
@@ -20,9 +31,19 @@ pub use dynorow_derive::Updatable;
 pub use dynamodb_context::expression::conditional::ConditionalExpression;
 pub use dynamodb_context::expression::conditional::expression_builder::BuildConditionalExpression;
 pub use dynamodb_context::expression::conditional::expression_builder::ConditionalExpressionBuilder;
+pub use dynamodb_context::expression::conditional::expression_builder::ConvertedConditionalExpressionBuilder;
+pub use dynamodb_context::expression::key_condition::{KeyCondition, KeyConditionBuilder};
+
+pub use dynamodb_context::expression::operand::ConditionOperand;
+pub use dynamodb_context::expression::operand::IntoConditionOperand;
+pub use dynamodb_context::expression::operand::IntoUpdateOperand;
+pub use dynamodb_context::expression::operand::UpdateOperand;
 
 pub use dynamodb_context::expression::update::UpdateExpression;
+pub use dynamodb_context::expression::update::expression_builder::Binary;
+pub use dynamodb_context::expression::update::expression_builder::ConvertedUpdateExpressionBuilder;
 pub use dynamodb_context::expression::update::expression_builder::UpdateExpressionBuilder;
 
 pub use aws_sdk_dynamodb;
+pub use serde_dynamo;
 pub use serde_json;
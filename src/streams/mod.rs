@@ -5,7 +5,14 @@ pub use handle_event_records::HandleEventRecords;
 
 use serde_dynamo::Item;
 
-use crate::{key::KeyValue, streams::from_image::FromImage};
+use crate::{
+    key::KeyValue,
+    streams::from_image::FromImage,
+    traits::{
+        has_entity_type::HasEntityType,
+        serde_dynamo_attribute_value_into::SerdeDynamoAttributeValueHashMapInto,
+    },
+};
 
 pub enum EventName {
     Insert { new_image: Item },
@@ -41,6 +48,18 @@ impl TryFrom<&EventRecord> for EventName {
     }
 }
 
+/// Routes stream records by entity type, removes only carry the old image.
+pub fn event_record_has_entity_type<T: HasEntityType>(event_record: &EventRecord) -> bool {
+    let StreamRecord {
+        new_image,
+        old_image,
+        ..
+    } = &event_record.change;
+    [new_image, old_image]
+        .into_iter()
+        .any(|x| T::matches_entity_type(&x.clone().into_inner().into_aws_attribute_value_hashmap()))
+}
+
 pub struct ModifiedItem<T> {
     pub old: T,
     pub new: T,
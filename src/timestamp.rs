@@ -0,0 +1,96 @@
+use std::fmt::Display;
+
+use aws_sdk_dynamodb::types::AttributeValue;
+
+use crate::{
+    error::Error,
+    traits::{from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue},
+};
+
+/// Storage format of a `#[dynorow(timestamp = "...")]` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// `S` attribute, UTC with nine fractional digits so that the strings sort chronologically.
+    Rfc3339,
+    /// `N` attribute, seconds since the unix epoch.
+    EpochSecs,
+    /// `N` attribute, milliseconds since the unix epoch.
+    EpochMillis,
+}
+
+/// Date and time types that can be stored in any `TimestampFormat`. <br>
+/// Implemented for the `chrono` and `time` types when their features are enabled.
+pub trait Timestamp: Sized {
+    fn to_timestamp_string(&self, format: TimestampFormat) -> String;
+
+    fn from_timestamp_string(value: &str, format: TimestampFormat) -> Result<Self, String>;
+
+    fn to_timestamp_attribute_value(&self, format: TimestampFormat) -> AttributeValue {
+        match format {
+            TimestampFormat::Rfc3339 => AttributeValue::S(self.to_timestamp_string(format)),
+            TimestampFormat::EpochSecs | TimestampFormat::EpochMillis => {
+                AttributeValue::N(self.to_timestamp_string(format))
+            }
+        }
+    }
+
+    fn from_timestamp_attribute_value(
+        value: AttributeValue,
+        format: TimestampFormat,
+    ) -> Result<Self, Error> {
+        let timestamp = match (&value, format) {
+            (AttributeValue::S(x), TimestampFormat::Rfc3339) => x,
+            (AttributeValue::N(x), TimestampFormat::EpochSecs | TimestampFormat::EpochMillis) => x,
+            _ => {
+                return Err(Error::parse_error(
+                    value,
+                    std::any::type_name::<Self>(),
+                    format!("Expected a {:?} timestamp.", format),
+                ));
+            }
+        };
+        Self::from_timestamp_string(timestamp, format)
+            .map_err(|e| Error::parse_error(value.clone(), std::any::type_name::<Self>(), e))
+    }
+}
+
+macro_rules! impl_timestamp_wrapper {
+    ($($wrapper:ident => $format:expr),*) => {
+        $(
+            /// Wraps a timestamp so that it is converted the same way as a
+            /// field with the matching `#[dynorow(timestamp = "...")]`. <br>
+            /// Used as the value type of the expression builders of such fields.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $wrapper<T>(pub T);
+
+            impl<T: Timestamp> IntoAttributeValue for $wrapper<T> {
+                fn into_attribute_value(&self) -> AttributeValue {
+                    self.0.to_timestamp_attribute_value($format)
+                }
+            }
+
+            impl<T: Timestamp> FromAttributeValue<$wrapper<T>> for $wrapper<T> {
+                fn from_attribute_value(value: AttributeValue) -> Result<$wrapper<T>, Error> {
+                    Ok($wrapper(T::from_timestamp_attribute_value(value, $format)?))
+                }
+            }
+
+            impl<T: Timestamp> Display for $wrapper<T> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}", self.0.to_timestamp_string($format))
+                }
+            }
+
+            impl<T> From<T> for $wrapper<T> {
+                fn from(value: T) -> Self {
+                    $wrapper(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_timestamp_wrapper!(
+    EpochSecs => TimestampFormat::EpochSecs,
+    EpochMillis => TimestampFormat::EpochMillis
+);
@@ -1,5 +1,59 @@
 use aws_sdk_dynamodb::types::AttributeValue;
 
+use crate::error::Error;
+
 pub trait AsAttributeKeyValues {
-    fn as_attribute_key_values(&self) -> std::collections::HashMap<String, AttributeValue>;
+    /// Used by the insert and update operations so that serialization errors are returned instead of panicking.
+    fn try_as_attribute_key_values(
+        &self,
+    ) -> Result<std::collections::HashMap<String, AttributeValue>, Error>;
+
+    /// Panics if a serde field cannot be serialized, see `try_as_attribute_key_values`.
+    fn as_attribute_key_values(&self) -> std::collections::HashMap<String, AttributeValue> {
+        self.try_as_attribute_key_values()
+            .expect("Should be able to generate attribute values.")
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::HashMap;
+
+    use aws_sdk_dynamodb::types::AttributeValue;
+
+    use crate::{self as dynorow, DynoRow, traits::as_attribute_key_values::AsAttributeKeyValues};
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", rename_all = "camelCase")]
+    #[dynorow(pk_value = "Customer")]
+    pub struct Customer {
+        #[dynorow(sk)]
+        pub customer_id: String,
+        #[dynorow(alias = "name", alias = "fullname")]
+        pub display_name: String,
+        #[dynorow(key = "email")]
+        pub email_address: String,
+    }
+
+    #[test]
+    pub fn test_rename_all_and_alias() {
+        let customer = Customer {
+            customer_id: "1".into(),
+            display_name: "Jane".into(),
+            email_address: "jane@example.com".into(),
+        };
+        let mut keys: Vec<String> = customer.as_attribute_key_values().into_keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["customerId", "displayName", "email", "pk"]);
+
+        let items = HashMap::from([
+            ("customerId".to_string(), AttributeValue::S("1".into())),
+            ("fullname".to_string(), AttributeValue::S("Jane".into())),
+            (
+                "email".to_string(),
+                AttributeValue::S("jane@example.com".into()),
+            ),
+        ]);
+        assert_eq!(Customer::try_from(items).unwrap().display_name, "Jane");
+    }
 }
@@ -1,15 +1,26 @@
-use crate::key::KeyValue;
+use crate::key::{KeyValue, TypedKey};
 
+/// Key constructors take the value types of the key fields, converted the same way as the stored attributes.
 pub trait AsPkAvailableCompositeKeyValue {
-    fn as_pk_available_composite_key_value(sort_key_value: String) -> KeyValue;
+    type SortKey;
+
+    fn as_pk_available_composite_key_value(sort_key_value: Self::SortKey) -> KeyValue;
 }
 
 pub trait AsCompositeKeyValue {
-    fn as_composite_key_value(partition_key_value: String, sort_key_value: String) -> KeyValue;
+    type PartitionKey;
+    type SortKey;
+
+    fn as_composite_key_value(
+        partition_key_value: Self::PartitionKey,
+        sort_key_value: Self::SortKey,
+    ) -> KeyValue;
 }
 
 pub trait AsPartitionKeyValue {
-    fn as_partition_key_value(partition_key_value: String) -> KeyValue;
+    type PartitionKey;
+
+    fn as_partition_key_value(partition_key_value: Self::PartitionKey) -> KeyValue;
 }
 
 pub trait AsValueAvailablePkValue {
@@ -17,5 +28,13 @@ pub trait AsValueAvailablePkValue {
 }
 
 pub trait AsKeyValue {
-    fn as_key_value(&self) -> KeyValue;
-}
\ No newline at end of file
+    /// Key of the row without its model, usable through `dyn` such as in batch deletes.
+    fn as_untyped_key_value(&self) -> KeyValue;
+
+    fn as_key_value(&self) -> TypedKey<Self>
+    where
+        Self: Sized,
+    {
+        TypedKey::from_key_value(self.as_untyped_key_value())
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::types::AttributeValue;
+
+use crate::error::Error;
+
+/// Implemented by `DynoCollection`, assembles the items of a partition into one struct. <br>
+/// Items matching none of the fields are skipped.
+pub trait Collection: Sized {
+    fn from_items(items: Vec<HashMap<String, AttributeValue>>) -> Result<Self, Error>;
+}
+
+#[cfg(test)]
+pub mod test {
+    use crate::{
+        self as dynorow, DynoCollection, DynoRow,
+        error::Error,
+        traits::{as_attribute_key_values::AsAttributeKeyValues, collection::Collection},
+    };
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Customer#{customer_id}")]
+    #[dynorow(sk = "sk", sk_value = "PROFILE")]
+    pub struct Customer {
+        pub customer_id: String,
+        pub name: String,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Customer#{customer_id}")]
+    #[dynorow(sk = "sk", sk_value = "INVOICE#{invoice_id}")]
+    pub struct Invoice {
+        pub customer_id: String,
+        pub invoice_id: String,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    pub struct Preferences {
+        #[dynorow(pk)]
+        pub pk: String,
+        #[dynorow(sk)]
+        pub sk: String,
+        pub theme: String,
+    }
+
+    #[derive(Debug, DynoCollection)]
+    #[dynorow(table = String::from("customers"))]
+    pub struct CustomerAggregate {
+        pub customer: Customer,
+        pub invoices: Vec<Invoice>,
+        #[dynorow(sk_prefix = "PREFERENCES")]
+        pub preferences: Option<Preferences>,
+    }
+
+    #[test]
+    pub fn test_collection() {
+        let customer = Customer {
+            customer_id: "c1".into(),
+            name: "Ada".into(),
+        };
+        let invoices = ["i1", "i2"].map(|x| {
+            Invoice {
+                customer_id: "c1".into(),
+                invoice_id: x.into(),
+            }
+            .as_attribute_key_values()
+        });
+        let preferences = Preferences {
+            pk: "Customer#c1".into(),
+            sk: "PREFERENCES".into(),
+            theme: "dark".into(),
+        }
+        .as_attribute_key_values();
+
+        let aggregate = CustomerAggregate::from_items(
+            [vec![customer.as_attribute_key_values()], invoices.to_vec()].concat(),
+        )
+        .unwrap();
+        assert_eq!(aggregate.customer.name, "Ada");
+        assert_eq!(aggregate.invoices.len(), 2);
+        assert!(aggregate.preferences.is_none());
+
+        let aggregate = CustomerAggregate::from_items(vec![
+            preferences.clone(),
+            customer.as_attribute_key_values(),
+        ])
+        .unwrap();
+        assert_eq!(aggregate.preferences.unwrap().theme, "dark");
+        assert!(aggregate.invoices.is_empty());
+
+        let error = CustomerAggregate::from_items(vec![preferences.clone()]).unwrap_err();
+        assert!(matches!(error, Error::ValueNotFound(x) if x == "customer"));
+
+        let error = CustomerAggregate::from_items(vec![
+            customer.as_attribute_key_values(),
+            preferences.clone(),
+            customer.as_attribute_key_values(),
+        ])
+        .unwrap_err();
+        assert!(matches!(error, Error::DuplicateItem(x) if x == "customer"));
+    }
+}
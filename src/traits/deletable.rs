@@ -9,7 +9,7 @@ pub trait Deletable : AsKeyValue {
 }
 
 pub fn into_delete_request(deletable: Box<&dyn Deletable>) -> Result<DeleteRequest, Error> {
-        let key = deletable.as_key_value();
+        let key = deletable.as_untyped_key_value();
         let builder = DeleteRequest::builder();
         return match key {
             KeyValue::CompositeKey { partition_key, partition_key_value, sort_key, sort_key_value } => {
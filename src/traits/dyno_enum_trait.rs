@@ -0,0 +1,104 @@
+/// Implemented by `#[derive(DynoEnum)]`. Each unit variant is stored as a plain string (`S`).
+pub trait DynoEnumTrait: Sized {
+    fn as_variant_str(&self) -> &'static str;
+    fn from_variant_str(value: &str) -> Option<Self>;
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::HashMap;
+
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use dynorow_derive::{DynoEnum, DynoRow};
+
+    use crate::{
+        self as dynorow,
+        error::Error,
+        traits::{
+            from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+        },
+    };
+
+    #[derive(Debug, Clone, PartialEq, DynoEnum)]
+    #[dynorow(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderStatus {
+        Pending,
+        InTransit,
+        #[dynorow(rename = "done")]
+        Delivered,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Order")]
+    pub struct Order {
+        #[dynorow(sk)]
+        pub order_id: String,
+        pub status: OrderStatus,
+        pub previous_status: Option<OrderStatus>,
+    }
+
+    #[test]
+    pub fn test_round_trip() {
+        assert_eq!(
+            OrderStatus::InTransit.into_attribute_value(),
+            AttributeValue::S("IN_TRANSIT".into())
+        );
+        assert_eq!(
+            OrderStatus::Delivered.into_attribute_value(),
+            AttributeValue::S("done".into())
+        );
+
+        let item = HashMap::from([
+            ("pk".to_string(), AttributeValue::S("Order".into())),
+            ("order_id".to_string(), AttributeValue::S("1".into())),
+            ("status".to_string(), AttributeValue::S("PENDING".into())),
+            (
+                "previous_status".to_string(),
+                AttributeValue::S("done".into()),
+            ),
+        ]);
+        let order = Order::try_from(item).unwrap();
+        assert_eq!(order.status, OrderStatus::Pending);
+        assert_eq!(order.previous_status, Some(OrderStatus::Delivered));
+    }
+
+    #[test]
+    pub fn test_unknown_variant() {
+        let result = OrderStatus::from_attribute_value(AttributeValue::S("Delivered".into()));
+        assert!(matches!(result, Err(Error::ParseError { .. })));
+        assert!(format!("{:?}", result).contains("OrderStatus"));
+    }
+
+    #[test]
+    pub fn test_typed_conditional_expression() {
+        let expression = Order::conditional_expression_builder()
+            .status()
+            .in_list([OrderStatus::Pending, OrderStatus::InTransit])
+            .and()
+            .expr(
+                Order::conditional_expression_builder()
+                    .previous_status()
+                    .equals(OrderStatus::Delivered),
+            );
+
+        assert_eq!(
+            expression.to_string(),
+            "#var_status IN (:vc1, :vc2) AND #var_previous_status = :vc3"
+        );
+        assert_eq!(
+            expression
+                .get_expression_attribute_values()
+                .unwrap()
+                .get(":vc2"),
+            Some(&AttributeValue::S("IN_TRANSIT".into()))
+        );
+        assert_eq!(
+            expression
+                .get_expression_attribute_values()
+                .unwrap()
+                .get(":vc3"),
+            Some(&AttributeValue::S("done".into()))
+        );
+    }
+}
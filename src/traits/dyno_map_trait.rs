@@ -1 +1,405 @@
 pub trait DynoMapTrait {}
+
+/// Path of a map field, flattened maps have an empty parent name and their fields stay at the top level.
+pub fn nested_attribute_path(parent_name: &str, key: &str) -> String {
+    match parent_name.is_empty() {
+        true => key.to_string(),
+        false => format!("{}.{}", parent_name, key),
+    }
+}
+
+/// Implemented by `#[derive(DynoMap)]` to reach the fields of `#[dynorow(nested)]` and flattened fields from a parent update expression builder.
+pub trait DynoMapUpdateFields {
+    type Builder;
+    fn dynomap_update_expression_builder(parent_name: &str) -> Self::Builder;
+}
+
+/// Implemented by `#[derive(DynoMap)]` to reach the fields of a flattened struct from the parent conditional expression builder.
+pub trait DynoMapConditionalFields {
+    type Builder;
+    fn dynomap_conditional_expression_builder(parent_name: &str) -> Self::Builder;
+}
+
+/// Implemented by the conditional expression builders of a struct for each of its `#[dynorow(flatten)]` fields of type `T`,
+/// `I` is the `FlattenedIndex` of the field and tells apart fields of the same type. <br>
+/// `#[derive(DynoMap)]` forwards the field functions of its builder to every builder implementing it,
+/// through the `{Name}FlattenedConditionalFields` trait.
+pub trait FlattenedConditionalFields<T, I> {
+    type Builder;
+    fn flattened_fields(&self) -> Self::Builder;
+}
+
+/// Update expression builder counterpart of `FlattenedConditionalFields`, forwarded through `{Name}FlattenedUpdateFields`.
+pub trait FlattenedUpdateFields<T, I> {
+    type Builder;
+    fn flattened_fields(&self) -> Self::Builder;
+}
+
+/// Position of a flattened field among the flattened fields of its struct.
+pub struct FlattenedIndex<const N: usize>;
+
+/// Attribute names of the fields of a struct deriving `DynoMap`,
+/// checked against the attributes of the structs flattening it.
+pub trait DynoMapAttributeNames {
+    const ATTRIBUTE_NAMES: &'static [&'static str];
+}
+
+/// Whether both lists contain the same name, usable in constants.
+pub const fn shares_attribute_name(left: &[&str], right: &[&str]) -> bool {
+    let mut i = 0;
+    while i < left.len() {
+        let mut j = 0;
+        while j < right.len() {
+            if left[i].len() == right[j].len() {
+                let (a, b) = (left[i].as_bytes(), right[j].as_bytes());
+                let mut k = 0;
+                while k < a.len() && a[k] == b[k] {
+                    k += 1;
+                }
+                if k == a.len() {
+                    return true;
+                }
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    false
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::HashMap;
+
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use dynorow_derive::{DynoMap, DynoRow};
+
+    use crate::{
+        self as dynorow, BuildConditionalExpression,
+        error::Error,
+        traits::{
+            as_attribute_key_values::AsAttributeKeyValues, as_projection::AsProjection,
+            from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+        },
+    };
+
+    #[derive(Debug, Clone, PartialEq, DynoMap)]
+    #[dynorow(tag = "type", rename_all = "snake_case")]
+    pub enum PaymentMethod {
+        Card {
+            last4: String,
+            #[dynorow(key = "expires")]
+            expiry: Option<String>,
+        },
+        Bank {
+            iban: String,
+        },
+        Cash,
+    }
+
+    #[derive(Debug, Clone, PartialEq, DynoMap)]
+    pub struct Address {
+        pub city: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, DynoMap)]
+    #[dynorow(tag = "kind", content = "data")]
+    pub enum Delivery {
+        Pickup,
+        Shipping { address: Address },
+        Locker(String),
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Payment")]
+    pub struct Payment {
+        #[dynorow(sk)]
+        pub payment_id: String,
+        pub method: PaymentMethod,
+        pub delivery: Option<Delivery>,
+    }
+
+    #[test]
+    pub fn test_internally_tagged() {
+        let card = PaymentMethod::Card {
+            last4: "4242".into(),
+            expiry: None,
+        };
+        let AttributeValue::M(map) = card.into_attribute_value() else {
+            panic!("Should be a map.");
+        };
+        assert_eq!(map.get("type"), Some(&AttributeValue::S("card".into())));
+        assert_eq!(map.get("last4"), Some(&AttributeValue::S("4242".into())));
+        assert_eq!(map.get("expires"), Some(&AttributeValue::Null(true)));
+
+        let bank = AttributeValue::M(HashMap::from([
+            ("type".to_string(), AttributeValue::S("bank".into())),
+            ("iban".to_string(), AttributeValue::S("DE00".into())),
+        ]));
+        assert_eq!(
+            PaymentMethod::from_attribute_value(bank).unwrap(),
+            PaymentMethod::Bank {
+                iban: "DE00".into()
+            }
+        );
+        assert_eq!(
+            PaymentMethod::from_attribute_value(PaymentMethod::Cash.into_attribute_value())
+                .unwrap(),
+            PaymentMethod::Cash
+        );
+
+        let unknown = AttributeValue::M(HashMap::from([(
+            "type".to_string(),
+            AttributeValue::S("crypto".into()),
+        )]));
+        let result = PaymentMethod::from_attribute_value(unknown);
+        assert!(matches!(result, Err(Error::ParseError { .. })));
+        assert!(format!("{:?}", result).contains("PaymentMethod"));
+    }
+
+    #[test]
+    pub fn test_adjacently_tagged() {
+        let shipping = Delivery::Shipping {
+            address: Address {
+                city: "Berlin".into(),
+            },
+        };
+        let AttributeValue::M(map) = shipping.into_attribute_value() else {
+            panic!("Should be a map.");
+        };
+        assert_eq!(map.get("kind"), Some(&AttributeValue::S("Shipping".into())));
+        assert!(
+            map.get("data")
+                .unwrap()
+                .as_m()
+                .unwrap()
+                .contains_key("address")
+        );
+
+        for delivery in [shipping, Delivery::Pickup, Delivery::Locker("L-12".into())] {
+            assert_eq!(
+                Delivery::from_attribute_value(delivery.into_attribute_value()).unwrap(),
+                delivery
+            );
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, DynoMap)]
+    pub struct Audit {
+        pub created_by: String,
+        #[dynorow(key = "tenant")]
+        pub tenant_id: String,
+        pub version: u32,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Document")]
+    pub struct Document {
+        #[dynorow(sk)]
+        pub document_id: String,
+        pub title: String,
+        #[dynorow(flatten)]
+        pub audit: Audit,
+        #[dynorow(flatten)]
+        pub retention: Retention,
+    }
+
+    #[derive(Debug, Clone, PartialEq, DynoMap)]
+    pub struct Retention {
+        pub keep_days: u32,
+    }
+
+    #[test]
+    pub fn test_flatten() {
+        let document = Document {
+            document_id: "1".into(),
+            title: "Notes".into(),
+            audit: Audit {
+                created_by: "jane".into(),
+                tenant_id: "acme".into(),
+                version: 2,
+            },
+            retention: Retention { keep_days: 30 },
+        };
+        let attributes = document.as_attribute_key_values();
+        let mut keys: Vec<&String> = attributes.keys().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                "created_by",
+                "document_id",
+                "keep_days",
+                "pk",
+                "tenant",
+                "title",
+                "version"
+            ]
+        );
+        assert_eq!(
+            Document::try_from(attributes).unwrap().audit,
+            document.audit
+        );
+
+        let projection_names = Document::as_projection_names();
+        assert_eq!(projection_names.get("#v_tenant").unwrap(), "tenant");
+        assert!(
+            Document::as_projection()
+                .ends_with("#v_created_by, #v_tenant, #v_version, #v_keep_days")
+        );
+
+        let update = Document::update_expression_builder()
+            .version()
+            .add_increment(1)
+            .and(
+                Document::update_expression_builder()
+                    .audit_fields()
+                    .created_by()
+                    .set_new_value("john".to_string()),
+            );
+        assert_eq!(
+            update.to_string(),
+            "\nSET #var_created_by = :vu1\nADD #var_version :vu2"
+        );
+
+        let condition = Document::conditional_expression_builder()
+            .tenant_id()
+            .equals("acme")
+            .and()
+            .expr(
+                Document::conditional_expression_builder()
+                    .keep_days()
+                    .greater_than(7),
+            );
+        assert_eq!(
+            condition.to_string(),
+            "#var_tenant = :vc1 AND #var_keep_days > :vc2"
+        );
+
+        let update = Document::update_expression_builder()
+            .keep_days()
+            .set_new_value(90);
+        assert_eq!(update.to_string(), "\nSET #var_keep_days = :vu1");
+    }
+
+    #[derive(Debug, Clone, PartialEq, DynoMap)]
+    pub struct Contact {
+        pub name: String,
+        #[dynorow(nested)]
+        pub address: Address,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Customer")]
+    pub struct Customer {
+        #[dynorow(sk)]
+        pub customer_id: String,
+        #[dynorow(nested)]
+        pub contact: Option<Contact>,
+    }
+
+    #[test]
+    pub fn test_nested_fields() {
+        let update = Customer::update_expression_builder()
+            .contact_fields()
+            .address_fields()
+            .city()
+            .set_new_value("Paris".to_string());
+        assert_eq!(
+            format!("{:?}", update.get_expression_attribute_names()),
+            r##"{"#var_contact_address_city": "contact.address.city"}"##
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq, DynoMap)]
+    pub struct OrderPlaced {
+        pub order_id: String,
+        pub amount: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Event")]
+    pub struct Envelope<T>
+    where
+        T: Clone,
+    {
+        #[dynorow(sk)]
+        pub event_id: String,
+        pub payload: T,
+        pub retries: Option<u8>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Tracked")]
+    pub struct Tracked<M: Clone> {
+        #[dynorow(sk)]
+        pub id: String,
+        #[dynorow(flatten)]
+        pub meta: M,
+    }
+
+    #[derive(Debug, Clone, PartialEq, DynoMap)]
+    #[dynorow(tag = "status", content = "result")]
+    pub enum Outcome<T> {
+        Done(T),
+        Failed { reason: String },
+    }
+
+    #[test]
+    pub fn test_generic_models() {
+        let envelope = Envelope {
+            event_id: "1".into(),
+            payload: OrderPlaced {
+                order_id: "o-1".into(),
+                amount: 30,
+            },
+            retries: None,
+        };
+        let attributes = envelope.as_attribute_key_values();
+        assert!(attributes.get("payload").unwrap().as_m().is_ok());
+        assert_eq!(
+            Envelope::<OrderPlaced>::try_from(attributes).unwrap(),
+            envelope
+        );
+        assert!(Envelope::<OrderPlaced>::as_projection().contains("#v_payload"));
+
+        let update = Envelope::<OrderPlaced>::update_expression_builder()
+            .retries()
+            .set_new_value(Some(1));
+        assert_eq!(update.to_string(), "\nSET #var_retries = :vu1");
+
+        let tracked = Tracked {
+            id: "1".into(),
+            meta: Audit {
+                created_by: "jane".into(),
+                tenant_id: "acme".into(),
+                version: 1,
+            },
+        };
+        let attributes = tracked.as_attribute_key_values();
+        assert!(attributes.contains_key("tenant"));
+        assert_eq!(Tracked::<Audit>::try_from(attributes).unwrap(), tracked);
+
+        let condition = Tracked::<Audit>::conditional_expression_builder()
+            .tenant_id()
+            .equals("acme");
+        assert_eq!(condition.to_string(), "#var_tenant = :vc1");
+
+        for outcome in [
+            Outcome::Done(envelope.payload),
+            Outcome::Failed {
+                reason: "timeout".into(),
+            },
+        ] {
+            assert_eq!(
+                Outcome::from_attribute_value(outcome.into_attribute_value()).unwrap(),
+                outcome
+            );
+        }
+    }
+}
@@ -0,0 +1,8 @@
+/// Implemented by `#[derive(DynoValue)]`, gives access to the inner value of the newtype. <br>
+/// Sets of newtypes are converted through it, as a set of the inner values.
+pub trait DynoValueTrait: Sized {
+    type Inner;
+
+    fn as_inner(&self) -> &Self::Inner;
+    fn from_inner(inner: Self::Inner) -> Self;
+}
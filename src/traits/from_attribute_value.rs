@@ -1,11 +1,27 @@
 use std::collections::{HashMap, HashSet};
 
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::{primitives::Blob, types::AttributeValue};
+use bytes::Bytes;
 
-use crate::{error::Error, traits::dyno_map_trait::DynoMapTrait};
+use crate::{
+    error::Error,
+    traits::{dyno_map_trait::DynoMapTrait, dyno_value_trait::DynoValueTrait},
+};
 
 pub trait FromAttributeValue<T> {
     fn from_attribute_value(value: AttributeValue) -> Result<T, Error>;
+
+    /// Used by `Vec<T>`. Read from a list by default, `u8` overrides this to read `Vec<u8>` from binary.
+    fn from_attribute_value_list(value: AttributeValue) -> Result<Vec<T>, Error> {
+        if let AttributeValue::L(list) = value {
+            let mut result: Vec<T> = vec![];
+            for item in list {
+                result.push(Self::from_attribute_value(item)?);
+            }
+            return Ok(result);
+        }
+        Err(Error::parse_error(value, "Vec<T>", String::new()))
+    }
 }
 
 impl<T> FromAttributeValue<T> for T
@@ -38,14 +54,7 @@ where
     T: FromAttributeValue<T>,
 {
     fn from_attribute_value(value: AttributeValue) -> Result<Vec<T>, Error> {
-        if let AttributeValue::L(list) = value {
-            let mut result: Vec<T> = vec![];
-            for item in list {
-                result.push(<T as FromAttributeValue<T>>::from_attribute_value(item)?);
-            }
-            return Ok(result);
-        }
-        return Err(Error::parse_error(value, "Vec<T>", String::new()));
+        <T as FromAttributeValue<T>>::from_attribute_value_list(value)
     }
 }
 
@@ -58,39 +67,114 @@ impl FromAttributeValue<String> for String {
     }
 }
 
-impl FromAttributeValue<i32> for i32 {
-    fn from_attribute_value(value: AttributeValue) -> Result<i32, Error> {
+macro_rules! impl_from_attribute_value_for_number {
+    ($($number:ty),*) => {
+        $(
+            impl FromAttributeValue<$number> for $number {
+                fn from_attribute_value(value: AttributeValue) -> Result<$number, Error> {
+                    if let Ok(number) = value.as_n() {
+                        if let Ok(parsed_value) = number.parse::<$number>() {
+                            return Ok(parsed_value);
+                        }
+                    }
+                    Err(Error::parse_error(value, stringify!($number), String::new()))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_attribute_value_for_number_set {
+    ($($number:ty),*) => {
+        $(
+            impl FromAttributeValue<HashSet<$number>> for HashSet<$number> {
+                fn from_attribute_value(value: AttributeValue) -> Result<HashSet<$number>, Error> {
+                    if value.is_null() {
+                        return Ok(HashSet::new());
+                    }
+
+                    if let Ok(numeric_set) = value.as_ns() {
+                        let mut result: HashSet<$number> = HashSet::new();
+                        for item in numeric_set {
+                            let number = item.parse::<$number>().map_err(|e| {
+                                Error::parse_error(
+                                    value.clone(),
+                                    concat!("HashSet<", stringify!($number), ">"),
+                                    format!("{:?}", e),
+                                )
+                            })?;
+                            result.insert(number);
+                        }
+                        return Ok(result);
+                    }
+                    Err(Error::parse_error(
+                        value,
+                        concat!("HashSet<", stringify!($number), ">"),
+                        String::new(),
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_attribute_value_for_number!(
+    i8, i16, i32, i64, i128, isize, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl FromAttributeValue<u8> for u8 {
+    fn from_attribute_value(value: AttributeValue) -> Result<u8, Error> {
         if let Ok(number) = value.as_n() {
-            if let Ok(parsed_value) = number.parse::<i32>() {
+            if let Ok(parsed_value) = number.parse::<u8>() {
                 return Ok(parsed_value);
             }
         }
-        return Err(Error::parse_error(value, "i32", String::new()));
+        Err(Error::parse_error(value, "u8", String::new()))
+    }
+
+    fn from_attribute_value_list(value: AttributeValue) -> Result<Vec<u8>, Error> {
+        if let AttributeValue::B(blob) = value {
+            return Ok(blob.into_inner());
+        }
+        Err(Error::parse_error(value, "Vec<u8>", String::new()))
     }
 }
 
-impl FromAttributeValue<u32> for u32 {
-    fn from_attribute_value(value: AttributeValue) -> Result<u32, Error> {
-        if let Ok(number) = value.as_n() {
-            if let Ok(parsed_value) = number.parse::<u32>() {
-                return Ok(parsed_value);
-            }
+impl FromAttributeValue<Blob> for Blob {
+    fn from_attribute_value(value: AttributeValue) -> Result<Blob, Error> {
+        if let AttributeValue::B(blob) = value {
+            return Ok(blob);
         }
-        return Err(Error::parse_error(value, "u32", String::new()));
+        Err(Error::parse_error(value, "Blob", String::new()))
     }
 }
 
-impl FromAttributeValue<f32> for f32 {
-    fn from_attribute_value(value: AttributeValue) -> Result<f32, Error> {
-        if let Ok(number) = value.as_n() {
-            if let Ok(parsed_value) = number.parse::<f32>() {
-                return Ok(parsed_value);
-            }
+impl FromAttributeValue<Bytes> for Bytes {
+    fn from_attribute_value(value: AttributeValue) -> Result<Bytes, Error> {
+        if let AttributeValue::B(blob) = value {
+            return Ok(Bytes::from(blob.into_inner()));
         }
-        return Err(Error::parse_error(value, "f32", String::new()));
+        Err(Error::parse_error(value, "Bytes", String::new()))
     }
 }
 
+impl FromAttributeValue<HashSet<Vec<u8>>> for HashSet<Vec<u8>> {
+    fn from_attribute_value(value: AttributeValue) -> Result<HashSet<Vec<u8>>, Error> {
+        if value.is_null() {
+            return Ok(HashSet::new());
+        }
+
+        if let AttributeValue::Bs(set) = value {
+            return Ok(set.into_iter().map(|x| x.into_inner()).collect());
+        }
+        Err(Error::parse_error(value, "HashSet<Vec<u8>>", String::new()))
+    }
+}
+
+impl_from_attribute_value_for_number_set!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
 impl FromAttributeValue<bool> for bool {
     fn from_attribute_value(value: AttributeValue) -> Result<bool, Error> {
         if let Ok(boolean) = value.as_bool() {
@@ -113,46 +197,15 @@ impl FromAttributeValue<HashSet<String>> for HashSet<String> {
     }
 }
 
-impl FromAttributeValue<HashSet<i32>> for HashSet<i32> {
-    fn from_attribute_value(value: AttributeValue) -> Result<HashSet<i32>, Error> {
-        if value.is_null() {
-            return Ok(HashSet::new());
-        }
-
-        if let Ok(numeric_set) = value.as_ns() {
-            let mut result: HashSet<i32> = HashSet::new();
-            for item in numeric_set {
-                let number = item.parse::<i32>().map_err(|e| {
-                    Error::parse_error(value.clone(), "HashSet<i32>", format!("{:?}", e))
-                })?;
-                result.insert(number);
-            }
-            return Ok(result);
-        }
-        return Err(Error::parse_error(value, "HashSet<i32>", String::new()));
-    }
-}
-
-impl FromAttributeValue<HashSet<u32>> for HashSet<u32> {
-    fn from_attribute_value(value: AttributeValue) -> Result<HashSet<u32>, Error> {
-        if value.is_null() {
-            return Ok(HashSet::new());
-        }
-
-        if let Ok(numeric_set) = value.as_ns() {
-            let mut result: HashSet<u32> = HashSet::new();
-            for item in numeric_set {
-                let number = item.parse::<u32>().map_err(|e| {
-                    Error::parse_error(value.clone(), "Result<HashSet<u32>", format!("{:?}", e))
-                })?;
-                result.insert(number);
-            }
-            return Ok(result);
-        }
-        return Err(Error::parse_error(
-            value,
-            "Result<HashSet<u32>",
-            String::new(),
-        ));
+/// Sets of `DynoValue` newtypes are read as the set of their inner values.
+impl<T, S> FromAttributeValue<HashSet<T, S>> for HashSet<T, S>
+where
+    T: DynoValueTrait,
+    HashSet<T::Inner>: FromAttributeValue<HashSet<T::Inner>>,
+    HashSet<T, S>: FromIterator<T>,
+{
+    fn from_attribute_value(value: AttributeValue) -> Result<HashSet<T, S>, Error> {
+        let inner = <HashSet<T::Inner>>::from_attribute_value(value)?;
+        Ok(inner.into_iter().map(T::from_inner).collect())
     }
 }
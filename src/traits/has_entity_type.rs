@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::types::AttributeValue;
+
+use crate::{
+    ConditionalExpression,
+    dynamodb_context::expression::conditional::expression_builder::BuildConditionalExpression,
+};
+
+/// Implemented by `DynoRow` for `#[dynorow(entity_type = "...", attr = "...")]`. <br>
+/// The entity type is written under the attribute on every put, and items holding another one are rejected when read.
+pub trait HasEntityType {
+    fn get_entity_type_attribute() -> String;
+    fn get_entity_type() -> String;
+
+    fn matches_entity_type(item: &HashMap<String, AttributeValue>) -> bool {
+        item.get(&Self::get_entity_type_attribute())
+            .and_then(|x| x.as_s().ok())
+            .is_some_and(|x| *x == Self::get_entity_type())
+    }
+
+    /// Filter on the entity type, for queries over partitions holding several entities.
+    fn entity_type_condition() -> ConditionalExpression {
+        Self::get_entity_type_attribute().string_equals(&Self::get_entity_type())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use aws_sdk_dynamodb::types::AttributeValue;
+
+    use crate::{
+        self as dynorow, DynoRow,
+        error::Error,
+        traits::{
+            as_attribute_key_values::AsAttributeKeyValues, as_projection::AsProjection,
+            has_entity_type::HasEntityType, matches_item::MatchesItem,
+        },
+    };
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "User#{user_id}", entity_type = "Like")]
+    pub struct Like {
+        pub user_id: String,
+        #[dynorow(sk)]
+        pub post_id: String,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "User#{user_id}")]
+    #[dynorow(entity_type = "Bookmark", attr = "kind")]
+    pub struct Bookmark {
+        pub user_id: String,
+        #[dynorow(sk)]
+        pub post_id: String,
+    }
+
+    #[test]
+    pub fn test_entity_type() {
+        let like = Like {
+            user_id: "user_1".into(),
+            post_id: "post_1".into(),
+        };
+        let bookmark = Bookmark {
+            user_id: "user_1".into(),
+            post_id: "post_1".into(),
+        };
+
+        let like_item = like.as_attribute_key_values();
+        assert_eq!(
+            like_item.get("_type"),
+            Some(&AttributeValue::S("Like".into()))
+        );
+        let bookmark_item = bookmark.as_attribute_key_values();
+        assert_eq!(
+            bookmark_item.get("kind"),
+            Some(&AttributeValue::S("Bookmark".into()))
+        );
+
+        assert!(Like::try_from(like_item.clone()).is_ok());
+        let mut item = bookmark_item.clone();
+        item.remove("kind");
+        item.insert("_type".into(), AttributeValue::S("Bookmark".into()));
+        assert!(matches!(
+            Like::try_from(item),
+            Err(Error::EntityTypeMismatch { found: Some(x), .. }) if x == "Bookmark"
+        ));
+        assert!(matches!(
+            Bookmark::try_from(like_item.clone()),
+            Err(Error::EntityTypeMismatch { found: None, .. })
+        ));
+
+        assert!(Like::matches_item(&like_item));
+        assert!(!Like::matches_item(&bookmark_item));
+        assert!(Like::as_projection().ends_with("#v__type"));
+
+        let expression = Bookmark::entity_type_condition();
+        assert_eq!("#var_kind = :vc1", expression.to_string());
+    }
+}
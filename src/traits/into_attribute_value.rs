@@ -1,11 +1,44 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    hash::{BuildHasher, Hash},
+};
 
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::{primitives::Blob, types::AttributeValue};
+use bytes::Bytes;
 
-use crate::traits::as_attribute_key_values::AsAttributeKeyValues;
+use crate::{
+    dyno_number::DynoNumber,
+    error::Error,
+    traits::{as_attribute_key_values::AsAttributeKeyValues, dyno_value_trait::DynoValueTrait},
+};
 
 pub trait IntoAttributeValue {
     fn into_attribute_value(&self) -> AttributeValue;
+
+    /// Returns serialization errors of nested serde fields instead of panicking.
+    fn try_into_attribute_value(&self) -> Result<AttributeValue, Error> {
+        Ok(self.into_attribute_value())
+    }
+
+    /// Used by `Vec<T>`. Stored as a list by default, `u8` overrides this to store `Vec<u8>` as binary.
+    fn into_attribute_value_list(items: &[Self]) -> AttributeValue
+    where
+        Self: Sized,
+    {
+        AttributeValue::L(items.iter().map(|x| x.into_attribute_value()).collect())
+    }
+
+    fn try_into_attribute_value_list(items: &[Self]) -> Result<AttributeValue, Error>
+    where
+        Self: Sized,
+    {
+        Ok(AttributeValue::L(
+            items
+                .iter()
+                .map(|x| x.try_into_attribute_value())
+                .collect::<Result<Vec<AttributeValue>, Error>>()?,
+        ))
+    }
 }
 
 impl IntoAttributeValue for AttributeValue {
@@ -26,66 +59,184 @@ impl IntoAttributeValue for &str {
     }
 }
 
-impl IntoAttributeValue for i32 {
-    fn into_attribute_value(&self) -> AttributeValue {
-        AttributeValue::N(self.to_string())
+macro_rules! impl_into_attribute_value_for_number {
+    ($($number:ty),*) => {
+        $(
+            impl IntoAttributeValue for $number {
+                fn into_attribute_value(&self) -> AttributeValue {
+                    AttributeValue::N(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+/// Numbers that can be out of the dynamodb range, `i128` and `u128` can have more than 38 digits,
+/// floats can be `NaN`, infinite or too large or small.
+macro_rules! impl_into_attribute_value_for_checked_number {
+    ($($number:ty),*) => {
+        $(
+            impl IntoAttributeValue for $number {
+                fn into_attribute_value(&self) -> AttributeValue {
+                    AttributeValue::N(self.to_string())
+                }
+
+                fn try_into_attribute_value(&self) -> Result<AttributeValue, Error> {
+                    Ok(AttributeValue::N(checked_number(self.to_string())?))
+                }
+            }
+        )*
+    };
+}
+
+fn checked_number(number: String) -> Result<String, Error> {
+    match DynoNumber::new(&number) {
+        Ok(_) => Ok(number),
+        Err(_) => Err(Error::IntoAttributeError(format!(
+            "`{}` is not a valid dynamodb number.",
+            number
+        ))),
     }
 }
 
-impl IntoAttributeValue for u32 {
+macro_rules! impl_into_attribute_value_for_number_set {
+    ($($number:ty),*) => {
+        $(
+            impl IntoAttributeValue for HashSet<$number> {
+                fn into_attribute_value(&self) -> AttributeValue {
+                    if self.is_empty() {
+                        AttributeValue::Null(true)
+                    } else {
+                        AttributeValue::Ns(self.iter().map(|x| x.to_string()).collect())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_into_attribute_value_for_number!(i8, i16, i32, i64, isize, u16, u32, u64, usize);
+
+impl_into_attribute_value_for_checked_number!(i128, u128, f32, f64);
+
+impl IntoAttributeValue for u8 {
     fn into_attribute_value(&self) -> AttributeValue {
         AttributeValue::N(self.to_string())
     }
+
+    fn into_attribute_value_list(items: &[Self]) -> AttributeValue {
+        AttributeValue::B(Blob::new(items))
+    }
+
+    fn try_into_attribute_value_list(items: &[Self]) -> Result<AttributeValue, Error> {
+        Ok(Self::into_attribute_value_list(items))
+    }
 }
 
-impl IntoAttributeValue for f32 {
+impl IntoAttributeValue for Blob {
     fn into_attribute_value(&self) -> AttributeValue {
-        AttributeValue::N(self.to_string())
+        AttributeValue::B(self.clone())
     }
 }
 
-impl IntoAttributeValue for bool {
+impl IntoAttributeValue for Bytes {
     fn into_attribute_value(&self) -> AttributeValue {
-        AttributeValue::Bool(*self)
+        AttributeValue::B(Blob::new(self.to_vec()))
     }
 }
 
-impl IntoAttributeValue for HashSet<String> {
+impl IntoAttributeValue for HashSet<Vec<u8>> {
     fn into_attribute_value(&self) -> AttributeValue {
         if self.is_empty() {
             AttributeValue::Null(true)
         } else {
-            AttributeValue::Ss(self.iter().map(|x| x.clone()).collect())
+            AttributeValue::Bs(self.iter().map(|x| Blob::new(x.as_slice())).collect())
         }
     }
 }
 
-impl IntoAttributeValue for HashSet<i32> {
+impl_into_attribute_value_for_number_set!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_into_attribute_value_for_checked_number_set {
+    ($($number:ty),*) => {
+        $(
+            impl IntoAttributeValue for HashSet<$number> {
+                fn into_attribute_value(&self) -> AttributeValue {
+                    if self.is_empty() {
+                        AttributeValue::Null(true)
+                    } else {
+                        AttributeValue::Ns(self.iter().map(|x| x.to_string()).collect())
+                    }
+                }
+
+                fn try_into_attribute_value(&self) -> Result<AttributeValue, Error> {
+                    if self.is_empty() {
+                        return Ok(AttributeValue::Null(true));
+                    }
+                    Ok(AttributeValue::Ns(
+                        self.iter()
+                            .map(|x| checked_number(x.to_string()))
+                            .collect::<Result<Vec<String>, Error>>()?,
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_attribute_value_for_checked_number_set!(i128, u128);
+
+impl IntoAttributeValue for bool {
     fn into_attribute_value(&self) -> AttributeValue {
-        if self.is_empty() {
-            AttributeValue::Null(true)
-        } else {
-            AttributeValue::Ns(self.iter().map(|x| x.to_string()).collect())
-        }
+        AttributeValue::Bool(*self)
     }
 }
 
-impl IntoAttributeValue for HashSet<u32> {
+impl IntoAttributeValue for HashSet<String> {
     fn into_attribute_value(&self) -> AttributeValue {
         if self.is_empty() {
             AttributeValue::Null(true)
         } else {
-            AttributeValue::Ns(self.iter().map(|x| x.to_string()).collect())
+            AttributeValue::Ss(self.iter().map(|x| x.clone()).collect())
         }
     }
 }
 
+/// Sets of `DynoValue` newtypes are stored as the set of their inner values.
+impl<T, S> IntoAttributeValue for HashSet<T, S>
+where
+    T: DynoValueTrait,
+    T::Inner: Clone + Eq + Hash,
+    S: BuildHasher,
+    HashSet<T::Inner>: IntoAttributeValue,
+{
+    fn into_attribute_value(&self) -> AttributeValue {
+        inner_set(self).into_attribute_value()
+    }
+
+    fn try_into_attribute_value(&self) -> Result<AttributeValue, Error> {
+        inner_set(self).try_into_attribute_value()
+    }
+}
+
+fn inner_set<T, S>(set: &HashSet<T, S>) -> HashSet<T::Inner>
+where
+    T: DynoValueTrait,
+    T::Inner: Clone + Eq + Hash,
+{
+    set.iter().map(|x| x.as_inner().clone()).collect()
+}
+
 impl<T> IntoAttributeValue for Vec<T>
 where
     T: IntoAttributeValue,
 {
     fn into_attribute_value(&self) -> AttributeValue {
-        AttributeValue::L(self.iter().map(|x| x.into_attribute_value()).collect())
+        T::into_attribute_value_list(self)
+    }
+
+    fn try_into_attribute_value(&self) -> Result<AttributeValue, Error> {
+        T::try_into_attribute_value_list(self)
     }
 }
 
@@ -96,6 +247,10 @@ where
     fn into_attribute_value(&self) -> AttributeValue {
         AttributeValue::M(self.as_attribute_key_values())
     }
+
+    fn try_into_attribute_value(&self) -> Result<AttributeValue, Error> {
+        Ok(AttributeValue::M(self.try_as_attribute_key_values()?))
+    }
 }
 
 impl<T> IntoAttributeValue for Option<T>
@@ -108,4 +263,167 @@ where
             None => AttributeValue::Null(true),
         }
     }
+
+    fn try_into_attribute_value(&self) -> Result<AttributeValue, Error> {
+        match self {
+            Some(x) => x.try_into_attribute_value(),
+            None => Ok(AttributeValue::Null(true)),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::HashSet;
+
+    use aws_sdk_dynamodb::{primitives::Blob, types::AttributeValue};
+    use bytes::Bytes;
+    use dynorow_derive::{DynoRow, DynoValue};
+
+    use crate::{
+        self as dynorow,
+        traits::{
+            as_attribute_key_values::AsAttributeKeyValues, as_key_value::AsKeyValue,
+            into_attribute_value::IntoAttributeValue,
+        },
+    };
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, DynoValue)]
+    #[dynorow(transparent)]
+    pub struct UserId(pub String);
+
+    #[derive(Debug, Clone, Copy, PartialEq, DynoValue)]
+    #[dynorow(transparent, display, from_str)]
+    pub struct OrgId {
+        pub value: u32,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Org:{org_id}", parse_key_parts)]
+    pub struct Member {
+        #[dynorow(sk)]
+        #[dynorow(key = "sk")]
+        pub user_id: UserId,
+        pub org_id: OrgId,
+        pub invited_by: Option<UserId>,
+        pub blocked: HashSet<UserId>,
+    }
+
+    #[test]
+    pub fn test_transparent_value() {
+        let member = Member {
+            user_id: UserId("u1".into()),
+            org_id: OrgId { value: 7 },
+            invited_by: None,
+            blocked: HashSet::from([UserId("u2".into())]),
+        };
+        assert_eq!(
+            member.org_id.into_attribute_value(),
+            AttributeValue::N("7".into())
+        );
+
+        let attributes = member.as_attribute_key_values();
+        assert_eq!(
+            attributes.get("pk"),
+            Some(&AttributeValue::S("Org:7".into()))
+        );
+        assert_eq!(attributes.get("sk"), Some(&AttributeValue::S("u1".into())));
+        assert_eq!(
+            attributes.get("blocked"),
+            Some(&AttributeValue::Ss(vec!["u2".into()]))
+        );
+
+        assert_eq!(
+            Member::parse_pk_value("Org:7").unwrap().org_id,
+            member.org_id
+        );
+        let parsed = Member::try_from(attributes).unwrap();
+        assert_eq!(parsed.user_id, member.user_id);
+        assert_eq!(parsed.blocked, member.blocked);
+        assert_eq!(
+            member.as_key_value().key_value().project_key(),
+            Member::generate_composite_key(OrgId { value: 7 }, UserId("u1".into()))
+                .key_value()
+                .project_key()
+        );
+
+        let expression = Member::update_expression_builder()
+            .invited_by()
+            .set_new_value(Some(UserId("u3".into())))
+            .and(
+                Member::update_expression_builder()
+                    .blocked()
+                    .add_element(HashSet::from([UserId("u4".into())])),
+            );
+        assert_eq!(
+            expression
+                .get_expression_attribute_values()
+                .unwrap()
+                .get(":vu2"),
+            Some(&AttributeValue::Ss(vec!["u4".into()]))
+        );
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Attachment")]
+    pub struct Attachment {
+        #[dynorow(sk)]
+        pub attachment_id: String,
+        pub encrypted: Vec<u8>,
+        pub thumbnail: Bytes,
+        pub signature: Option<Blob>,
+        pub chunks: HashSet<Vec<u8>>,
+        pub sizes: Vec<u8>,
+    }
+
+    #[test]
+    pub fn test_binary_round_trip() {
+        let attachment = Attachment {
+            attachment_id: "attachment_1".into(),
+            encrypted: vec![0, 1, 255],
+            thumbnail: Bytes::from_static(b"png"),
+            signature: Some(Blob::new(vec![9])),
+            chunks: HashSet::from([vec![1], vec![2, 3]]),
+            sizes: vec![],
+        };
+
+        let attributes = attachment.as_attribute_key_values();
+        assert_eq!(
+            attributes.get("encrypted"),
+            Some(&AttributeValue::B(Blob::new(vec![0, 1, 255])))
+        );
+        assert!(attributes.get("chunks").unwrap().is_bs());
+
+        let parsed = Attachment::try_from(attributes).unwrap();
+        assert_eq!(parsed.encrypted, vec![0, 1, 255]);
+        assert_eq!(parsed.thumbnail, Bytes::from_static(b"png"));
+        assert_eq!(parsed.signature, Some(Blob::new(vec![9])));
+        assert_eq!(parsed.chunks, HashSet::from([vec![1], vec![2, 3]]));
+    }
+
+    #[test]
+    pub fn test_binary_set_update_expression() {
+        let expression = Attachment::update_expression_builder()
+            .chunks()
+            .add_element(HashSet::from([vec![4]]))
+            .and(
+                Attachment::update_expression_builder()
+                    .chunks()
+                    .delete_element(HashSet::from([vec![1]])),
+            );
+
+        assert_eq!(
+            expression.to_string(),
+            "\nADD #var_chunks :vu1\nDELETE #var_chunks :vu2"
+        );
+        assert_eq!(
+            expression
+                .get_expression_attribute_values()
+                .unwrap()
+                .get(":vu1"),
+            Some(&AttributeValue::Bs(vec![Blob::new(vec![4])]))
+        );
+    }
 }
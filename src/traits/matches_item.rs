@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::types::AttributeValue;
+
+/// Implemented by `DynoRow`, tells whether an item read from the table is a row of `Self`. <br>
+/// Static and templated pk and sk values have to match, key fields only have to be present.
+pub trait MatchesItem {
+    /// Neither the pk, the sk nor an entity type tell the row apart, any item holding the key attributes matches.
+    const MATCHES_BY_PRESENCE: bool;
+    const PARTITION_KEY: &'static str;
+    const SORT_KEY: Option<&'static str>;
+    /// The `table = ...` expression of the row, if any.
+    const TABLE_NAME_PROVIDER: Option<&'static str>;
+
+    fn matches_item(item: &HashMap<String, AttributeValue>) -> bool;
+}
+
+/// Whether two rows share their key attribute names and table name expression, as the variants of a `DynoEntity` must.
+pub const fn shares_table_key<A: MatchesItem, B: MatchesItem>() -> bool {
+    same_str(A::PARTITION_KEY, B::PARTITION_KEY)
+        && same_optional_str(A::SORT_KEY, B::SORT_KEY)
+        && same_optional_str(A::TABLE_NAME_PROVIDER, B::TABLE_NAME_PROVIDER)
+}
+
+/// Whether the table name expression of a row, when it has one, is `provider`.
+pub const fn uses_table_name_provider<A: MatchesItem>(provider: &str) -> bool {
+    match A::TABLE_NAME_PROVIDER {
+        Some(x) => same_str(x, provider),
+        None => true,
+    }
+}
+
+const fn same_optional_str(left: Option<&str>, right: Option<&str>) -> bool {
+    match (left, right) {
+        (Some(left), Some(right)) => same_str(left, right),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+const fn same_str(left: &str, right: &str) -> bool {
+    let (left, right) = (left.as_bytes(), right.as_bytes());
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < left.len() {
+        if left[i] != right[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::HashMap;
+
+    use aws_sdk_dynamodb::types::AttributeValue;
+
+    use crate::{
+        self as dynorow, DynoEntity, DynoRow, Fetchable,
+        error::Error,
+        traits::{
+            as_attribute_key_values::AsAttributeKeyValues, as_projection::AsProjection,
+            has_table_name::HasTableName, matches_item::MatchesItem,
+        },
+    };
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(table = String::from("users"), pk = "pk", pk_value = "User#{user_id}")]
+    #[dynorow(sk = "sk", sk_value = "PROFILE")]
+    pub struct Profile {
+        pub user_id: String,
+        pub name: String,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(table = String::from("users"), pk = "pk", pk_value = "User#{user_id}")]
+    #[dynorow(sk = "sk", sk_value = "ORDER#{order_id}")]
+    pub struct Order {
+        pub user_id: String,
+        pub order_id: String,
+        pub total: u32,
+    }
+
+    #[derive(Debug, Clone, DynoEntity, Fetchable)]
+    #[dynorow(table = String::from("users"))]
+    pub enum UserItem {
+        Profile(Profile),
+        Order(Order),
+    }
+
+    /// Takes the table of its variants.
+    #[derive(Debug, Clone, DynoEntity, Fetchable)]
+    pub enum OrderItem {
+        Order(Order),
+    }
+
+    #[test]
+    pub fn test_entity() {
+        let profile = Profile {
+            user_id: "user_1".into(),
+            name: "Ada".into(),
+        };
+        let order = Order {
+            user_id: "user_1".into(),
+            order_id: "order_1".into(),
+            total: 10,
+        };
+        assert!(Profile::matches_item(&profile.as_attribute_key_values()));
+        assert!(!Profile::matches_item(&order.as_attribute_key_values()));
+
+        let item = UserItem::try_from(profile.as_attribute_key_values()).unwrap();
+        assert!(matches!(item, UserItem::Profile(x) if x.name == "Ada"));
+        let item = UserItem::try_from(order.as_attribute_key_values()).unwrap();
+        assert!(matches!(item, UserItem::Order(x) if x.total == 10));
+
+        let order = Order {
+            user_id: String::new(),
+            order_id: String::new(),
+            total: 5,
+        };
+        let item = UserItem::try_from(order.as_attribute_key_values()).unwrap();
+        assert!(matches!(item, UserItem::Order(x) if x.total == 5 && x.order_id.is_empty()));
+
+        let unknown = HashMap::from([
+            ("pk".to_string(), AttributeValue::S("User#user_1".into())),
+            ("sk".to_string(), AttributeValue::S("SETTINGS".into())),
+        ]);
+        assert!(matches!(
+            UserItem::try_from(unknown),
+            Err(Error::EntityMismatch(_))
+        ));
+
+        assert_eq!(
+            UserItem::as_projection(),
+            "#v_name, #v_order_id, #v_pk, #v_sk, #v_total, #v_user_id"
+        );
+        let expression = UserItem::key_condition("User#user_1")
+            .build()
+            .into_conditional_expression();
+        assert_eq!("#var_pk = :vc1", expression.to_string());
+
+        assert_eq!(UserItem::get_table_name(), "users");
+        assert_eq!(OrderItem::get_table_name(), "users");
+    }
+}
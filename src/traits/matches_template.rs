@@ -1,45 +1,12 @@
-use crate::traits::has_pk_value_template::HasPkValueTemplate;
-
+/// Implemented by `#[derive(DynoRow)]` for templated pk values. <br>
+/// Exact match, the value has to parse into the pk value parts when `parse_pk_value` is generated.
 pub trait MatchesTemplate {
     fn matches_template(value: &str) -> bool;
 }
 
-impl<T> MatchesTemplate for T
-where
-    T: HasPkValueTemplate,
-{
-    fn matches_template(value: &str) -> bool {
-        let mut value = value;
-        let template = T::get_pk_value_template();
-        let parts = template.split("{}").collect::<Vec<&str>>();
-        if parts.is_empty() {
-            return false;
-        }
-
-        let begins_with_variable = template.starts_with("{}");
-        if !begins_with_variable {
-            if !value.starts_with(parts[0]) {
-                return false;
-            }
-        }
-
-        let ends_with_variable = template.ends_with("{}");
-        if !ends_with_variable {
-            if !value.ends_with(parts[parts.len() - 1]) {
-                return false;
-            }
-        }
-
-        for part in parts {
-            if let Some((_, rest)) = value.split_once(part) {
-                value = rest;
-            } else {
-                return false;
-            }
-        }
-
-        return true;
-    }
+/// Implemented by `#[derive(DynoRow)]` for struct level sk values, the sk counterpart of `MatchesTemplate`.
+pub trait MatchesSkTemplate {
+    fn matches_sk_template(value: &str) -> bool;
 }
 
 #[cfg(test)]
@@ -51,7 +18,7 @@ pub mod test {
     #[dynorow(pk = "pk")]
     #[dynorow(pk_value = "Order:{user_id}:{order_id}")]
     pub struct OrderPayment {
-        #[dynorow(sk = "sk")]
+        #[dynorow(sk)]
         pub payment_id: String,
         pub order_id: String,
         pub user_id: String,
@@ -69,4 +36,146 @@ pub mod test {
             "PaymentStatus:user_1234:payment_12312"
         ));
     }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Note#{folder}#{revision}", template_escape = "\\")]
+    pub struct Note {
+        #[dynorow(sk)]
+        pub note_id: String,
+        pub folder: String,
+        pub revision: u32,
+    }
+
+    #[test]
+    pub fn test_parse_pk_value() {
+        let parts = OrderPayment::parse_pk_value("Order:user_1:order_2:3").unwrap();
+        assert_eq!(parts.user_id, "user_1");
+        assert_eq!(parts.order_id, "order_2:3");
+        assert_eq!(
+            OrderPayment::parse_pk_value("Order:user_1:")
+                .unwrap()
+                .order_id,
+            ""
+        );
+        assert!(!OrderPayment::matches_template(
+            "Prefix:Order:user_1:order_2"
+        ));
+
+        let note = Note {
+            note_id: "1".into(),
+            folder: "work#2024".into(),
+            revision: 7,
+        };
+        let pk_value = note.as_pk_value().get_partition_key_value();
+        assert_eq!(pk_value.as_s().unwrap(), "Note#work\\#2024#7");
+
+        let parts = Note::parse_pk_value(pk_value.as_s().unwrap()).unwrap();
+        assert_eq!(parts.folder, note.folder);
+        assert_eq!(parts.revision, 7);
+        assert!(!Note::matches_template("Note#work#seven"));
+        assert!(!Note::matches_template("Note#work#"));
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "User#{user_id}")]
+    #[dynorow(sk = "sk", sk_value = "ORDER#{created_at}#{order_id}")]
+    pub struct Order {
+        pub user_id: String,
+        pub created_at: u64,
+        pub order_id: String,
+        pub total: u32,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "User#{user_id}")]
+    #[dynorow(sk = "sk", sk_value = "PROFILE")]
+    pub struct Profile {
+        pub user_id: String,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Tenant#{tenant_id}")]
+    #[dynorow(sk = "sk", sk_value = "Tenant#{tenant_id}#User#{user_id}")]
+    pub struct TenantUser {
+        pub tenant_id: String,
+        pub user_id: String,
+    }
+
+    #[test]
+    pub fn test_sk_value_template() {
+        use crate::traits::{
+            as_attribute_key_values::AsAttributeKeyValues, as_key_value::AsKeyValue,
+            matches_template::MatchesSkTemplate,
+        };
+
+        let order = Order {
+            user_id: "user_1".into(),
+            created_at: 1700,
+            order_id: "order_2".into(),
+            total: 10,
+        };
+        assert_eq!(order.as_sk_value(), "ORDER#00000000000000001700#order_2");
+        assert_eq!(
+            Order::generate_sk_value(1700, "order_2"),
+            "ORDER#00000000000000001700#order_2"
+        );
+
+        let attributes = order.as_attribute_key_values();
+        assert_eq!(
+            attributes.get("sk").unwrap().as_s().unwrap(),
+            "ORDER#00000000000000001700#order_2"
+        );
+
+        let key_value = order.as_key_value().into_key_value();
+        assert_eq!(
+            key_value.get_sort_key_value().unwrap().as_s().unwrap(),
+            "ORDER#00000000000000001700#order_2"
+        );
+        assert!(key_value.matches_sk_template::<Order>());
+        let composite_key =
+            Order::generate_composite_key("user_1", 1700, "order_2").into_key_value();
+        assert_eq!(
+            composite_key.get_partition_key_value().as_s().unwrap(),
+            "User#user_1"
+        );
+        assert_eq!(
+            composite_key.get_sort_key_value().unwrap().as_s().unwrap(),
+            "ORDER#00000000000000001700#order_2"
+        );
+
+        let parts = Order::parse_sk_value("ORDER#00000000000000001700#order_2").unwrap();
+        assert_eq!(parts.created_at, 1700);
+        assert_eq!(parts.order_id, "order_2");
+        assert!(!Order::matches_sk_template("ORDER#today#order_2"));
+        assert!(Order::generate_sk_value(9, "order_2") < Order::generate_sk_value(10, "order_2"));
+
+        let expression = Order::sk_begins_with_created_at(1700);
+        assert_eq!("begins_with(#var_sk, :vc1)", expression.to_string());
+        let attribute_values = expression.get_expression_attribute_values().unwrap();
+        assert_eq!(
+            attribute_values.get(":vc1").unwrap().as_s().unwrap(),
+            "ORDER#00000000000000001700#"
+        );
+        let attribute_values = Order::sk_begins_with()
+            .get_expression_attribute_values()
+            .unwrap();
+        assert_eq!(
+            attribute_values.get(":vc1").unwrap().as_s().unwrap(),
+            "ORDER#"
+        );
+
+        let key_value = Profile::generate_composite_key("user_1").into_key_value();
+        assert_eq!(
+            key_value.get_sort_key_value().unwrap().as_s().unwrap(),
+            "PROFILE"
+        );
+        assert!(Profile::matches_sk_template("PROFILE"));
+
+        let key_value = TenantUser::generate_composite_key("tenant_1", "user_1").into_key_value();
+        assert_eq!(
+            key_value.get_sort_key_value().unwrap().as_s().unwrap(),
+            "Tenant#tenant_1#User#user_1"
+        );
+    }
 }
@@ -1,10 +1,14 @@
 pub mod as_attribute_key_values;
 pub mod as_key_value;
 pub mod as_projection;
+pub mod collection;
 pub mod deletable;
+pub mod dyno_enum_trait;
 pub mod dyno_map_trait;
+pub mod dyno_value_trait;
 pub mod fetchable;
 pub mod from_attribute_value;
+pub mod has_entity_type;
 pub mod has_key;
 pub mod has_pk_value;
 pub mod has_pk_value_template;
@@ -12,8 +16,14 @@ pub mod has_sort_key;
 pub mod has_table_name;
 pub mod insertable;
 pub mod into_attribute_value;
+pub mod matches_item;
 pub mod matches_template;
+pub mod numeric;
+pub mod parse_pk_value;
+pub mod parse_sk_value;
 pub mod pk_equals;
+pub mod projection_of;
+pub mod scalar_attribute;
 pub mod serde_dynamo_attribute_value_into;
 pub mod try_into_row;
 pub mod updatable;
@@ -0,0 +1,153 @@
+use std::fmt::Display;
+
+use crate::traits::{
+    from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+};
+
+/// Implemented by every type that is stored as a dynamodb number (`N`). <br>
+/// Enables the arithmetic functions of `UpdateExpressionBuilder`.
+pub trait Numeric: IntoAttributeValue + FromAttributeValue<Self> + Display + Sized {
+    /// Number with its sign flipped, as a dynamodb number attribute value.
+    fn negated_attribute_value(&self) -> aws_sdk_dynamodb::types::AttributeValue {
+        negate_number(&self.to_string())
+    }
+
+    /// Same as `negated_attribute_value`, with the range checks of `try_into_attribute_value`.
+    fn try_negated_attribute_value(
+        &self,
+    ) -> Result<aws_sdk_dynamodb::types::AttributeValue, crate::error::Error> {
+        match self.try_into_attribute_value()? {
+            aws_sdk_dynamodb::types::AttributeValue::N(number) => Ok(negate_number(&number)),
+            _ => Ok(self.negated_attribute_value()),
+        }
+    }
+}
+
+fn negate_number(number: &str) -> aws_sdk_dynamodb::types::AttributeValue {
+    match number.strip_prefix('-') {
+        Some(positive) => aws_sdk_dynamodb::types::AttributeValue::N(positive.into()),
+        None => aws_sdk_dynamodb::types::AttributeValue::N(format!(
+            "-{}",
+            number.strip_prefix('+').unwrap_or(number)
+        )),
+    }
+}
+
+macro_rules! impl_numeric {
+    ($($number:ty),*) => {
+        $(
+            impl Numeric for $number {}
+        )*
+    };
+}
+
+impl_numeric!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::{HashMap, HashSet};
+
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use dynorow_derive::DynoRow;
+
+    use crate::{
+        self as dynorow,
+        traits::{
+            as_attribute_key_values::AsAttributeKeyValues,
+            from_attribute_value::FromAttributeValue, into_attribute_value::IntoAttributeValue,
+        },
+    };
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Account")]
+    pub struct Account {
+        #[dynorow(sk)]
+        pub account_id: String,
+        pub balance: i64,
+        pub updated_on: u64,
+        pub rate: f64,
+        pub flags: u8,
+        pub seen_on: HashSet<u64>,
+    }
+
+    #[test]
+    pub fn test_numeric_round_trip() {
+        let account = Account {
+            account_id: "account_1".into(),
+            balance: i64::MIN,
+            updated_on: u64::MAX,
+            rate: 0.25,
+            flags: 3,
+            seen_on: HashSet::from([1, 2]),
+        };
+
+        let attributes: HashMap<String, AttributeValue> = account.as_attribute_key_values();
+        assert_eq!(
+            attributes.get("updated_on").unwrap().as_n().unwrap(),
+            &u64::MAX.to_string()
+        );
+
+        let parsed = Account::try_from(attributes).unwrap();
+        assert_eq!(parsed.balance, i64::MIN);
+        assert_eq!(parsed.updated_on, u64::MAX);
+        assert_eq!(parsed.rate, 0.25);
+        assert_eq!(parsed.seen_on, HashSet::from([1, 2]));
+
+        assert!(u8::from_attribute_value(AttributeValue::N("256".into())).is_err());
+    }
+
+    #[test]
+    pub fn test_numeric_update_expression() {
+        let expression = Account::update_expression_builder()
+            .balance()
+            .add_decrement(-5)
+            .and(
+                Account::update_expression_builder()
+                    .rate()
+                    .set_increment(0.5),
+            );
+
+        assert_eq!(
+            expression.to_string(),
+            "\nSET #var_rate = #var_rate + :vu1\nADD #var_balance :vu2"
+        );
+
+        let attribute_values = expression.get_expression_attribute_values().unwrap();
+        assert_eq!(attribute_values.get(":vu1").unwrap().as_n().unwrap(), "0.5");
+        assert_eq!(attribute_values.get(":vu2").unwrap().as_n().unwrap(), "5");
+    }
+
+    #[test]
+    pub fn test_numbers_out_of_dynamodb_range() {
+        assert!(f64::NAN.try_into_attribute_value().is_err());
+        assert!(f64::INFINITY.try_into_attribute_value().is_err());
+        assert!(f32::NEG_INFINITY.try_into_attribute_value().is_err());
+        assert!(1e300_f64.try_into_attribute_value().is_err());
+        assert!(0.25_f64.try_into_attribute_value().is_ok());
+        assert!(i128::MAX.try_into_attribute_value().is_err());
+        assert!(u128::MAX.try_into_attribute_value().is_err());
+        assert!(10_u128.pow(38).try_into_attribute_value().is_ok());
+        assert!(
+            HashSet::from([i128::MIN])
+                .try_into_attribute_value()
+                .is_err()
+        );
+
+        let expression = Account::update_expression_builder()
+            .rate()
+            .set_new_value(f64::NAN);
+        assert!(expression.get_expression_attribute_values().is_err());
+
+        let expression = Account::update_expression_builder()
+            .rate()
+            .add_increment(f64::INFINITY);
+        assert!(expression.get_expression_attribute_values().is_err());
+        let expression = Account::update_expression_builder()
+            .rate()
+            .add_decrement(f64::NAN);
+        assert!(expression.get_expression_attribute_values().is_err());
+    }
+}
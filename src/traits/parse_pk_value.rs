@@ -0,0 +1,9 @@
+use crate::error::Error;
+
+/// Implemented by `#[derive(DynoRow)]` for templated pk values, the inverse of `generate_pk_value`.
+pub trait ParsePkValue: Sized {
+    /// Generated struct with one field per placeholder.
+    type Parts;
+
+    fn parse_pk_value(value: &str) -> Result<Self::Parts, Error>;
+}
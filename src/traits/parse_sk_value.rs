@@ -0,0 +1,9 @@
+use crate::error::Error;
+
+/// Implemented by `#[derive(DynoRow)]` for struct level sk values, the inverse of `generate_sk_value`.
+pub trait ParseSkValue: Sized {
+    /// Generated struct with one field per placeholder.
+    type Parts;
+
+    fn parse_sk_value(value: &str) -> Result<Self::Parts, Error>;
+}
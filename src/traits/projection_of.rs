@@ -0,0 +1,188 @@
+use crate::{
+    key::Key,
+    traits::{
+        has_entity_type::HasEntityType, has_key::HasKey, has_pk_value::HasStaticPkValue,
+        has_sort_key::HasSortKey, has_table_name::HasTableName,
+    },
+};
+
+/// Implemented by `DynoProjection`, the projection reads a subset of the attributes of `Parent`. <br>
+/// Table name, key and entity type are the ones of the parent.
+pub trait ProjectionOf {
+    type Parent;
+}
+
+impl<P> HasTableName for P
+where
+    P: ProjectionOf,
+    P::Parent: HasTableName,
+{
+    fn get_table_name() -> String {
+        P::Parent::get_table_name()
+    }
+}
+
+impl<P> HasKey for P
+where
+    P: ProjectionOf,
+    P::Parent: HasKey,
+{
+    fn get_key() -> Key {
+        P::Parent::get_key()
+    }
+}
+
+impl<P> HasSortKey for P
+where
+    P: ProjectionOf,
+    P::Parent: HasSortKey,
+{
+    fn get_sort_key() -> String {
+        P::Parent::get_sort_key()
+    }
+}
+
+impl<P> HasStaticPkValue for P
+where
+    P: ProjectionOf,
+    P::Parent: HasStaticPkValue,
+{
+    fn get_static_pk_value() -> String {
+        P::Parent::get_static_pk_value()
+    }
+}
+
+impl<P> HasEntityType for P
+where
+    P: ProjectionOf,
+    P::Parent: HasEntityType,
+{
+    fn get_entity_type_attribute() -> String {
+        P::Parent::get_entity_type_attribute()
+    }
+
+    fn get_entity_type() -> String {
+        P::Parent::get_entity_type()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::HashMap;
+
+    use crate::{
+        self as dynorow, DynoProjection, DynoRow, Fetchable,
+        error::Error,
+        key::TypedKey,
+        traits::{
+            as_attribute_key_values::AsAttributeKeyValues, as_projection::AsProjection,
+            has_entity_type::HasEntityType, has_key::HasKey, has_table_name::HasTableName,
+        },
+    };
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(table = String::from("users"), pk = "pk", pk_value = "User")]
+    pub struct User {
+        #[dynorow(sk)]
+        pub email_address: String,
+        #[dynorow(key = "retry")]
+        pub retry_count: i32,
+        pub tags: Vec<String>,
+        pub biography: String,
+    }
+
+    #[derive(Debug, Clone, DynoProjection, Fetchable)]
+    #[dynorow(of = User)]
+    pub struct UserSummary {
+        pub retry_count: i32,
+        pub tags: Vec<String>,
+    }
+
+    #[test]
+    pub fn test_projection() {
+        assert_eq!(UserSummary::as_projection(), "#v_retry, #v_tags");
+        assert_eq!(
+            UserSummary::as_projection_names(),
+            HashMap::from([
+                ("#v_retry".to_string(), "retry".to_string()),
+                ("#v_tags".to_string(), "tags".to_string()),
+            ])
+        );
+        assert_eq!(UserSummary::get_table_name(), "users");
+        assert_eq!(
+            UserSummary::get_key().get_sort_key().unwrap(),
+            "email_address"
+        );
+
+        let user = User {
+            email_address: "a@b.com".into(),
+            retry_count: 3,
+            tags: vec!["admin".into()],
+            biography: "long text".into(),
+        };
+        let summary = UserSummary::try_from(user.as_attribute_key_values()).unwrap();
+        assert_eq!(summary.retry_count, 3);
+        assert_eq!(summary.tags, user.tags);
+
+        let key: TypedKey<UserSummary> = TypedKey::from(&user).into_projection();
+        assert_eq!(
+            key.key_value()
+                .get_sort_key_value()
+                .unwrap()
+                .as_s()
+                .unwrap(),
+            "a@b.com"
+        );
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Post#{post_id}", entity_type = "Like")]
+    pub struct Like {
+        pub post_id: String,
+        #[dynorow(sk)]
+        pub user_id: String,
+        pub created_on: u64,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk", pk_value = "Post#{post_id}", entity_type = "Share")]
+    pub struct Share {
+        pub post_id: String,
+        #[dynorow(sk)]
+        pub user_id: String,
+        pub created_on: u64,
+    }
+
+    #[derive(Debug, Clone, DynoProjection)]
+    #[dynorow(of = Like)]
+    pub struct LikeDate {
+        pub created_on: u64,
+    }
+
+    #[test]
+    pub fn test_projection_entity_type() {
+        assert_eq!(LikeDate::as_projection(), "#v_created_on, #v__type");
+        assert_eq!(
+            LikeDate::entity_type_condition().to_string(),
+            Like::entity_type_condition().to_string()
+        );
+
+        // Both rows share the partition and the projected field.
+        let like = Like {
+            post_id: "post_1".into(),
+            user_id: "user_1".into(),
+            created_on: 1,
+        };
+        let share = Share {
+            post_id: "post_1".into(),
+            user_id: "user_2".into(),
+            created_on: 2,
+        };
+        let date = LikeDate::try_from(like.as_attribute_key_values()).unwrap();
+        assert_eq!(date.created_on, 1);
+        assert!(matches!(
+            LikeDate::try_from(share.as_attribute_key_values()),
+            Err(Error::EntityTypeMismatch { found: Some(x), .. }) if x == "Share"
+        ));
+    }
+}
@@ -0,0 +1,55 @@
+use aws_sdk_dynamodb::{primitives::Blob, types::ScalarAttributeType};
+use bytes::Bytes;
+
+use crate::{DynoNumber, traits::into_attribute_value::IntoAttributeValue};
+
+/// `ScalarAttributeType` of a value stored as a key attribute. <br>
+/// `DynoRow` reads it for key fields of types it cannot tell from the type name,
+/// `#[derive(DynoValue)]` takes the one of the inner value and `#[derive(DynoEnum)]` is a string.
+pub trait ScalarAttribute {
+    const SCALAR_ATTRIBUTE_TYPE: ScalarAttributeType;
+}
+
+/// Implemented by the key types stored as strings or binary, the ones `begins_with` applies to. <br>
+/// `Prefix` is the value compared with the start of the key, a string or bytes.
+pub trait SortKeyPrefix {
+    type Prefix: IntoAttributeValue;
+}
+
+impl ScalarAttribute for String {
+    const SCALAR_ATTRIBUTE_TYPE: ScalarAttributeType = ScalarAttributeType::S;
+}
+
+impl SortKeyPrefix for String {
+    type Prefix = String;
+}
+
+macro_rules! impl_scalar_attribute_for_number {
+    ($($number:ty),*) => {
+        $(
+            impl ScalarAttribute for $number {
+                const SCALAR_ATTRIBUTE_TYPE: ScalarAttributeType = ScalarAttributeType::N;
+            }
+        )*
+    };
+}
+
+impl_scalar_attribute_for_number!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, DynoNumber
+);
+
+macro_rules! impl_scalar_attribute_for_binary {
+    ($($binary:ty),*) => {
+        $(
+            impl ScalarAttribute for $binary {
+                const SCALAR_ATTRIBUTE_TYPE: ScalarAttributeType = ScalarAttributeType::B;
+            }
+
+            impl SortKeyPrefix for $binary {
+                type Prefix = Vec<u8>;
+            }
+        )*
+    };
+}
+
+impl_scalar_attribute_for_binary!(Vec<u8>, Bytes, Blob);
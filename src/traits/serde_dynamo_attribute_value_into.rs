@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use aws_sdk_dynamodb::{primitives::Blob, types::AttributeValue};
 
+use crate::error::Error;
+
 pub trait SerdeDynamoAttributeValueInto {
     fn into_aws_attribute_value(self) -> aws_sdk_dynamodb::types::AttributeValue;
 }
@@ -49,3 +51,184 @@ impl SerdeDynamoAttributeValueHashMapInto for HashMap<String, serde_dynamo::Attr
         return result;
     }
 }
+
+pub trait AwsAttributeValueInto {
+    fn into_serde_dynamo_attribute_value(self) -> Result<serde_dynamo::AttributeValue, Error>;
+}
+
+impl AwsAttributeValueInto for AttributeValue {
+    fn into_serde_dynamo_attribute_value(self) -> Result<serde_dynamo::AttributeValue, Error> {
+        let result = match self {
+            AttributeValue::N(x) => serde_dynamo::AttributeValue::N(x),
+            AttributeValue::S(x) => serde_dynamo::AttributeValue::S(x),
+            AttributeValue::Bool(x) => serde_dynamo::AttributeValue::Bool(x),
+            AttributeValue::B(blob) => serde_dynamo::AttributeValue::B(blob.into_inner()),
+            AttributeValue::Null(x) => serde_dynamo::AttributeValue::Null(x),
+            AttributeValue::M(hash_map) => {
+                let mut result: HashMap<String, serde_dynamo::AttributeValue> = HashMap::new();
+                for item in hash_map {
+                    result.insert(item.0, item.1.into_serde_dynamo_attribute_value()?);
+                }
+                serde_dynamo::AttributeValue::M(result)
+            }
+            AttributeValue::L(attribute_values) => serde_dynamo::AttributeValue::L(
+                attribute_values
+                    .into_iter()
+                    .map(|x| x.into_serde_dynamo_attribute_value())
+                    .collect::<Result<Vec<serde_dynamo::AttributeValue>, Error>>()?,
+            ),
+            AttributeValue::Ss(items) => serde_dynamo::AttributeValue::Ss(items),
+            AttributeValue::Ns(items) => serde_dynamo::AttributeValue::Ns(items),
+            AttributeValue::Bs(items) => serde_dynamo::AttributeValue::Bs(
+                items.into_iter().map(|x| x.into_inner()).collect(),
+            ),
+            _ => {
+                return Err(Error::parse_error(
+                    self,
+                    "serde_dynamo::AttributeValue",
+                    String::from("Unknown attribute value type."),
+                ));
+            }
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::HashMap;
+
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use dynorow_derive::{DynoMap, DynoRow};
+    use serde::{Deserialize, Serialize, Serializer};
+
+    use crate::{
+        self as dynorow, BuildConditionalExpression, error::Error,
+        traits::as_attribute_key_values::AsAttributeKeyValues,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Preferences {
+        pub theme: String,
+        pub font_size: u32,
+        pub languages: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    pub struct Unserializable;
+
+    impl Serialize for Unserializable {
+        fn serialize<S: Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("Cannot serialize."))
+        }
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Profile")]
+    pub struct Profile {
+        #[dynorow(sk)]
+        pub profile_id: String,
+        #[dynorow(serde_dynamo)]
+        pub preferences: Preferences,
+        #[dynorow(serde_dynamo)]
+        pub previous_preferences: Option<Preferences>,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Broken")]
+    pub struct Broken {
+        #[dynorow(sk)]
+        pub broken_id: String,
+        #[dynorow(serde)]
+        pub value: Unserializable,
+    }
+
+    #[derive(Debug, Clone, DynoMap)]
+    pub struct BrokenSettings {
+        #[dynorow(serde)]
+        pub value: Unserializable,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Holder")]
+    pub struct Holder {
+        #[dynorow(sk)]
+        pub holder_id: String,
+        pub settings: BrokenSettings,
+    }
+
+    #[test]
+    pub fn test_serde_dynamo_is_stored_natively() {
+        let profile = Profile {
+            profile_id: "1".into(),
+            preferences: Preferences {
+                theme: "dark".into(),
+                font_size: 14,
+                languages: vec!["en".into()],
+            },
+            previous_preferences: None,
+        };
+
+        let items = profile.try_as_attribute_key_values().unwrap();
+        let preferences = items["preferences"].as_m().unwrap();
+        assert_eq!(preferences["theme"], AttributeValue::S("dark".into()));
+        assert_eq!(preferences["font_size"], AttributeValue::N("14".into()));
+        assert_eq!(
+            preferences["languages"],
+            AttributeValue::L(vec![AttributeValue::S("en".into())])
+        );
+        assert_eq!(items["previous_preferences"], AttributeValue::Null(true));
+
+        let parsed = Profile::try_from(items).unwrap();
+        assert_eq!(parsed.preferences, profile.preferences);
+        assert_eq!(parsed.previous_preferences, None);
+
+        let items = HashMap::from([
+            ("profile_id".to_string(), AttributeValue::S("1".into())),
+            ("preferences".to_string(), AttributeValue::S("dark".into())),
+        ]);
+        assert!(matches!(
+            Profile::try_from(items),
+            Err(Error::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    pub fn test_serialization_error_is_returned() {
+        let broken = Broken {
+            broken_id: "1".into(),
+            value: Unserializable,
+        };
+        assert!(matches!(
+            broken.try_as_attribute_key_values(),
+            Err(Error::IntoAttributeError(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_serialization_error_is_returned_by_expressions() {
+        let settings = BrokenSettings {
+            value: Unserializable,
+        };
+
+        let update = Holder::update_expression_builder()
+            .settings()
+            .set_new_value(settings.clone());
+        assert_eq!(update.to_string(), "\nSET #var_settings = :vu1");
+        assert!(matches!(
+            update.get_expression_attribute_values(),
+            Err(Error::IntoAttributeError(_))
+        ));
+
+        let condition = Holder::conditional_expression_builder()
+            .settings()
+            .equals(settings);
+        assert!(matches!(
+            condition.get_expression_attribute_values(),
+            Err(Error::IntoAttributeError(_))
+        ));
+    }
+}
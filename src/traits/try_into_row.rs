@@ -8,4 +8,77 @@ impl TryIntoRow for GetItemOutput {
     fn try_into_row<T: TryFrom<GetItemOutput>>(self) -> Result<T, T::Error> {
         T::try_from(self)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+pub mod test {
+    use std::collections::HashMap;
+
+    use aws_sdk_dynamodb::{operation::get_item::GetItemOutput, types::AttributeValue};
+
+    use crate::{
+        self as dynorow, DynoMap, DynoRow, error::Error, traits::try_into_row::TryIntoRow,
+    };
+
+    fn default_plan() -> String {
+        String::from("free")
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, DynoMap)]
+    #[dynorow(default)]
+    pub struct Limits {
+        pub seats: u32,
+        pub storage: u64,
+    }
+
+    #[derive(Debug, Clone, DynoRow)]
+    #[dynorow(pk = "pk")]
+    #[dynorow(pk_value = "Account")]
+    pub struct Account {
+        #[dynorow(sk)]
+        pub account_id: String,
+        #[dynorow(default = default_plan)]
+        pub plan: String,
+        #[dynorow(default)]
+        pub tags: Vec<String>,
+        #[dynorow(default)]
+        pub limits: Limits,
+        pub region: String,
+    }
+
+    #[test]
+    pub fn test_missing_attributes_use_defaults() {
+        let items = HashMap::from([
+            ("account_id".to_string(), AttributeValue::S("1".into())),
+            ("region".to_string(), AttributeValue::S("eu".into())),
+            (
+                "limits".to_string(),
+                AttributeValue::M(HashMap::from([(
+                    "seats".to_string(),
+                    AttributeValue::N("3".into()),
+                )])),
+            ),
+        ]);
+
+        let account: Account = GetItemOutput::builder()
+            .set_item(Some(items.clone()))
+            .build()
+            .try_into_row()
+            .unwrap();
+        assert_eq!(account.plan, "free");
+        assert!(account.tags.is_empty());
+        assert_eq!(
+            account.limits,
+            Limits {
+                seats: 3,
+                storage: 0
+            }
+        );
+
+        let mut items = items;
+        items.remove("region");
+        assert!(matches!(
+            Account::try_from(items),
+            Err(Error::ValueNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,10 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk", pk_value = "Like", attr = "kind")]
+pub struct Like {
+    #[dynorow(sk)]
+    pub post_id: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use dynorow::DynoRow;
+
+#[derive(Clone, Debug, DynoRow)]
+#[dynorow(pk = "pk", pk_value = "Secret")]
+pub struct Secret {
+    #[dynorow(sk)]
+    pub secret_id: String,
+    pub encrypted: Vec<u8>,
+}
+
+fn main() {
+    let _ = Secret::update_expression_builder()
+        .encrypted()
+        .set_list_append(vec![1u8]);
+}
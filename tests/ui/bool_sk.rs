@@ -0,0 +1,10 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk", pk_value = "Flag")]
+pub struct Flag {
+    #[dynorow(sk)]
+    pub enabled: bool,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "User")]
+pub struct User {
+    #[dynorow(sk)]
+    pub user_id: String,
+    pub name: String,
+    #[dynorow(key = "name")]
+    pub display_name: String,
+}
+
+fn main() {}
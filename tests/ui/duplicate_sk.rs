@@ -0,0 +1,13 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "User")]
+pub struct User {
+    #[dynorow(sk)]
+    pub user_id: String,
+    #[dynorow(sk)]
+    pub email: String,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use dynorow::{DynoEntity, DynoRow};
+
+#[derive(DynoRow)]
+#[dynorow(table = String::from("users"), pk = "pk", pk_value = "User#{user_id}")]
+#[dynorow(sk = "sk", sk_value = "PROFILE")]
+pub struct Profile {
+    pub user_id: String,
+}
+
+#[derive(DynoRow)]
+#[dynorow(table = String::from("users"), pk = "PK", pk_value = "User#{user_id}")]
+#[dynorow(sk = "SK", sk_value = "ORDER#{order_id}")]
+pub struct Order {
+    pub user_id: String,
+    pub order_id: String,
+}
+
+#[derive(DynoEntity)]
+pub enum UserItem {
+    Profile(Profile),
+    Order(Order),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use dynorow::DynoEntity;
+
+#[derive(DynoEntity)]
+pub enum UserItem {
+    Profile { name: String },
+}
+
+fn main() {}
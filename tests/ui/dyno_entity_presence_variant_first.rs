@@ -0,0 +1,25 @@
+use dynorow::{DynoEntity, DynoRow};
+
+#[derive(DynoRow)]
+#[dynorow(table = String::from("users"))]
+pub struct Raw {
+    #[dynorow(pk)]
+    pub pk: String,
+    #[dynorow(sk)]
+    pub sk: String,
+}
+
+#[derive(DynoRow)]
+#[dynorow(table = String::from("users"), pk = "pk", pk_value = "User#{user_id}")]
+#[dynorow(sk = "sk", sk_value = "PROFILE")]
+pub struct Profile {
+    pub user_id: String,
+}
+
+#[derive(DynoEntity)]
+pub enum UserItem {
+    Raw(Raw),
+    Profile(Profile),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dynorow::DynoEnum;
+
+#[derive(DynoEnum)]
+pub enum Status {
+    #[dynorow(renamed = "ACTIVE")]
+    Active,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use dynorow::DynoValue;
+
+#[derive(DynoValue)]
+pub struct UserId(pub String);
+
+fn main() {}
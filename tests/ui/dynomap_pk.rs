@@ -0,0 +1,9 @@
+use dynorow::DynoMap;
+
+#[derive(DynoMap)]
+#[dynorow(pk = "pk")]
+pub struct Address {
+    pub city: String,
+}
+
+fn main() {}
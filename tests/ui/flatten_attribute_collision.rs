@@ -0,0 +1,27 @@
+use dynorow::{DynoMap, DynoRow};
+
+#[derive(Clone, Debug, DynoMap)]
+pub struct Audit {
+    pub created_by: String,
+    #[dynorow(key = "title")]
+    pub audit_title: String,
+}
+
+#[derive(Clone, Debug, DynoMap)]
+pub struct Owner {
+    pub created_by: String,
+}
+
+#[derive(Clone, Debug, DynoRow)]
+#[dynorow(pk = "pk", pk_value = "Document")]
+pub struct Document {
+    #[dynorow(sk)]
+    pub document_id: String,
+    pub title: String,
+    #[dynorow(flatten)]
+    pub audit: Audit,
+    #[dynorow(flatten)]
+    pub owner: Owner,
+}
+
+fn main() {}
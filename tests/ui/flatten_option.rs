@@ -0,0 +1,18 @@
+use dynorow::{DynoMap, DynoRow};
+
+#[derive(DynoMap)]
+pub struct Audit {
+    pub created_by: String,
+}
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "Document")]
+pub struct Document {
+    #[dynorow(sk)]
+    pub document_id: String,
+    #[dynorow(flatten)]
+    pub audit: Option<Audit>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dynorow::DynoRow;
+
+#[derive(Debug, Clone, DynoRow)]
+#[dynorow(pk = "pk", pk_value = "User#{user_id}")]
+#[dynorow(key_name = "User Key")]
+pub struct User {
+    pub user_id: String,
+}
+
+fn main() {}
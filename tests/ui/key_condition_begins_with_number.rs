@@ -0,0 +1,12 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk", pk_value = "Score")]
+pub struct Score {
+    #[dynorow(sk)]
+    pub points: u32,
+}
+
+fn main() {
+    let _ = Score::key_condition().sort_key_begins_with("1");
+}
@@ -0,0 +1,9 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+pub struct User {
+    #[dynorow(sk)]
+    pub user_id: String,
+}
+
+fn main() {}
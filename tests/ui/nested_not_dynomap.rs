@@ -0,0 +1,18 @@
+use dynorow::{DynoEnum, DynoRow};
+
+#[derive(Clone, Debug, PartialEq, DynoEnum)]
+pub enum Status {
+    Active,
+    Closed,
+}
+
+#[derive(Clone, Debug, DynoRow)]
+#[dynorow(pk = "pk", pk_value = "Account")]
+pub struct Account {
+    #[dynorow(sk)]
+    pub account_id: String,
+    #[dynorow(nested)]
+    pub status: Status,
+}
+
+fn main() {}
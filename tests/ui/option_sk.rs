@@ -0,0 +1,11 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "User")]
+pub struct User {
+    #[dynorow(sk)]
+    pub user_id: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+use dynorow::{
+    DynoRow,
+    traits::{
+        into_attribute_value::IntoAttributeValue,
+        matches_template::{MatchesSkTemplate, MatchesTemplate},
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct Region(pub String);
+
+impl Display for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl IntoAttributeValue for Region {
+    fn into_attribute_value(&self) -> dynorow::aws_sdk_dynamodb::types::AttributeValue {
+        self.0.into_attribute_value()
+    }
+}
+
+impl dynorow::traits::from_attribute_value::FromAttributeValue<Region> for Region {
+    fn from_attribute_value(
+        value: dynorow::aws_sdk_dynamodb::types::AttributeValue,
+    ) -> Result<Region, dynorow::error::Error> {
+        String::from_attribute_value(value).map(Region)
+    }
+}
+
+#[derive(Debug, Clone, DynoRow)]
+#[dynorow(pk = "pk", pk_value = "Store#{region}")]
+#[dynorow(sk = "sk", sk_value = "SHELF#{region}#{shelf}")]
+pub struct Shelf {
+    pub region: Region,
+    pub shelf: u32,
+}
+
+fn main() {
+    assert!(Shelf::matches_template("Store#eu"));
+    assert!(!Shelf::matches_template("Shop#eu"));
+    assert!(Shelf::matches_sk_template("SHELF#eu#3"));
+}
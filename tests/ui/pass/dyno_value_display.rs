@@ -0,0 +1,40 @@
+use std::{fmt::Display, str::FromStr};
+
+use dynorow::DynoValue;
+
+#[derive(Debug, Clone, DynoValue)]
+#[dynorow(transparent)]
+pub struct Email(pub String);
+
+impl Display for Email {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}>", self.0)
+    }
+}
+
+impl FromStr for Email {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.strip_prefix('<').and_then(|x| x.strip_suffix('>')) {
+            Some(email) => Ok(Email(email.into())),
+            None => Err(String::from("Expected <email>.")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, DynoValue)]
+#[dynorow(transparent)]
+pub struct Nickname(pub Option<String>);
+
+#[derive(Debug, Clone, DynoValue)]
+#[dynorow(transparent, display, from_str)]
+pub struct AccountId(pub u64);
+
+fn main() {
+    assert_eq!(Email("a@b.c".into()).to_string(), "<a@b.c>");
+    assert_eq!(AccountId(7).to_string(), "7");
+    assert_eq!("<a@b.c>".parse::<Email>().unwrap().0, "a@b.c");
+    assert_eq!("7".parse::<AccountId>().unwrap().0, 7);
+    let _ = Nickname(None);
+}
@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+use dynorow::{
+    DynoRow, DynoValue,
+    traits::{
+        as_attribute_key_values::AsAttributeKeyValues, into_attribute_value::IntoAttributeValue,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, DynoValue)]
+#[dynorow(transparent)]
+pub struct UserId(pub String);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, DynoValue)]
+#[dynorow(transparent)]
+pub struct Score(pub u32);
+
+#[derive(Debug, Clone, DynoRow)]
+#[dynorow(pk = "pk", pk_value = "Team")]
+pub struct Team {
+    #[dynorow(sk)]
+    pub team_id: String,
+    pub members: HashSet<UserId>,
+    pub scores: HashSet<Score>,
+}
+
+fn main() {
+    let team = Team {
+        team_id: "t1".into(),
+        members: HashSet::from([UserId("u1".into())]),
+        scores: HashSet::from([Score(3)]),
+    };
+    let attributes = team.as_attribute_key_values();
+    assert_eq!(
+        attributes.get("members"),
+        Some(&team.members.into_attribute_value())
+    );
+    let team = Team::try_from(attributes).unwrap();
+    assert!(team.scores.contains(&Score(3)));
+}
@@ -0,0 +1,39 @@
+use dynorow::{DynoRow, key::TypedKey};
+
+/// Types of the application that would clash with the generated names.
+pub struct OrderKey;
+pub struct OrderPkParts;
+pub struct OrderSkParts;
+
+#[derive(Debug, Clone, DynoRow)]
+#[dynorow(pk = "pk", pk_value = "User#{user_id}")]
+#[dynorow(sk = "sk", sk_value = "ORDER#{order_id}")]
+#[dynorow(key_name = "OrderRowKey", pk_parts_name = "OrderPk", sk_parts_name = "OrderSk")]
+pub struct Order {
+    pub user_id: String,
+    pub order_id: String,
+}
+
+fn main() {
+    let _ = (OrderKey, OrderPkParts, OrderSkParts);
+
+    let key: TypedKey<Order> = OrderRowKey {
+        user_id: "u1".into(),
+        order_id: "o1".into(),
+    }
+    .into();
+    let generated: TypedKey<Order> = Order::generate_composite_key("u1", "o1");
+    assert_eq!(
+        key.key_value().get_partition_key_value(),
+        generated.key_value().get_partition_key_value()
+    );
+    assert_eq!(
+        key.key_value().get_sort_key_value(),
+        generated.key_value().get_sort_key_value()
+    );
+
+    let OrderPk { user_id } = Order::parse_pk_value("User#u1").unwrap();
+    assert_eq!(user_id, "u1");
+    let OrderSk { order_id } = Order::parse_sk_value("ORDER#o1").unwrap();
+    assert_eq!(order_id, "o1");
+}
@@ -0,0 +1,9 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+pub struct User {
+    #[dynorow(pk, sk)]
+    pub user_id: String,
+}
+
+fn main() {}
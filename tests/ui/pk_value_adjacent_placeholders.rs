@@ -0,0 +1,13 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "Order:{user_id}{shop_id}")]
+pub struct Order {
+    #[dynorow(sk)]
+    pub order_id: String,
+    pub user_id: String,
+    pub shop_id: String,
+}
+
+fn main() {}
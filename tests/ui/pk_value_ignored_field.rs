@@ -0,0 +1,13 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "Order:{user_id}")]
+pub struct Order {
+    #[dynorow(sk)]
+    pub order_id: String,
+    #[dynorow(ignore)]
+    pub user_id: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use dynorow::{DynoMap, DynoRow};
+
+#[derive(Clone, Debug, DynoMap)]
+pub struct Address {
+    pub city: String,
+}
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "Shop:{address}")]
+pub struct Shop {
+    #[dynorow(sk)]
+    pub shop_id: String,
+    pub address: Address,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "Report:{year}-{region}")]
+pub struct Report {
+    #[dynorow(sk)]
+    pub report_id: String,
+    pub year: i32,
+    pub region: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "Order:{user}")]
+pub struct Order {
+    #[dynorow(sk)]
+    pub order_id: String,
+    pub user_id: String,
+}
+
+fn main() {}
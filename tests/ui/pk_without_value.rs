@@ -0,0 +1,10 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+pub struct User {
+    #[dynorow(sk)]
+    pub user_id: String,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use dynorow::{DynoProjection, DynoRow};
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk", pk_value = "User")]
+pub struct User {
+    #[dynorow(sk)]
+    pub email_address: String,
+    pub retry_count: i32,
+}
+
+#[derive(DynoProjection)]
+#[dynorow(of = User)]
+pub struct UserSummary {
+    pub retry_count: String,
+    pub nickname: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "User")]
+pub struct User {
+    #[dynorow(sk)]
+    pub user_id: String,
+    #[dynorow(serde, timestamp = "rfc3339")]
+    pub created_at: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk", pk_value = "User")]
+#[dynorow(sk = "sk", sk_value = "USER#{user_id}")]
+pub struct User {
+    #[dynorow(sk)]
+    pub user_id: String,
+}
+
+fn main() {}
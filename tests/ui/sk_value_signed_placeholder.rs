@@ -0,0 +1,10 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk", pk_value = "User")]
+#[dynorow(sk = "sk", sk_value = "SCORE#{score}")]
+pub struct Score {
+    pub score: i64,
+}
+
+fn main() {}
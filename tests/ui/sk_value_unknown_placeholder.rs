@@ -0,0 +1,11 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk", pk_value = "User")]
+#[dynorow(sk = "sk", sk_value = "ORDER#{created_at}#{order}")]
+pub struct Order {
+    pub created_at: u64,
+    pub order_id: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk", pk_value = "User")]
+#[dynorow(sk = "sk")]
+pub struct User {
+    pub user_id: String,
+}
+
+fn main() {}
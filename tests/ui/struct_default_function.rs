@@ -0,0 +1,9 @@
+use dynorow::DynoMap;
+
+#[derive(DynoMap)]
+#[dynorow(default = default_address)]
+pub struct Address {
+    pub city: String,
+}
+
+fn main() {}
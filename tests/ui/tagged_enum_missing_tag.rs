@@ -0,0 +1,9 @@
+use dynorow::DynoMap;
+
+#[derive(DynoMap)]
+pub enum PaymentMethod {
+    Card { last4: String },
+    Cash,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dynorow::DynoMap;
+
+#[derive(DynoMap)]
+#[dynorow(tag = "type", contents = "data")]
+pub enum PaymentMethod {
+    Card { last4: String },
+    Cash,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use dynorow::{DynoRow, Fetchable};
+
+#[derive(Debug, Clone, DynoRow, Fetchable)]
+#[dynorow(pk = "pk", pk_value = "User#{user_id}")]
+pub struct User {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, DynoRow, Fetchable)]
+#[dynorow(table = "orders", pk = "pk", pk_value = "Order#{order_id}")]
+pub struct Order {
+    pub order_id: String,
+}
+
+async fn get_order(context: dynorow::DynamodbContext) {
+    let _ = context
+        .get::<Order>(UserKey {
+            user_id: "user_1".into(),
+        })
+        .await;
+}
+
+fn main() {}
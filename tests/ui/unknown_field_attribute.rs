@@ -0,0 +1,11 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "User")]
+pub struct User {
+    #[dynorow(sk_key)]
+    pub user_id: String,
+}
+
+fn main() {}
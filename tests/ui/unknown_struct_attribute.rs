@@ -0,0 +1,11 @@
+use dynorow::DynoRow;
+
+#[derive(DynoRow)]
+#[dynorow(pk = "pk")]
+#[dynorow(pk_value = "User", tabel = "users")]
+pub struct User {
+    #[dynorow(sk)]
+    pub user_id: String,
+}
+
+fn main() {}